@@ -1,12 +1,31 @@
 use crate::config::server_config::ServerConfig;
-use crate::repository::application::dao::application_repository::ApplicationRepository;
-use crate::repository::v2::dao::version_repository::VersionRepository;
+use crate::repository::api_key::dao::mongo_api_key_repository::MongoApiKeyRepository;
+use crate::repository::application::dao::mongo_application_repository::MongoApplicationRepository;
+use crate::repository::change::dao::mongo_change_stream_repository::MongoChangeStreamRepository;
+use crate::repository::health::dao::mongo_health_repository::MongoHealthRepository;
+use crate::repository::v2::dao::mongo_version_repository::MongoVersionRepository;
+use crate::repository::webhook::dao::mongo_webhook_delivery_repository::MongoWebhookDeliveryRepository;
+use crate::repository::webhook::dao::mongo_webhook_repository::MongoWebhookRepository;
 use crate::services::Services;
+use crate::services::api_key::api_key_service::ApiKeyService;
 use crate::services::application::application_service::ApplicationService;
+use crate::services::cache::cache_invalidation_service::CacheInvalidationService;
+use crate::services::cache::ttl_cache::CacheConfig;
+use crate::services::event::event_service::EventService;
+use crate::services::health::health_service::HealthService;
+use crate::services::info::info_service::InfoService;
+use crate::services::jwt::jwt_service::JwtService;
+use crate::services::metrics::metrics_service::MetricsService;
 use crate::services::version::version_service::VersionService;
-use log::info;
+use crate::services::webhook::webhook_service::{RetryPolicy, WebhookService};
+use jsonwebtoken::jwk::JwkSet;
+use log::{error, info};
 use mongodb::Client;
-use std::env;
+use mongodb::event::EventHandler;
+use mongodb::options::ClientOptions;
+use std::sync::Arc;
+use std::time::Duration;
+use std::{env, fs};
 
 pub struct EnvReader {}
 
@@ -47,9 +66,18 @@ impl EnvReader {
             Err(_) => panic!("MONGODB_CONNECTION_STRING has not been specified"),
         };
 
-        let client = Client::with_uri_str(connection_string)
+        let metrics_service = MetricsService::new();
+
+        let mut client_options = ClientOptions::parse(connection_string)
             .await
-            .expect("Failed to initialize MongoDB client");
+            .expect("MONGODB_CONNECTION_STRING must be a valid connection string");
+        let command_metrics = metrics_service.clone();
+        client_options.command_event_handler = Some(EventHandler::callback(move |event| {
+            command_metrics.observe_command_event(event)
+        }));
+
+        let client =
+            Client::with_options(client_options).expect("Failed to initialize MongoDB client");
 
         let max_fetch_limit = match env::var("MAX_FETCH_LIMIT") {
             Ok(d) => {
@@ -67,6 +95,71 @@ impl EnvReader {
             Err(_) => -1,
         };
 
+        let cache_ttl = match env::var("CACHE_TTL_SECONDS") {
+            Ok(d) => {
+                let res: u64 = d
+                    .trim()
+                    .parse()
+                    .expect("CACHE_TTL_SECONDS must be a positive number");
+                res
+            }
+            Err(_) => 60,
+        };
+
+        let cache_max_entries = match env::var("CACHE_MAX_ENTRIES") {
+            Ok(d) => {
+                let res: usize = d
+                    .trim()
+                    .parse()
+                    .expect("CACHE_MAX_ENTRIES must be a positive number");
+                res
+            }
+            Err(_) => 1000,
+        };
+
+        let cache_config = CacheConfig::new(Duration::from_secs(cache_ttl), cache_max_entries);
+        if !cache_config.is_enabled() {
+            info!("Caching of applications and versions is disabled");
+        }
+
+        let change_streams = match env::var("MONGODB_CHANGE_STREAMS") {
+            Ok(d) => {
+                let res: bool = d
+                    .trim()
+                    .parse()
+                    .expect("MONGODB_CHANGE_STREAMS must be true or false");
+                res
+            }
+            Err(_) => false,
+        };
+
+        let heartbeat_interval = match env::var("SSE_HEARTBEAT_SECONDS") {
+            Ok(d) => {
+                let res: u64 = d
+                    .trim()
+                    .parse()
+                    .expect("SSE_HEARTBEAT_SECONDS must be a positive number");
+                if res == 0 {
+                    panic!("SSE_HEARTBEAT_SECONDS must be greater than zero");
+                }
+                res
+            }
+            Err(_) => 15,
+        };
+
+        let max_subscribers = match env::var("SSE_MAX_SUBSCRIBERS") {
+            Ok(d) => {
+                let res: usize = d
+                    .trim()
+                    .parse()
+                    .expect("SSE_MAX_SUBSCRIBERS must be a positive number");
+                res
+            }
+            Err(_) => 1000,
+        };
+        let event_service =
+            EventService::new(Duration::from_secs(heartbeat_interval), max_subscribers);
+
         let application_collection = match env::var("MONGODB_APPLICATIONS_COLLECTION") {
             Ok(e) => e,
             Err(_) => {
@@ -81,6 +174,32 @@ impl EnvReader {
             }
         };
 
+        let api_key_collection = env::var("MONGODB_API_KEY_COLLECTION")
+            .ok()
+            .filter(|e| !e.is_empty())
+            .unwrap_or(String::from("api_keys"));
+
+        let webhook_collection = env::var("MONGODB_WEBHOOK_COLLECTION")
+            .ok()
+            .filter(|e| !e.is_empty())
+            .unwrap_or(String::from("webhooks"));
+
+        let webhook_delivery_collection = env::var("MONGODB_WEBHOOK_DELIVERY_COLLECTION")
+            .ok()
+            .filter(|e| !e.is_empty())
+            .unwrap_or(String::from("webhook_deliveries"));
+
+        let retry_policy = self.read_webhook_retry_policy();
+
+        let cors_allowed_origins: Vec<String> = match env::var("CORS_ALLOWED_ORIGINS") {
+            Ok(e) => e
+                .split(',')
+                .map(|o| o.trim().to_string())
+                .filter(|o| !o.is_empty())
+                .collect(),
+            Err(_) => vec![],
+        };
+
         let server_context = match env::var("SERVER_CONTEXT") {
             Ok(e) => e,
             Err(_) => {
@@ -88,23 +207,172 @@ impl EnvReader {
             }
         };
 
-        let application_repository = ApplicationRepository::new(application_collection);
-        let application_service = ApplicationService::new(application_repository);
+        let webhook_http = match env::var("WEBHOOK_ALLOW_HTTP") {
+            Ok(d) => {
+                let res: bool = d
+                    .trim()
+                    .parse()
+                    .expect("WEBHOOK_ALLOW_HTTP must be true or false");
+                res
+            }
+            Err(_) => false,
+        };
+
+        let db = client.database(&database_name);
+
+        let mut webhook_service = WebhookService::new(
+            Arc::new(MongoWebhookRepository::new(&db, &webhook_collection)),
+            Arc::new(MongoWebhookDeliveryRepository::new(
+                &db,
+                &webhook_delivery_collection,
+            )),
+            retry_policy,
+        );
+        if webhook_http {
+            webhook_service = webhook_service.with_http_urls();
+        }
+
+        let application_repository = MongoApplicationRepository::new(&db, &application_collection);
+        let mut application_service = ApplicationService::new(Arc::new(application_repository))
+            .with_cache(cache_config, &metrics_service)
+            .with_events(&event_service)
+            .with_webhooks(&webhook_service);
+        if change_streams {
+            application_service = application_service.with_followed_changes();
+        }
+
+        let version_repository = MongoVersionRepository::new(&db, &version_collection);
+        if let Err(e) = version_repository.create_indexes().await {
+            error!("Failed to create the version indexes: {}", e);
+        }
+        let version_service = VersionService::new(Arc::new(version_repository))
+            .with_cache(cache_config, &metrics_service);
+
+        let cache_invalidation_service = if change_streams {
+            Some(
+                CacheInvalidationService::new(
+                    application_service.clone(),
+                    version_service.clone(),
+                    Arc::new(MongoChangeStreamRepository::new(
+                        &db,
+                        &application_collection,
+                    )),
+                    Arc::new(MongoChangeStreamRepository::new(&db, &version_collection)),
+                )
+                .with_events(&event_service),
+            )
+        } else {
+            None
+        };
+
+        let api_key_repository = MongoApiKeyRepository::new(&db, &api_key_collection);
+        let api_key_service = ApiKeyService::new(Arc::new(api_key_repository));
+
+        let jwt_service = self.read_jwt_service();
 
-        let version_repository = VersionRepository::new(version_collection);
-        let version_service = VersionService::new(version_repository);
+        let health_repository = MongoHealthRepository::new(&db);
+        let health_service = HealthService::new(
+            Arc::new(health_repository),
+            &application_collection,
+            &version_collection,
+        );
 
-        let services = Services::new(application_service, version_service);
+        let services = Services::new(
+            application_service,
+            version_service,
+            api_key_service,
+            jwt_service,
+            health_service,
+            metrics_service,
+            InfoService::new(),
+            event_service,
+            webhook_service,
+            cache_invalidation_service,
+        );
 
         ServerConfig::new(
             &addr,
             port,
-            &database_name,
-            client,
             max_fetch_limit,
             workers,
             services,
             &server_context,
+            cors_allowed_origins,
         )
     }
+
+    /// Reads the backoff between the attempts of a webhook delivery from environment variables
+    ///
+    /// # Returns
+    ///
+    /// A new instance of RetryPolicy
+    fn read_webhook_retry_policy(&self) -> RetryPolicy {
+        let read = |name: &str, default: u64| match env::var(name) {
+            Ok(d) => {
+                let res: u64 = d
+                    .trim()
+                    .parse()
+                    .unwrap_or_else(|_| panic!("{} must be a positive number", name));
+                if res == 0 {
+                    panic!("{} must be greater than zero", name);
+                }
+                res
+            }
+            Err(_) => default,
+        };
+
+        let initial_delay = read("WEBHOOK_RETRY_INITIAL_SECONDS", 10);
+        let max_delay = read("WEBHOOK_RETRY_MAX_SECONDS", 3600);
+        let max_attempts = read("WEBHOOK_MAX_ATTEMPTS", 8);
+
+        RetryPolicy::new(
+            Duration::from_secs(initial_delay),
+            Duration::from_secs(max_delay),
+            u32::try_from(max_attempts).expect("WEBHOOK_MAX_ATTEMPTS is too large"),
+        )
+    }
+
+    /// Reads the bearer token verification configuration from environment variables
+    ///
+    /// # Returns
+    ///
+    /// A new instance of JwtService, which has no verification keys if none are configured
+    fn read_jwt_service(&self) -> JwtService {
+        let issuer = env::var("JWT_ISSUER").ok().filter(|e| !e.is_empty());
+        let audience = env::var("JWT_AUDIENCE").ok().filter(|e| !e.is_empty());
+
+        let mut jwt_service = JwtService::new(issuer, audience);
+
+        if let Some(secret) = env::var("JWT_HS256_SECRET").ok().filter(|e| !e.is_empty()) {
+            if secret.len() < 32 {
+                panic!("JWT_HS256_SECRET must be at least 32 characters long");
+            }
+            jwt_service = jwt_service.with_hs256_secret(&secret);
+        }
+
+        if let Some(path) = env::var("JWT_PUBLIC_KEY_FILE")
+            .ok()
+            .filter(|e| !e.is_empty())
+        {
+            let pem = fs::read(&path).expect("JWT_PUBLIC_KEY_FILE could not be read");
+            jwt_service = jwt_service
+                .with_public_key_pem(&pem)
+                .expect("JWT_PUBLIC_KEY_FILE must contain an RSA or Ed25519 public key");
+        }
+
+        if let Some(path) = env::var("JWT_JWKS_FILE").ok().filter(|e| !e.is_empty()) {
+            let content = fs::read_to_string(&path).expect("JWT_JWKS_FILE could not be read");
+            let jwks: JwkSet =
+                serde_json::from_str(&content).expect("JWT_JWKS_FILE must contain a valid JWKS");
+            jwt_service = jwt_service
+                .with_jwks(&jwks)
+                .expect("JWT_JWKS_FILE contains an invalid key");
+        }
+
+        if !jwt_service.is_enabled() {
+            info!("No JWT verification keys configured, bearer tokens are treated as API keys");
+        }
+
+        jwt_service
+    }
 }
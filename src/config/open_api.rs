@@ -1,23 +1,94 @@
-use utoipa::OpenApi;
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::{Modify, OpenApi};
 
 #[derive(OpenApi)]
 #[openapi(
     paths(
         crate::web::controller::actuator_controller::health,
+        crate::web::controller::actuator_controller::liveness,
+        crate::web::controller::actuator_controller::readiness,
+        crate::web::controller::actuator_controller::metrics,
+        crate::web::controller::actuator_controller::info,
         crate::web::controller::application_controller::find_all,
         crate::web::controller::application_controller::find_by_id,
+        crate::web::controller::application_controller::check_for_update,
+        crate::web::controller::application_controller::find_latest_release,
+        crate::web::controller::application_controller::find_latest_platform_release,
+        crate::web::controller::application_controller::find_latest_architecture_release,
+        crate::web::controller::application_controller::download,
+        crate::web::controller::application_controller::create_application,
+        crate::web::controller::application_controller::replace_application,
+        crate::web::controller::application_controller::patch_application,
+        crate::web::controller::application_controller::delete_application,
+        crate::web::controller::application_controller::create_release,
+        crate::web::controller::event_controller::application_events,
+        crate::web::controller::event_controller::events,
+        crate::web::controller::appcast_controller::find_appcast,
+        crate::web::controller::feed_controller::application_atom_feed,
+        crate::web::controller::feed_controller::application_rss_feed,
+        crate::web::controller::feed_controller::atom_feed,
+        crate::web::controller::feed_controller::rss_feed,
+        crate::web::controller::package_controller::find_winget_manifests,
+        crate::web::controller::package_controller::find_homebrew_cask,
+        crate::web::controller::package_controller::find_scoop_manifest,
         crate::web::controller::version_controller::find_all_versions,
         crate::web::controller::version_controller::find_version_by_id,
+        crate::web::controller::version_controller::create_version,
+        crate::web::controller::version_controller::update_version,
+        crate::web::controller::version_controller::delete_version,
+        crate::web::controller::webhook_controller::find_all_webhooks,
+        crate::web::controller::webhook_controller::find_webhook_by_id,
+        crate::web::controller::webhook_controller::find_webhook_deliveries,
+        crate::web::controller::webhook_controller::create_webhook,
+        crate::web::controller::webhook_controller::delete_webhook,
     ),
     components(schemas(
-        crate::errors::internal_server_error::InternalServerError,
+        crate::errors::api_error::ProblemDetails,
         crate::web::dto::application::application_dto::ApplicationDto,
         crate::web::dto::application::application_dto::ApplicationPlatformDto,
         crate::web::dto::application::application_dto::ArchitectureDto,
         crate::web::dto::application::application_dto::ReleaseDto,
         crate::web::dto::application::application_dto::ReleaseTypeDto,
+        crate::web::dto::application::application_request_dto::CreateApplicationDto,
+        crate::web::dto::application::application_request_dto::UpdateApplicationDto,
+        crate::web::dto::application::application_request_dto::PatchApplicationDto,
+        crate::web::dto::event::event_dto::ApplicationEventDto,
+        crate::web::dto::package::winget_dto::WingetPackageDto,
+        crate::web::dto::package::winget_dto::WingetManifestDto,
+        crate::web::dto::package::scoop_manifest_dto::ScoopManifestDto,
+        crate::web::dto::package::scoop_manifest_dto::ScoopArchitectureDto,
         crate::web::dto::version::version_dto::VersionDto,
         crate::web::dto::version::version_dto::PlatformDto,
-    ))
+        crate::web::dto::version::version_request_dto::CreateVersionDto,
+        crate::web::dto::version::version_request_dto::UpdateVersionDto,
+        crate::web::dto::webhook::webhook_dto::WebhookDto,
+        crate::web::dto::webhook::webhook_dto::CreateWebhookDto,
+        crate::web::dto::webhook::webhook_delivery_dto::WebhookDeliveryDto,
+        crate::web::dto::webhook::webhook_delivery_dto::DeliveryStatusDto,
+        crate::web::dto::health::health_dto::HealthDto,
+        crate::web::dto::health::health_dto::HealthStatusDto,
+        crate::web::dto::health::health_dto::ComponentHealthDto,
+        crate::web::dto::info::info_dto::InfoDto,
+        crate::web::dto::info::info_dto::BuildInfoDto,
+        crate::web::dto::info::info_dto::RuntimeInfoDto,
+        crate::web::dto::info::info_dto::ConfigInfoDto,
+    )),
+    modifiers(&SecurityAddon)
 )]
 pub struct ApiDoc;
+
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "api_key",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("X-Api-Key"))),
+        );
+        components.add_security_scheme(
+            "bearer",
+            SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).build()),
+        );
+    }
+}
@@ -1 +1,3 @@
-pub(crate) mod internal_server_error;
+pub(crate) mod api_error;
+pub(crate) mod repository_error;
+pub(crate) mod service_error;
@@ -0,0 +1,228 @@
+use actix_web::http::{StatusCode, header};
+use actix_web::{HttpResponse, ResponseError};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use utoipa::ToSchema;
+
+/// The media type of RFC 9457 problem details documents
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+/// A problem details document as described by RFC 9457
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ProblemDetails {
+    #[serde(rename = "type")]
+    #[schema(example = "about:blank")]
+    pub problem_type: String,
+    #[schema(example = "Not Found")]
+    pub title: String,
+    #[schema(example = 404)]
+    pub status: u16,
+    #[schema(example = "Application with ID deadhash was not found")]
+    pub detail: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "/api/v1/applications/deadhash")]
+    pub instance: Option<String>,
+}
+
+/// An error that is returned to API clients as an `application/problem+json` document
+///
+/// The problem type is `about:blank`, so the title is always the reason phrase of the status
+/// code and the detail carries the explanation that is specific to this occurrence.
+#[derive(Debug, Clone)]
+pub struct ApiError {
+    status: StatusCode,
+    detail: String,
+    instance: Option<String>,
+    challenge: Option<String>,
+    retry_after: Option<u64>,
+}
+
+impl ApiError {
+    /// Initialize a new ApiError
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - The HTTP status code of the problem
+    /// * `detail` - The explanation that is specific to this occurrence of the problem
+    ///
+    /// # Returns
+    ///
+    /// A new instance of ApiError
+    pub fn new(status: StatusCode, detail: &str) -> Self {
+        ApiError {
+            status,
+            detail: detail.to_string(),
+            instance: None,
+            challenge: None,
+            retry_after: None,
+        }
+    }
+
+    /// Initialize a new ApiError with status 400 Bad Request
+    pub fn bad_request(detail: &str) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, detail)
+    }
+
+    /// Initialize a new ApiError with status 401 Unauthorized
+    pub fn unauthorized(detail: &str) -> Self {
+        Self::new(StatusCode::UNAUTHORIZED, detail)
+    }
+
+    /// Initialize a new ApiError with status 403 Forbidden
+    pub fn forbidden(detail: &str) -> Self {
+        Self::new(StatusCode::FORBIDDEN, detail)
+    }
+
+    /// Initialize a new ApiError with status 404 Not Found
+    pub fn not_found(detail: &str) -> Self {
+        Self::new(StatusCode::NOT_FOUND, detail)
+    }
+
+    /// Initialize a new ApiError with status 409 Conflict
+    pub fn conflict(detail: &str) -> Self {
+        Self::new(StatusCode::CONFLICT, detail)
+    }
+
+    /// Initialize a new ApiError with status 422 Unprocessable Content
+    pub fn unprocessable_content(detail: &str) -> Self {
+        Self::new(StatusCode::UNPROCESSABLE_ENTITY, detail)
+    }
+
+    /// Initialize a new ApiError with status 429 Too Many Requests
+    pub fn too_many_requests(detail: &str) -> Self {
+        Self::new(StatusCode::TOO_MANY_REQUESTS, detail)
+    }
+
+    /// Initialize a new ApiError with status 500 Internal Server Error
+    pub fn internal_server_error(detail: &str) -> Self {
+        Self::new(StatusCode::INTERNAL_SERVER_ERROR, detail)
+    }
+
+    /// Set the URI reference that identifies this occurrence of the problem
+    ///
+    /// # Arguments
+    ///
+    /// * `instance` - The path of the request that caused the problem
+    ///
+    /// # Returns
+    ///
+    /// The ApiError with the instance
+    pub fn with_instance(mut self, instance: &str) -> Self {
+        self.instance = Some(instance.to_string());
+        self
+    }
+
+    /// Set the `WWW-Authenticate` challenge that is sent along with the problem
+    ///
+    /// # Arguments
+    ///
+    /// * `challenge` - The value of the `WWW-Authenticate` header
+    ///
+    /// # Returns
+    ///
+    /// The ApiError with the challenge
+    pub fn with_challenge(mut self, challenge: String) -> Self {
+        self.challenge = Some(challenge);
+        self
+    }
+
+    /// Set the number of seconds after which the client may retry the request
+    ///
+    /// # Arguments
+    ///
+    /// * `seconds` - The value of the `Retry-After` header
+    ///
+    /// # Returns
+    ///
+    /// The ApiError with the delay
+    pub fn with_retry_after(mut self, seconds: u64) -> Self {
+        self.retry_after = Some(seconds);
+        self
+    }
+
+    /// Check whether the instance of this problem is known
+    ///
+    /// # Returns
+    ///
+    /// True if the instance is set, otherwise false
+    pub fn has_instance(&self) -> bool {
+        self.instance.is_some()
+    }
+
+    /// Build the problem details document of this error
+    ///
+    /// # Returns
+    ///
+    /// The ProblemDetails that describe this error
+    pub fn problem_details(&self) -> ProblemDetails {
+        ProblemDetails {
+            problem_type: String::from("about:blank"),
+            title: self
+                .status
+                .canonical_reason()
+                .unwrap_or("Unknown Error")
+                .to_string(),
+            status: self.status.as_u16(),
+            detail: self.detail.clone(),
+            instance: self.instance.clone(),
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.status, self.detail)
+    }
+}
+
+impl ResponseError for ApiError {
+    fn status_code(&self) -> StatusCode {
+        self.status
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        let mut response = HttpResponse::build(self.status);
+        response.content_type(PROBLEM_JSON);
+        if let Some(challenge) = &self.challenge {
+            response.append_header((header::WWW_AUTHENTICATE, challenge.as_str()));
+        }
+        if let Some(seconds) = self.retry_after {
+            response.append_header((header::RETRY_AFTER, seconds));
+        }
+        response.json(self.problem_details())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::body::to_bytes;
+
+    #[actix_web::test]
+    async fn too_many_requests_renders_problem_details_with_retry_after() {
+        let error = ApiError::too_many_requests("Too many event streams are open")
+            .with_retry_after(30)
+            .with_instance("/api/v1/events");
+
+        let response = error.error_response();
+
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(
+            response.headers().get(header::CONTENT_TYPE).unwrap(),
+            PROBLEM_JSON
+        );
+        assert_eq!(response.headers().get(header::RETRY_AFTER).unwrap(), "30");
+        let body: serde_json::Value =
+            serde_json::from_slice(&to_bytes(response.into_body()).await.unwrap()).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "type": "about:blank",
+                "title": "Too Many Requests",
+                "status": 429,
+                "detail": "Too many event streams are open",
+                "instance": "/api/v1/events"
+            })
+        );
+    }
+}
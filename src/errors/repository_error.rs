@@ -0,0 +1,67 @@
+use mongodb::error::{ErrorKind, WriteFailure};
+use std::fmt;
+
+/// The MongoDB error code that is reported when a unique index is violated
+const DUPLICATE_KEY_ERROR_CODE: i32 = 11000;
+
+/// The MongoDB error codes that are reported when a change stream cannot be resumed with its
+/// resume token: InvalidResumeToken, ChangeStreamFatalError and ChangeStreamHistoryLost
+const NON_RESUMABLE_CHANGE_STREAM_ERROR_CODES: [i32; 3] = [260, 280, 286];
+
+/// An error that occurred while accessing a repository
+#[derive(Debug)]
+pub enum RepositoryError {
+    /// An entity with the same unique key already exists
+    Duplicate,
+    /// The underlying MongoDB operation failed
+    Database(mongodb::error::Error),
+}
+
+impl RepositoryError {
+    /// Check whether a change stream that failed with this error can no longer be resumed
+    ///
+    /// # Returns
+    ///
+    /// True if the resume token of the stream is no longer valid, for example because the
+    /// change it points to was removed from the oplog, or false if the error is transient
+    pub fn invalidates_resume_token(&self) -> bool {
+        match self {
+            RepositoryError::Duplicate => false,
+            RepositoryError::Database(e) => match e.kind.as_ref() {
+                ErrorKind::Command(e) => NON_RESUMABLE_CHANGE_STREAM_ERROR_CODES.contains(&e.code),
+                _ => false,
+            },
+        }
+    }
+}
+
+impl fmt::Display for RepositoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RepositoryError::Duplicate => write!(f, "An entity with the same key already exists"),
+            RepositoryError::Database(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for RepositoryError {}
+
+impl From<mongodb::error::Error> for RepositoryError {
+    fn from(error: mongodb::error::Error) -> Self {
+        match error.kind.as_ref() {
+            ErrorKind::Write(WriteFailure::WriteError(e)) if e.code == DUPLICATE_KEY_ERROR_CODE => {
+                RepositoryError::Duplicate
+            }
+            ErrorKind::Command(e) if e.code == DUPLICATE_KEY_ERROR_CODE => {
+                RepositoryError::Duplicate
+            }
+            _ => RepositoryError::Database(error),
+        }
+    }
+}
+
+impl From<mongodb::bson::ser::Error> for RepositoryError {
+    fn from(error: mongodb::bson::ser::Error) -> Self {
+        RepositoryError::Database(error.into())
+    }
+}
@@ -0,0 +1,59 @@
+use crate::errors::api_error::ApiError;
+use crate::errors::repository_error::RepositoryError;
+use log::error;
+use std::fmt;
+
+/// An error that occurred while executing a service operation
+#[derive(Debug)]
+pub enum ServiceError {
+    /// The requested entity does not exist
+    NotFound(String),
+    /// The operation conflicts with the current state of an entity
+    Conflict(String),
+    /// The provided data is invalid
+    Validation(String),
+    /// The underlying repository operation failed
+    Database(RepositoryError),
+}
+
+impl ServiceError {
+    /// Convert this error into an ApiError
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The detail to return when the underlying database operation failed
+    ///
+    /// # Returns
+    ///
+    /// An ApiError with a status code that matches the kind of error
+    pub fn into_api_error(self, message: &str) -> ApiError {
+        match self {
+            ServiceError::NotFound(m) => ApiError::not_found(&m),
+            ServiceError::Conflict(m) => ApiError::conflict(&m),
+            ServiceError::Validation(m) => ApiError::bad_request(&m),
+            ServiceError::Database(e) => {
+                error!("{}: {}", message, e);
+                ApiError::internal_server_error(message)
+            }
+        }
+    }
+}
+
+impl fmt::Display for ServiceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ServiceError::NotFound(message) => write!(f, "{}", message),
+            ServiceError::Conflict(message) => write!(f, "{}", message),
+            ServiceError::Validation(message) => write!(f, "{}", message),
+            ServiceError::Database(e) => write!(f, "Database error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ServiceError {}
+
+impl From<RepositoryError> for ServiceError {
+    fn from(error: RepositoryError) -> Self {
+        ServiceError::Database(error)
+    }
+}
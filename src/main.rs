@@ -2,6 +2,7 @@ use crate::component::env_reader::EnvReader;
 use crate::config::open_api::ApiDoc;
 use crate::web::controller::Controller;
 use actix_cors::Cors;
+use actix_web::http::{Method, header};
 use actix_web::middleware::Logger;
 use actix_web::{App, HttpServer};
 use dotenvy::dotenv;
@@ -15,6 +16,8 @@ mod config;
 mod errors;
 mod repository;
 mod services;
+#[cfg(test)]
+mod tests;
 mod web;
 
 #[actix_web::main]
@@ -29,6 +32,15 @@ async fn main() -> std::io::Result<()> {
     let port = server_config.port;
     let workers = server_config.workers;
 
+    if let Some(cache_invalidation_service) =
+        server_config.services.cache_invalidation_service.clone()
+    {
+        info!("Following MongoDB change streams to invalidate cached entries and publish events");
+        actix_web::rt::spawn(cache_invalidation_service.run());
+    }
+
+    actix_web::rt::spawn(server_config.services.webhook_service.clone().run());
+
     info!(
         "Starting server on {}:{} with context {}",
         host, port, server_config.server_context
@@ -37,6 +49,7 @@ async fn main() -> std::io::Result<()> {
     let openapi = ApiDoc::openapi();
     let mut server = HttpServer::new(move || {
         let logger = Logger::default();
+        let cors = build_cors(&server_config.cors_allowed_origins);
         App::new()
             .wrap(logger)
             .service(
@@ -44,7 +57,7 @@ async fn main() -> std::io::Result<()> {
                     .url("/api/api-docs/openapi.json", openapi.clone()),
             )
             .app_data(actix_web::web::Data::new(server_config.clone()))
-            .wrap(Cors::permissive())
+            .wrap(cors)
             .configure(Controller::configure_routes)
     })
     .bind((host, port))
@@ -56,3 +69,62 @@ async fn main() -> std::io::Result<()> {
 
     server.run().await
 }
+
+/// Build the CORS configuration of the server
+///
+/// Read-only requests are allowed from any origin. Requests that modify data are only allowed
+/// from the configured origins. The validators of conditional requests, the pagination links and
+/// the locations of created resources are exposed to scripts.
+///
+/// # Arguments
+///
+/// * `allowed_origins` - The origins that may perform cross-origin write requests
+///
+/// # Returns
+///
+/// The CORS middleware
+fn build_cors(allowed_origins: &[String]) -> Cors {
+    let write_origins = allowed_origins.to_vec();
+
+    Cors::default()
+        .allowed_origin_fn(move |origin, head| {
+            // Preflight requests announce the method of the actual request
+            let method = if head.method == Method::OPTIONS {
+                head.headers
+                    .get(header::ACCESS_CONTROL_REQUEST_METHOD)
+                    .and_then(|m| Method::from_bytes(m.as_bytes()).ok())
+            } else {
+                Some(head.method.clone())
+            };
+
+            method.is_some_and(|m| m == Method::GET || m == Method::HEAD)
+                || write_origins
+                    .iter()
+                    .any(|o| o.as_bytes() == origin.as_bytes())
+        })
+        .allowed_methods(vec![
+            Method::GET,
+            Method::HEAD,
+            Method::OPTIONS,
+            Method::POST,
+            Method::PUT,
+            Method::PATCH,
+            Method::DELETE,
+        ])
+        .allowed_headers(vec![
+            header::AUTHORIZATION,
+            header::CONTENT_TYPE,
+            header::ACCEPT,
+            header::IF_NONE_MATCH,
+            header::IF_MODIFIED_SINCE,
+            header::HeaderName::from_static("x-api-key"),
+            header::HeaderName::from_static("last-event-id"),
+        ])
+        .expose_headers(vec![
+            header::LINK,
+            header::ETAG,
+            header::LAST_MODIFIED,
+            header::LOCATION,
+        ])
+        .max_age(3600)
+}
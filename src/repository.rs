@@ -1,2 +1,9 @@
+pub(crate) mod api_key;
 pub(crate) mod application;
+pub(crate) mod change;
+pub(crate) mod health;
+#[cfg(test)]
+pub(crate) mod in_memory;
+pub(crate) mod semver;
 pub(crate) mod v2;
+pub(crate) mod webhook;
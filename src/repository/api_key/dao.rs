@@ -0,0 +1,4 @@
+pub(crate) mod api_key_repository;
+#[cfg(test)]
+pub(crate) mod in_memory_api_key_repository;
+pub(crate) mod mongo_api_key_repository;
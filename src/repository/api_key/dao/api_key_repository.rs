@@ -0,0 +1,18 @@
+use crate::errors::repository_error::RepositoryError;
+use crate::repository::api_key::model::api_key::ApiKey;
+use async_trait::async_trait;
+
+/// A store of ApiKey records
+#[async_trait]
+pub trait ApiKeyRepository: Send + Sync {
+    /// Find a single ApiKey by the hash of its secret
+    ///
+    /// # Arguments
+    ///
+    /// * `hash` - The hex-encoded SHA-256 hash of the secret
+    ///
+    /// # Returns
+    ///
+    /// A result containing an Option with the ApiKey if found, or None if not found, or an error if the underlying store fails
+    async fn find_by_hash(&self, hash: &str) -> Result<Option<ApiKey>, RepositoryError>;
+}
@@ -0,0 +1,32 @@
+use crate::errors::repository_error::RepositoryError;
+use crate::repository::api_key::dao::api_key_repository::ApiKeyRepository;
+use crate::repository::api_key::model::api_key::ApiKey;
+use async_trait::async_trait;
+
+/// An ApiKeyRepository that keeps ApiKey records in memory
+#[derive(Default)]
+pub struct InMemoryApiKeyRepository {
+    api_keys: Vec<ApiKey>,
+}
+
+impl InMemoryApiKeyRepository {
+    /// Initialize a new InMemoryApiKeyRepository that contains the provided ApiKey records
+    ///
+    /// # Arguments
+    ///
+    /// * `api_keys` - The ApiKey records to store
+    ///
+    /// # Returns
+    ///
+    /// A new instance of InMemoryApiKeyRepository
+    pub fn with(api_keys: Vec<ApiKey>) -> Self {
+        Self { api_keys }
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepository for InMemoryApiKeyRepository {
+    async fn find_by_hash(&self, hash: &str) -> Result<Option<ApiKey>, RepositoryError> {
+        Ok(self.api_keys.iter().find(|k| k.hash == hash).cloned())
+    }
+}
@@ -0,0 +1,37 @@
+use crate::errors::repository_error::RepositoryError;
+use crate::repository::api_key::dao::api_key_repository::ApiKeyRepository;
+use crate::repository::api_key::model::api_key::ApiKey;
+use async_trait::async_trait;
+use mongodb::bson::doc;
+use mongodb::{Collection, Database};
+
+/// An ApiKeyRepository that stores ApiKey records in a MongoDB collection
+#[derive(Clone)]
+pub struct MongoApiKeyRepository {
+    collection: Collection<ApiKey>,
+}
+
+impl MongoApiKeyRepository {
+    /// Initialize a new MongoApiKeyRepository
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - The MongoDB database
+    /// * `collection` - The name of the MongoDB collection to use for ApiKey records
+    ///
+    /// # Returns
+    ///
+    /// A new instance of MongoApiKeyRepository
+    pub fn new(db: &Database, collection: &str) -> Self {
+        Self {
+            collection: db.collection::<ApiKey>(collection),
+        }
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepository for MongoApiKeyRepository {
+    async fn find_by_hash(&self, hash: &str) -> Result<Option<ApiKey>, RepositoryError> {
+        Ok(self.collection.find_one(doc! { "hash": hash }).await?)
+    }
+}
@@ -1 +1,4 @@
 pub(crate) mod application_repository;
+#[cfg(test)]
+pub(crate) mod in_memory_application_repository;
+pub(crate) mod mongo_application_repository;
@@ -1,60 +1,50 @@
-use crate::repository::application::model::application::Application;
-use futures::TryStreamExt;
-use mongodb::Database;
-use mongodb::bson::doc;
+use crate::errors::repository_error::RepositoryError;
+use crate::repository::application::model::application::{Application, Release};
+use crate::repository::application::model::application_changes::ApplicationChanges;
+use async_trait::async_trait;
 
-#[derive(Clone)]
-pub struct ApplicationRepository {
-    collection: String,
-}
-
-impl ApplicationRepository {
-    /// Initialize a new ApplicationRepository
+/// A store of Applications
+#[async_trait]
+pub trait ApplicationRepository: Send + Sync {
+    /// Find a single Application by its ID
     ///
     /// # Arguments
     ///
-    /// * `collection` - The name of the collection.
+    /// * `id` - The ID of the Application
     ///
     /// # Returns
     ///
-    /// A new instance of ApplicationRepository.
-    pub fn new(collection: String) -> Self {
-        ApplicationRepository { collection }
-    }
+    /// An Option containing the Application if found, or None if not found
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying store fails
+    async fn get_by_id(&self, id: &str) -> Result<Option<Application>, RepositoryError>;
 
-    /// Find a single Application by its ID
+    /// Find all Applications sorted by ID
     ///
     /// # Arguments
     ///
-    /// * `id` - The ID of the Application
-    /// * `db` - The MongoDB database
+    /// * `limit` - The maximum number of Applications to return, or None for no limit
     ///
     /// # Returns
     ///
-    /// An Option containing the Application if found, or None if not found
+    /// A Result containing a Vec of Applications
     ///
     /// # Errors
     ///
-    /// Returns an error if the MongoDB query fails
-    pub async fn get_by_id(
+    /// Returns an error if the underlying store fails
+    async fn get_all_sorted_by_id(
         &self,
-        id: &str,
-        db: &Database,
-    ) -> Result<Option<Application>, mongodb::error::Error> {
-        let collection = db.collection::<Application>(&self.collection);
-        let filter = doc! { "_id": id };
-        match collection.find_one(filter).await {
-            Ok(application) => Ok(application),
-            Err(e) => Err(e),
-        }
-    }
+        limit: Option<i64>,
+    ) -> Result<Vec<Application>, RepositoryError>;
 
-    /// Find all Applications sorted by ID
+    /// Find all Applications with an ID greater than the provided ID, sorted by ID
     ///
     /// # Arguments
     ///
-    /// * `limit` - The maximum number of Applications to return
-    /// * `db` - The MongoDB database
+    /// * `id` - The ID from which to start searching
+    /// * `limit` - The maximum number of Applications to return, or None for no limit
     ///
     /// # Returns
     ///
@@ -62,64 +52,94 @@ impl ApplicationRepository {
     ///
     /// # Errors
     ///
-    /// Returns an error if the MongoDB query fails
-    pub async fn get_all_sorted_by_id(
+    /// Returns an error if the underlying store fails
+    async fn get_all_with_id_greater_than(
         &self,
+        id: &str,
         limit: Option<i64>,
-        db: &Database,
-    ) -> Result<Vec<Application>, mongodb::error::Error> {
-        let sort = doc! {"_id": 1};
-        let limit = limit.unwrap_or(0);
+    ) -> Result<Vec<Application>, RepositoryError>;
 
-        let cursor = match db
-            .collection::<Application>(&self.collection)
-            .find(doc! {})
-            .sort(sort)
-            .limit(limit)
-            .await
-        {
-            Ok(d) => d,
-            Err(e) => return Err(e),
-        };
-
-        Ok(cursor.try_collect().await.unwrap_or_else(|_| vec![]))
-    }
+    /// Insert a new Application
+    ///
+    /// # Arguments
+    ///
+    /// * `application` - The Application to insert
+    ///
+    /// # Returns
+    ///
+    /// An empty Result if the Application was inserted
+    ///
+    /// # Errors
+    ///
+    /// Returns a duplicate error if an Application with the same ID already exists, or an error
+    /// if the underlying store fails
+    async fn create(&self, application: &Application) -> Result<(), RepositoryError>;
 
-    /// Find all Applications with an ID greater than the provided ID
+    /// Apply a set of changes to a single Application
     ///
     /// # Arguments
     ///
-    /// * `id` - The ID from which to start searching
-    /// * `limit` - The maximum number of Applications to return
+    /// * `id` - The ID of the Application
+    /// * `changes` - The changes to apply
+    /// * `updated_at` - The new modification timestamp of the Application
     ///
     /// # Returns
     ///
-    /// A Result containing a Vec of Applications
+    /// An Option containing the updated Application, or None if the Application was not found
     ///
     /// # Errors
     ///
-    /// Returns an error if the MongoDB query fails
-    pub async fn get_all_with_id_greater_than(
+    /// Returns an error if the underlying store fails
+    async fn update(
         &self,
         id: &str,
-        limit: Option<i64>,
-        db: &Database,
-    ) -> Result<Vec<Application>, mongodb::error::Error> {
-        let filter = doc! { "_id": { "$gt": id } };
-        let sort = doc! {"_id": 1};
-        let limit = limit.unwrap_or(0);
+        changes: &ApplicationChanges,
+        updated_at: &str,
+    ) -> Result<Option<Application>, RepositoryError>;
 
-        let cursor = match db
-            .collection::<Application>(&self.collection)
-            .find(filter)
-            .limit(limit)
-            .sort(sort)
-            .await
-        {
-            Ok(d) => d,
-            Err(e) => return Err(e),
-        };
+    /// Delete a single Application by its ID
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the Application
+    ///
+    /// # Returns
+    ///
+    /// A Result containing true if the Application was deleted, or false if it was not found
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying store fails
+    async fn delete_by_id(&self, id: &str) -> Result<bool, RepositoryError>;
 
-        Ok(cursor.try_collect().await.unwrap_or_else(|_| vec![]))
-    }
+    /// Append a Release to an Architecture of an Application in a single atomic operation
+    ///
+    /// The operation only succeeds when the Architecture does not contain a Release with the same
+    /// semantic version yet. The modification timestamp of the Application is updated as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the Application
+    /// * `platform` - The exact name of the platform
+    /// * `architecture` - The exact name of the architecture
+    /// * `release` - The Release to append
+    /// * `initialize` - Whether the releases of the Architecture are missing and need to be initialized
+    /// * `updated_at` - The new modification timestamp of the Application
+    ///
+    /// # Returns
+    ///
+    /// A Result containing true if the Release was appended, or false if no Architecture matched
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying store fails
+    async fn push_release(
+        &self,
+        id: &str,
+        platform: &str,
+        architecture: &str,
+        release: &Release,
+        initialize: bool,
+        updated_at: &str,
+    ) -> Result<bool, RepositoryError>;
 }
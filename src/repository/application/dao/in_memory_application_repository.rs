@@ -0,0 +1,153 @@
+use crate::errors::repository_error::RepositoryError;
+use crate::repository::application::dao::application_repository::ApplicationRepository;
+use crate::repository::application::model::application::{Application, Release};
+use crate::repository::application::model::application_changes::ApplicationChanges;
+use crate::repository::in_memory::effective_limit;
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+use std::sync::RwLock;
+
+/// An ApplicationRepository that keeps Applications in memory, ordered by ID
+#[derive(Default)]
+pub struct InMemoryApplicationRepository {
+    applications: RwLock<BTreeMap<String, Application>>,
+}
+
+impl InMemoryApplicationRepository {
+    /// Initialize a new InMemoryApplicationRepository that contains the provided Applications
+    ///
+    /// # Arguments
+    ///
+    /// * `applications` - The Applications to store
+    ///
+    /// # Returns
+    ///
+    /// A new instance of InMemoryApplicationRepository
+    pub fn with(applications: Vec<Application>) -> Self {
+        InMemoryApplicationRepository {
+            applications: RwLock::new(
+                applications
+                    .into_iter()
+                    .map(|a| (a.id.clone(), a))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+#[async_trait]
+impl ApplicationRepository for InMemoryApplicationRepository {
+    async fn get_by_id(&self, id: &str) -> Result<Option<Application>, RepositoryError> {
+        Ok(self.applications.read().unwrap().get(id).cloned())
+    }
+
+    async fn get_all_sorted_by_id(
+        &self,
+        limit: Option<i64>,
+    ) -> Result<Vec<Application>, RepositoryError> {
+        Ok(self
+            .applications
+            .read()
+            .unwrap()
+            .values()
+            .take(effective_limit(limit))
+            .cloned()
+            .collect())
+    }
+
+    async fn get_all_with_id_greater_than(
+        &self,
+        id: &str,
+        limit: Option<i64>,
+    ) -> Result<Vec<Application>, RepositoryError> {
+        Ok(self
+            .applications
+            .read()
+            .unwrap()
+            .values()
+            .filter(|a| a.id.as_str() > id)
+            .take(effective_limit(limit))
+            .cloned()
+            .collect())
+    }
+
+    async fn create(&self, application: &Application) -> Result<(), RepositoryError> {
+        let mut applications = self.applications.write().unwrap();
+        if applications.contains_key(&application.id) {
+            return Err(RepositoryError::Duplicate);
+        }
+
+        applications.insert(application.id.clone(), application.clone());
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        id: &str,
+        changes: &ApplicationChanges,
+        updated_at: &str,
+    ) -> Result<Option<Application>, RepositoryError> {
+        let mut applications = self.applications.write().unwrap();
+        let application = match applications.get_mut(id) {
+            Some(application) => application,
+            None => return Ok(None),
+        };
+
+        if let Some(name) = &changes.name {
+            application.name = name.clone();
+        }
+        if let Some(description) = &changes.description {
+            application.description = description.clone();
+        }
+        if let Some(platforms) = &changes.platforms {
+            application.platforms = platforms.clone();
+        }
+        application.updated_at = updated_at.to_string();
+
+        Ok(Some(application.clone()))
+    }
+
+    async fn delete_by_id(&self, id: &str) -> Result<bool, RepositoryError> {
+        Ok(self.applications.write().unwrap().remove(id).is_some())
+    }
+
+    async fn push_release(
+        &self,
+        id: &str,
+        platform: &str,
+        architecture: &str,
+        release: &Release,
+        initialize: bool,
+        updated_at: &str,
+    ) -> Result<bool, RepositoryError> {
+        let mut applications = self.applications.write().unwrap();
+        let application = match applications.get_mut(id) {
+            Some(application) => application,
+            None => return Ok(false),
+        };
+
+        let target = application
+            .platforms
+            .iter_mut()
+            .flatten()
+            .filter(|p| p.platform_name == platform)
+            .flat_map(|p| p.architectures.iter_mut().flatten())
+            .find(|a| a.name == architecture);
+
+        let target = match target {
+            Some(target) => target,
+            None => return Ok(false),
+        };
+
+        match (&mut target.releases, initialize) {
+            (None, true) => target.releases = Some(vec![release.clone()]),
+            (Some(releases), false) if releases.iter().all(|r| r.semver != release.semver) => {
+                releases.push(release.clone())
+            }
+            _ => return Ok(false),
+        }
+
+        application.updated_at = updated_at.to_string();
+        Ok(true)
+    }
+}
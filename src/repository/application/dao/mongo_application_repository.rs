@@ -0,0 +1,251 @@
+use crate::errors::repository_error::RepositoryError;
+use crate::repository::application::dao::application_repository::ApplicationRepository;
+use crate::repository::application::model::application::{Application, Release};
+use crate::repository::application::model::application_changes::ApplicationChanges;
+use crate::repository::semver::same_precedence;
+use async_trait::async_trait;
+use futures::TryStreamExt;
+use mongodb::bson::{Bson, Document, doc, to_bson};
+use mongodb::options::ReturnDocument;
+use mongodb::{Collection, Database};
+
+/// An ApplicationRepository that stores Applications in a MongoDB collection
+#[derive(Clone)]
+pub struct MongoApplicationRepository {
+    collection: Collection<Application>,
+}
+
+impl MongoApplicationRepository {
+    /// Initialize a new MongoApplicationRepository
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - The MongoDB database
+    /// * `collection` - The name of the collection.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of MongoApplicationRepository.
+    pub fn new(db: &Database, collection: &str) -> Self {
+        MongoApplicationRepository {
+            collection: db.collection::<Application>(collection),
+        }
+    }
+}
+
+#[async_trait]
+impl ApplicationRepository for MongoApplicationRepository {
+    async fn get_by_id(&self, id: &str) -> Result<Option<Application>, RepositoryError> {
+        let filter = doc! { "_id": id };
+        Ok(self.collection.find_one(filter).await?)
+    }
+
+    async fn get_all_sorted_by_id(
+        &self,
+        limit: Option<i64>,
+    ) -> Result<Vec<Application>, RepositoryError> {
+        let sort = doc! {"_id": 1};
+        let limit = limit.unwrap_or(0);
+
+        let cursor = self
+            .collection
+            .find(doc! {})
+            .sort(sort)
+            .limit(limit)
+            .await?;
+
+        Ok(cursor.try_collect().await?)
+    }
+
+    async fn get_all_with_id_greater_than(
+        &self,
+        id: &str,
+        limit: Option<i64>,
+    ) -> Result<Vec<Application>, RepositoryError> {
+        let filter = doc! { "_id": { "$gt": id } };
+        let sort = doc! {"_id": 1};
+        let limit = limit.unwrap_or(0);
+
+        let cursor = self.collection.find(filter).limit(limit).sort(sort).await?;
+
+        Ok(cursor.try_collect().await?)
+    }
+
+    async fn create(&self, application: &Application) -> Result<(), RepositoryError> {
+        self.collection.insert_one(application).await?;
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        id: &str,
+        changes: &ApplicationChanges,
+        updated_at: &str,
+    ) -> Result<Option<Application>, RepositoryError> {
+        let mut set = doc! { "updatedAt": updated_at };
+
+        if let Some(name) = &changes.name {
+            set.insert("name", name);
+        }
+
+        if let Some(description) = &changes.description {
+            set.insert("description", description);
+        }
+
+        if let Some(platforms) = &changes.platforms {
+            set.insert("platforms", to_bson(platforms)?);
+        }
+
+        Ok(self
+            .collection
+            .find_one_and_update(doc! { "_id": id }, doc! { "$set": set })
+            .return_document(ReturnDocument::After)
+            .await?)
+    }
+
+    async fn delete_by_id(&self, id: &str) -> Result<bool, RepositoryError> {
+        let res = self.collection.delete_one(doc! { "_id": id }).await?;
+        Ok(res.deleted_count > 0)
+    }
+
+    async fn push_release(
+        &self,
+        id: &str,
+        platform: &str,
+        architecture: &str,
+        release: &Release,
+        initialize: bool,
+        updated_at: &str,
+    ) -> Result<bool, RepositoryError> {
+        let release_bson = to_bson(release)?;
+        let path = "platforms.$[p].architectures.$[a].releases";
+
+        let update = if initialize {
+            doc! {
+                "$set": { path: [release_bson], "updatedAt": updated_at }
+            }
+        } else {
+            doc! {
+                "$push": { path: release_bson },
+                "$set": { "updatedAt": updated_at }
+            }
+        };
+
+        let filter = push_release_filter(id, platform, architecture, release, initialize);
+
+        let res = self
+            .collection
+            .update_one(filter, update)
+            .array_filters(vec![
+                doc! { "p.platformName": platform },
+                doc! { "a.name": architecture },
+            ])
+            .await?;
+
+        Ok(res.matched_count > 0)
+    }
+}
+
+/// Build the filter that selects the architecture a release can be pushed into
+///
+/// The filter only matches if the architecture has no releases yet when it is initialized, or
+/// otherwise if none of its releases has the same precedence as the new release.
+///
+/// # Arguments
+///
+/// * `id` - The ID of the Application
+/// * `platform` - The name of the platform
+/// * `architecture` - The name of the architecture
+/// * `release` - The Release to push
+/// * `initialize` - Whether the architecture has no releases yet
+///
+/// # Returns
+///
+/// The filter document
+fn push_release_filter(
+    id: &str,
+    platform: &str,
+    architecture: &str,
+    release: &Release,
+    initialize: bool,
+) -> Document {
+    let architecture_filter = if initialize {
+        doc! { "name": architecture, "releases": Bson::Null }
+    } else {
+        doc! {
+            "name": architecture,
+            "releases.semver": { "$not": same_precedence(&release.semver) }
+        }
+    };
+
+    doc! {
+        "_id": id,
+        "platforms": {
+            "$elemMatch": {
+                "platformName": platform,
+                "architectures": { "$elemMatch": architecture_filter }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::release;
+
+    #[test]
+    fn push_release_filter_rejects_releases_with_the_same_precedence() {
+        let filter = push_release_filter(
+            "deadhash",
+            "Windows",
+            "x64",
+            &release("1.0.0-rc.1+b7"),
+            false,
+        );
+
+        let expected = doc! {
+            "_id": "deadhash",
+            "platforms": {
+                "$elemMatch": {
+                    "platformName": "Windows",
+                    "architectures": {
+                        "$elemMatch": {
+                            "name": "x64",
+                            "releases.semver": {
+                                "$not": Bson::RegularExpression(mongodb::bson::Regex {
+                                    pattern: String::from(r"^1\.0\.0-rc\.1(\+.*)?$"),
+                                    options: String::new(),
+                                })
+                            }
+                        }
+                    }
+                }
+            }
+        };
+        assert_eq!(filter, expected);
+
+        let pattern =
+            regex::Regex::new(&same_precedence(&release("1.0.0-rc.1+b7").semver).pattern).unwrap();
+        assert!(pattern.is_match("1.0.0-rc.1"));
+        assert!(pattern.is_match("1.0.0-rc.1+x"));
+        assert!(!pattern.is_match("1.0.0-rc.10"));
+        assert!(!pattern.is_match("1.0.0"));
+    }
+
+    #[test]
+    fn push_release_filter_initializes_architectures_without_releases() {
+        let filter = push_release_filter("deadhash", "Windows", "x64", &release("1.0.0"), true);
+
+        let architecture_filter = filter
+            .get_document("platforms")
+            .and_then(|p| p.get_document("$elemMatch"))
+            .and_then(|p| p.get_document("architectures"))
+            .and_then(|a| a.get_document("$elemMatch"))
+            .unwrap();
+        assert_eq!(
+            architecture_filter,
+            &doc! { "name": "x64", "releases": Bson::Null }
+        );
+    }
+}
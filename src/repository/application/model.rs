@@ -1 +1,3 @@
 pub(crate) mod application;
+pub(crate) mod application_changes;
+pub(crate) mod release_channel;
@@ -1,3 +1,5 @@
+use crate::repository::application::model::release_channel::ReleaseChannel;
+use crate::repository::semver::SemVer;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -36,12 +38,64 @@ pub struct Release {
     pub release_date: Option<String>,
     #[serde(rename = "releaseType")]
     pub release_type: Option<ReleaseType>,
-    pub semver: String,
+    pub channel: Option<ReleaseChannel>,
+    pub semver: SemVer,
     #[serde(rename = "downloadUrl")]
     pub download_url: String,
     #[serde(rename = "infoUrl")]
     pub info_url: Option<String>,
     pub checksum: Option<String>,
+    /// The size of the download in bytes
+    pub size: Option<u64>,
+    /// The base64-encoded Ed25519 signature of the download, as verified by Sparkle and WinSparkle
+    pub signature: Option<String>,
+}
+
+impl Release {
+    /// Get the SHA-256 hash of the download from the checksum of this Release
+    ///
+    /// The checksum may carry a `sha256:` prefix. Checksums of other algorithms are ignored.
+    ///
+    /// # Returns
+    ///
+    /// An Option containing the lowercase hex-encoded SHA-256 hash, or None if the checksum is not a SHA-256 hash
+    pub fn sha256(&self) -> Option<String> {
+        let checksum = self.checksum.as_deref()?.trim();
+        let hash = match checksum.split_once(':') {
+            Some((algorithm, hash)) if algorithm.eq_ignore_ascii_case("sha256") => hash,
+            Some(_) => return None,
+            None => checksum,
+        };
+
+        if hash.len() == 64 && hash.chars().all(|c| c.is_ascii_hexdigit()) {
+            Some(hash.to_ascii_lowercase())
+        } else {
+            None
+        }
+    }
+
+    /// Determine the channel of this Release
+    ///
+    /// Releases without an explicit channel are considered beta releases when they are marked as
+    /// a pre-release, either by their type or by their semantic version, and stable otherwise.
+    ///
+    /// # Returns
+    ///
+    /// The ReleaseChannel of this Release
+    pub fn effective_channel(&self) -> ReleaseChannel {
+        match &self.channel {
+            Some(channel) => channel.clone(),
+            None => {
+                if matches!(self.release_type, Some(ReleaseType::PreRelease))
+                    || self.semver.is_pre_release()
+                {
+                    ReleaseChannel::Beta
+                } else {
+                    ReleaseChannel::Stable
+                }
+            }
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -0,0 +1,11 @@
+use crate::repository::application::model::application::ApplicationPlatform;
+
+/// A set of changes to apply to an Application
+///
+/// Fields that are None are left untouched. For optional fields, `Some(None)` clears the value.
+#[derive(Default)]
+pub struct ApplicationChanges {
+    pub name: Option<String>,
+    pub description: Option<Option<String>>,
+    pub platforms: Option<Option<Vec<ApplicationPlatform>>>,
+}
@@ -0,0 +1,168 @@
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// The channel through which a Release is distributed
+///
+/// Channels are (de)serialized as lowercase strings. Any value other than `stable`, `beta` or
+/// `nightly` is treated as a custom channel.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ReleaseChannel {
+    Stable,
+    Beta,
+    Nightly,
+    Custom(String),
+}
+
+impl ReleaseChannel {
+    /// Check whether clients subscribed to this channel should receive a Release of another channel
+    ///
+    /// Every channel receives stable releases, nightly also receives beta releases and custom
+    /// channels only receive their own releases next to the stable ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The channel of the Release
+    ///
+    /// # Returns
+    ///
+    /// True if the Release should be visible on this channel, otherwise false
+    pub fn includes(&self, other: &ReleaseChannel) -> bool {
+        match (self, other) {
+            (_, ReleaseChannel::Stable) => true,
+            (ReleaseChannel::Beta, ReleaseChannel::Beta) => true,
+            (ReleaseChannel::Nightly, ReleaseChannel::Beta | ReleaseChannel::Nightly) => true,
+            (ReleaseChannel::Custom(a), ReleaseChannel::Custom(b)) => a.eq_ignore_ascii_case(b),
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for ReleaseChannel {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_ascii_lowercase().as_str() {
+            "stable" => ReleaseChannel::Stable,
+            "beta" => ReleaseChannel::Beta,
+            "nightly" => ReleaseChannel::Nightly,
+            custom => ReleaseChannel::Custom(custom.to_string()),
+        })
+    }
+}
+
+impl fmt::Display for ReleaseChannel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReleaseChannel::Stable => write!(f, "stable"),
+            ReleaseChannel::Beta => write!(f, "beta"),
+            ReleaseChannel::Nightly => write!(f, "nightly"),
+            ReleaseChannel::Custom(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+impl Serialize for ReleaseChannel {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+struct ReleaseChannelVisitor;
+
+impl Visitor<'_> for ReleaseChannelVisitor {
+    type Value = ReleaseChannel;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a release channel such as stable, beta or nightly")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        if v.is_empty() {
+            return Err(E::custom("release channel must not be empty"));
+        }
+
+        match v.parse() {
+            Ok(channel) => Ok(channel),
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ReleaseChannel {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(ReleaseChannelVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::application::model::application::ReleaseType;
+    use crate::tests::release;
+
+    fn custom(name: &str) -> ReleaseChannel {
+        ReleaseChannel::Custom(String::from(name))
+    }
+
+    #[test]
+    fn custom_channels_are_lowercased_when_parsed_and_deserialized() {
+        assert_eq!("Insider".parse(), Ok(custom("insider")));
+        assert_eq!("NIGHTLY".parse(), Ok(ReleaseChannel::Nightly));
+        assert_eq!(
+            serde_json::from_str::<ReleaseChannel>(r#""Insider""#).unwrap(),
+            custom("insider")
+        );
+        assert_eq!(
+            serde_json::to_string(&custom("insider")).unwrap(),
+            r#""insider""#
+        );
+        assert!(serde_json::from_str::<ReleaseChannel>(r#""""#).is_err());
+    }
+
+    #[test]
+    fn every_channel_includes_stable_and_nightly_includes_beta() {
+        for channel in [
+            ReleaseChannel::Stable,
+            ReleaseChannel::Beta,
+            ReleaseChannel::Nightly,
+            custom("insider"),
+        ] {
+            assert!(channel.includes(&ReleaseChannel::Stable));
+        }
+
+        assert!(ReleaseChannel::Nightly.includes(&ReleaseChannel::Beta));
+        assert!(ReleaseChannel::Beta.includes(&ReleaseChannel::Beta));
+        assert!(!ReleaseChannel::Beta.includes(&ReleaseChannel::Nightly));
+        assert!(!ReleaseChannel::Stable.includes(&ReleaseChannel::Beta));
+    }
+
+    #[test]
+    fn custom_channels_only_include_themselves_next_to_stable() {
+        let insider = custom("insider");
+
+        assert!(insider.includes(&custom("insider")));
+        assert!(!insider.includes(&custom("preview")));
+        assert!(!insider.includes(&ReleaseChannel::Beta));
+        assert!(!insider.includes(&ReleaseChannel::Nightly));
+        assert!(!ReleaseChannel::Nightly.includes(&insider));
+    }
+
+    #[test]
+    fn pre_releases_default_to_the_beta_channel() {
+        let mut marked = release("1.0.0");
+        marked.release_type = Some(ReleaseType::PreRelease);
+        let mut explicit = release("1.0.0-rc.1");
+        explicit.channel = Some(ReleaseChannel::Nightly);
+
+        assert_eq!(release("1.0.0").effective_channel(), ReleaseChannel::Stable);
+        assert_eq!(
+            release("1.0.0-rc.1").effective_channel(),
+            ReleaseChannel::Beta
+        );
+        assert_eq!(marked.effective_channel(), ReleaseChannel::Beta);
+        assert_eq!(explicit.effective_channel(), ReleaseChannel::Nightly);
+    }
+}
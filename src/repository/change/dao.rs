@@ -0,0 +1,4 @@
+pub(crate) mod change_stream_repository;
+#[cfg(test)]
+pub(crate) mod in_memory_change_stream_repository;
+pub(crate) mod mongo_change_stream_repository;
@@ -0,0 +1,26 @@
+use crate::errors::repository_error::RepositoryError;
+use crate::repository::change::model::document_change::DocumentChange;
+use async_trait::async_trait;
+use futures::stream::BoxStream;
+use mongodb::change_stream::event::ResumeToken;
+
+/// An open change stream, which ends when the collection is invalidated
+pub type ChangeStream = BoxStream<'static, Result<DocumentChange, RepositoryError>>;
+
+/// A source of the changes that are made to a single collection
+#[async_trait]
+pub trait ChangeStreamRepository: Send + Sync {
+    /// Open a change stream on the collection
+    ///
+    /// # Arguments
+    ///
+    /// * `resume_token` - The token of the last processed change, or None to only receive new changes
+    ///
+    /// # Returns
+    ///
+    /// A result containing the ChangeStream, or an error if the stream cannot be opened or resumed
+    async fn watch(
+        &self,
+        resume_token: Option<ResumeToken>,
+    ) -> Result<ChangeStream, RepositoryError>;
+}
@@ -0,0 +1,63 @@
+use crate::errors::repository_error::RepositoryError;
+use crate::repository::change::dao::change_stream_repository::{
+    ChangeStream, ChangeStreamRepository,
+};
+use crate::repository::change::model::document_change::DocumentChange;
+use async_trait::async_trait;
+use futures::StreamExt;
+use futures::stream;
+use mongodb::change_stream::event::ResumeToken;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+/// A ChangeStreamRepository that replays a fixed sequence of change streams
+#[derive(Default)]
+pub struct InMemoryChangeStreamRepository {
+    streams: Mutex<VecDeque<Vec<Result<DocumentChange, RepositoryError>>>>,
+    resume_tokens: Mutex<Vec<Option<ResumeToken>>>,
+}
+
+impl InMemoryChangeStreamRepository {
+    /// Initialize a new InMemoryChangeStreamRepository
+    ///
+    /// # Arguments
+    ///
+    /// * `streams` - The changes of every stream that is opened, in order. Opening more streams
+    ///   than provided fails.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of InMemoryChangeStreamRepository
+    pub fn with(streams: Vec<Vec<Result<DocumentChange, RepositoryError>>>) -> Self {
+        Self {
+            streams: Mutex::new(streams.into()),
+            resume_tokens: Mutex::new(vec![]),
+        }
+    }
+
+    /// Get the resume tokens that were passed to every opened stream
+    ///
+    /// # Returns
+    ///
+    /// The resume tokens, in order
+    pub fn resume_tokens(&self) -> Vec<Option<ResumeToken>> {
+        self.resume_tokens.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl ChangeStreamRepository for InMemoryChangeStreamRepository {
+    async fn watch(
+        &self,
+        resume_token: Option<ResumeToken>,
+    ) -> Result<ChangeStream, RepositoryError> {
+        self.resume_tokens.lock().unwrap().push(resume_token);
+
+        match self.streams.lock().unwrap().pop_front() {
+            Some(changes) => Ok(stream::iter(changes).boxed()),
+            None => Err(RepositoryError::Database(mongodb::error::Error::custom(
+                "change stream unavailable",
+            ))),
+        }
+    }
+}
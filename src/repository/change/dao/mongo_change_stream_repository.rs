@@ -0,0 +1,215 @@
+use crate::errors::repository_error::RepositoryError;
+use crate::repository::change::dao::change_stream_repository::{
+    ChangeStream, ChangeStreamRepository,
+};
+use crate::repository::change::model::document_change::{
+    AddedRelease, ChangeOperation, DocumentChange,
+};
+use async_trait::async_trait;
+use futures::StreamExt;
+use mongodb::bson::{Bson, Document};
+use mongodb::change_stream::event::{ChangeStreamEvent, OperationType, ResumeToken};
+use mongodb::options::FullDocumentType;
+use mongodb::{Collection, Database};
+
+/// A ChangeStreamRepository that follows a MongoDB collection
+///
+/// Change streams require MongoDB to run as a replica set or sharded cluster.
+#[derive(Clone)]
+pub struct MongoChangeStreamRepository {
+    collection: Collection<Document>,
+}
+
+impl MongoChangeStreamRepository {
+    /// Initialize a new MongoChangeStreamRepository
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - The MongoDB database
+    /// * `collection` - The name of the collection to follow
+    ///
+    /// # Returns
+    ///
+    /// A new instance of MongoChangeStreamRepository
+    pub fn new(db: &Database, collection: &str) -> Self {
+        MongoChangeStreamRepository {
+            collection: db.collection::<Document>(collection),
+        }
+    }
+
+    /// Convert a change stream event into a DocumentChange
+    fn document_change(event: ChangeStreamEvent<Document>) -> DocumentChange {
+        let operation = match event.operation_type {
+            OperationType::Insert => ChangeOperation::Insert,
+            OperationType::Update | OperationType::Replace => ChangeOperation::Update,
+            OperationType::Delete => ChangeOperation::Delete,
+            _ => ChangeOperation::Invalidate,
+        };
+        let document_id = match operation {
+            ChangeOperation::Invalidate => None,
+            _ => event
+                .document_key
+                .as_ref()
+                .and_then(|key| key.get_str("_id").ok())
+                .map(String::from),
+        };
+
+        let added_releases = match (event.operation_type, &event.update_description) {
+            (OperationType::Update, Some(description)) => {
+                Self::added_releases(&description.updated_fields, event.full_document.as_ref())
+            }
+            _ => vec![],
+        };
+
+        DocumentChange {
+            operation,
+            document_id,
+            added_releases,
+            resume_token: event.id,
+        }
+    }
+
+    /// Find the Releases that an update added to the architectures of an Application
+    ///
+    /// Pushed Releases are reported as `platforms.<i>.architectures.<j>.releases.<k>` and the
+    /// first Release of an architecture as the whole `platforms.<i>.architectures.<j>.releases`
+    /// array, of which only the last Release is new. The names of the platform and architecture
+    /// are looked up in the updated Application, so Releases of platforms or architectures that
+    /// were removed in the meantime are not reported.
+    ///
+    /// # Arguments
+    ///
+    /// * `updated_fields` - The fields that the update changed, with their new values
+    /// * `application` - The Application after the update, if it still exists
+    ///
+    /// # Returns
+    ///
+    /// The Releases that were added
+    fn added_releases(
+        updated_fields: &Document,
+        application: Option<&Document>,
+    ) -> Vec<AddedRelease> {
+        let Some(application) = application else {
+            return vec![];
+        };
+
+        updated_fields
+            .iter()
+            .filter_map(|(path, value)| {
+                let parts: Vec<&str> = path.split('.').collect();
+                let release = match (parts.as_slice(), value) {
+                    (["platforms", _, "architectures", _, "releases", _], Bson::Document(r)) => r,
+                    (["platforms", _, "architectures", _, "releases"], Bson::Array(releases)) => {
+                        releases.last()?.as_document()?
+                    }
+                    _ => return None,
+                };
+
+                let platform = application
+                    .get_array("platforms")
+                    .ok()?
+                    .get(parts[1].parse::<usize>().ok()?)?
+                    .as_document()?;
+                let architecture = platform
+                    .get_array("architectures")
+                    .ok()?
+                    .get(parts[3].parse::<usize>().ok()?)?
+                    .as_document()?;
+
+                Some(AddedRelease {
+                    platform: platform.get_str("platformName").ok()?.to_string(),
+                    architecture: architecture.get_str("name").ok()?.to_string(),
+                    semver: release.get_str("semver").ok()?.to_string(),
+                })
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl ChangeStreamRepository for MongoChangeStreamRepository {
+    async fn watch(
+        &self,
+        resume_token: Option<ResumeToken>,
+    ) -> Result<ChangeStream, RepositoryError> {
+        let stream = self
+            .collection
+            .watch()
+            .resume_after(resume_token)
+            .full_document(FullDocumentType::UpdateLookup)
+            .await?;
+
+        Ok(stream
+            .map(|event| Ok(Self::document_change(event?)))
+            .boxed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mongodb::bson::doc;
+
+    fn application() -> Document {
+        doc! {
+            "_id": "deadhash",
+            "platforms": [
+                { "platformName": "Linux", "architectures": [{ "name": "x64" }] },
+                {
+                    "platformName": "Windows",
+                    "architectures": [{ "name": "x86" }, { "name": "x64" }]
+                }
+            ]
+        }
+    }
+
+    fn added(platform: &str, architecture: &str, semver: &str) -> AddedRelease {
+        AddedRelease {
+            platform: String::from(platform),
+            architecture: String::from(architecture),
+            semver: String::from(semver),
+        }
+    }
+
+    #[test]
+    fn added_releases_are_read_from_pushed_and_initialized_release_arrays() {
+        let updated_fields = doc! {
+            "platforms.1.architectures.1.releases.3": { "semver": "2.1.0" },
+            "platforms.0.architectures.0.releases": [{ "semver": "1.0.0" }],
+            "updatedAt": "2026-10-18T09:30:00+00:00",
+        };
+
+        let releases =
+            MongoChangeStreamRepository::added_releases(&updated_fields, Some(&application()));
+
+        assert_eq!(
+            releases,
+            vec![
+                added("Windows", "x64", "2.1.0"),
+                added("Linux", "x64", "1.0.0")
+            ]
+        );
+    }
+
+    #[test]
+    fn added_releases_ignore_other_updates_and_removed_architectures() {
+        let updated_fields = doc! {
+            "name": "DeadHash",
+            "platforms.1.architectures.1.releases.3.name": "Release 2.1.0",
+            "platforms.1.architectures.2.releases.0": { "semver": "2.1.0" },
+            "platforms": [],
+        };
+
+        assert!(
+            MongoChangeStreamRepository::added_releases(&updated_fields, Some(&application()))
+                .is_empty()
+        );
+        assert!(
+            MongoChangeStreamRepository::added_releases(
+                &doc! { "platforms.0.architectures.0.releases.0": { "semver": "1.0.0" } },
+                None
+            )
+            .is_empty()
+        );
+    }
+}
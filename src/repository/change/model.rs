@@ -0,0 +1 @@
+pub(crate) mod document_change;
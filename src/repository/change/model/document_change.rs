@@ -0,0 +1,39 @@
+use mongodb::change_stream::event::ResumeToken;
+
+/// The kind of change that was made to a collection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeOperation {
+    /// A document was inserted
+    Insert,
+    /// A document was updated or replaced
+    Update,
+    /// A document was deleted
+    Delete,
+    /// The collection as a whole was dropped, renamed or invalidated
+    Invalidate,
+}
+
+/// A Release that was added to an architecture of an Application by an update
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddedRelease {
+    /// The name of the platform
+    pub platform: String,
+    /// The name of the architecture
+    pub architecture: String,
+    /// The semantic version of the Release
+    pub semver: String,
+}
+
+/// A change to a collection that was published by a change stream
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentChange {
+    /// The kind of change
+    pub operation: ChangeOperation,
+    /// The ID of the inserted, updated, replaced or deleted document, or None if the collection
+    /// as a whole was dropped, renamed or invalidated
+    pub document_id: Option<String>,
+    /// The Releases that an update added to the architectures of an Application
+    pub added_releases: Vec<AddedRelease>,
+    /// The token to resume the change stream after this change
+    pub resume_token: ResumeToken,
+}
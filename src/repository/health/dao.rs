@@ -0,0 +1,4 @@
+pub(crate) mod health_repository;
+#[cfg(test)]
+pub(crate) mod in_memory_health_repository;
+pub(crate) mod mongo_health_repository;
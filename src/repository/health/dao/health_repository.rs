@@ -0,0 +1,20 @@
+use crate::errors::repository_error::RepositoryError;
+use async_trait::async_trait;
+
+/// A probe of the database that backs the repositories
+#[async_trait]
+pub trait HealthRepository: Send + Sync {
+    /// Check whether the database responds to requests
+    ///
+    /// # Returns
+    ///
+    /// An empty result, or an error if the database cannot be reached
+    async fn ping(&self) -> Result<(), RepositoryError>;
+
+    /// List the names of all collections in the database
+    ///
+    /// # Returns
+    ///
+    /// A result containing the names of the collections, or an error if the database cannot be reached
+    async fn collection_names(&self) -> Result<Vec<String>, RepositoryError>;
+}
@@ -0,0 +1,37 @@
+use crate::errors::repository_error::RepositoryError;
+use crate::repository::health::dao::health_repository::HealthRepository;
+use async_trait::async_trait;
+
+/// A HealthRepository that reports a fixed set of collections
+#[derive(Default)]
+pub struct InMemoryHealthRepository {
+    collections: Vec<String>,
+}
+
+impl InMemoryHealthRepository {
+    /// Initialize a new InMemoryHealthRepository that contains the provided collections
+    ///
+    /// # Arguments
+    ///
+    /// * `collections` - The names of the collections that exist
+    ///
+    /// # Returns
+    ///
+    /// A new instance of InMemoryHealthRepository
+    pub fn with(collections: &[&str]) -> Self {
+        Self {
+            collections: collections.iter().map(|c| c.to_string()).collect(),
+        }
+    }
+}
+
+#[async_trait]
+impl HealthRepository for InMemoryHealthRepository {
+    async fn ping(&self) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn collection_names(&self) -> Result<Vec<String>, RepositoryError> {
+        Ok(self.collections.clone())
+    }
+}
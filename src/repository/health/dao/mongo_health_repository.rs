@@ -0,0 +1,38 @@
+use crate::errors::repository_error::RepositoryError;
+use crate::repository::health::dao::health_repository::HealthRepository;
+use async_trait::async_trait;
+use mongodb::Database;
+use mongodb::bson::doc;
+
+/// A HealthRepository that probes a MongoDB database
+#[derive(Clone)]
+pub struct MongoHealthRepository {
+    db: Database,
+}
+
+impl MongoHealthRepository {
+    /// Initialize a new MongoHealthRepository
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - The MongoDB database
+    ///
+    /// # Returns
+    ///
+    /// A new instance of MongoHealthRepository
+    pub fn new(db: &Database) -> Self {
+        Self { db: db.clone() }
+    }
+}
+
+#[async_trait]
+impl HealthRepository for MongoHealthRepository {
+    async fn ping(&self) -> Result<(), RepositoryError> {
+        self.db.run_command(doc! { "ping": 1 }).await?;
+        Ok(())
+    }
+
+    async fn collection_names(&self) -> Result<Vec<String>, RepositoryError> {
+        Ok(self.db.list_collection_names().await?)
+    }
+}
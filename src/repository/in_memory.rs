@@ -0,0 +1,15 @@
+/// Convert a MongoDB style limit, where zero or less means no limit, into a number of entries
+///
+/// # Arguments
+///
+/// * `limit` - The optional limit
+///
+/// # Returns
+///
+/// The maximum number of entries to return
+pub fn effective_limit(limit: Option<i64>) -> usize {
+    match limit {
+        Some(limit) if limit > 0 => usize::try_from(limit).unwrap_or(usize::MAX),
+        _ => usize::MAX,
+    }
+}
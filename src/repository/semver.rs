@@ -0,0 +1,410 @@
+use mongodb::bson::Regex;
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+/// A semantic version as described by the SemVer 2.0.0 specification
+///
+/// Equality, ordering and hashing follow SemVer precedence rules, which means that build metadata
+/// is ignored when comparing two versions
+#[derive(Debug, Clone)]
+pub struct SemVer {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    pub pre_release: Vec<PreReleaseIdentifier>,
+    pub build: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PreReleaseIdentifier {
+    Numeric(u64),
+    AlphaNumeric(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SemVerError {
+    Empty,
+    InvalidCore(String),
+    InvalidPreRelease(String),
+    InvalidBuild(String),
+}
+
+impl SemVer {
+    /// Check whether this version carries pre-release identifiers
+    ///
+    /// # Returns
+    ///
+    /// True if this version is a pre-release, otherwise false
+    pub fn is_pre_release(&self) -> bool {
+        !self.pre_release.is_empty()
+    }
+
+    /// Strip the build metadata of this version
+    ///
+    /// # Returns
+    ///
+    /// The version with the same precedence and without build metadata
+    pub fn without_build(&self) -> SemVer {
+        SemVer {
+            build: vec![],
+            ..self.clone()
+        }
+    }
+}
+
+/// Build a pattern that matches every stored version with the same precedence as a version
+///
+/// Versions only differ in precedence by their core and pre-release identifiers, so the pattern
+/// accepts any build metadata.
+///
+/// # Arguments
+///
+/// * `semver` - The version to match
+///
+/// # Returns
+///
+/// A regular expression that matches the version with or without build metadata
+pub fn same_precedence(semver: &SemVer) -> Regex {
+    let precedence = semver.without_build();
+
+    Regex {
+        pattern: format!(r"^{}(\+.*)?$", precedence.to_string().replace('.', r"\.")),
+        options: String::new(),
+    }
+}
+
+/// Parse a numeric identifier, rejecting leading zeros as required by the specification
+fn parse_numeric(value: &str) -> Option<u64> {
+    if value.is_empty()
+        || !value.bytes().all(|b| b.is_ascii_digit())
+        || (value.len() > 1 && value.starts_with('0'))
+    {
+        return None;
+    }
+
+    value.parse().ok()
+}
+
+/// Check whether an identifier only contains ASCII alphanumerics and hyphens
+fn is_valid_identifier(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-')
+}
+
+impl FromStr for SemVer {
+    type Err = SemVerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(SemVerError::Empty);
+        }
+
+        let (rest, build) = match s.split_once('+') {
+            Some((rest, build)) => (rest, Some(build)),
+            None => (s, None),
+        };
+
+        let (core, pre_release) = match rest.split_once('-') {
+            Some((core, pre_release)) => (core, Some(pre_release)),
+            None => (rest, None),
+        };
+
+        let parts: Vec<&str> = core.split('.').collect();
+        if parts.len() != 3 {
+            return Err(SemVerError::InvalidCore(core.to_string()));
+        }
+
+        let mut numbers = [0u64; 3];
+        for (i, part) in parts.iter().enumerate() {
+            numbers[i] = match parse_numeric(part) {
+                Some(n) => n,
+                None => return Err(SemVerError::InvalidCore(core.to_string())),
+            };
+        }
+
+        let mut pre_release_identifiers = vec![];
+        if let Some(pre_release) = pre_release {
+            for identifier in pre_release.split('.') {
+                if !is_valid_identifier(identifier) {
+                    return Err(SemVerError::InvalidPreRelease(pre_release.to_string()));
+                }
+
+                if identifier.bytes().all(|b| b.is_ascii_digit()) {
+                    match parse_numeric(identifier) {
+                        Some(n) => pre_release_identifiers.push(PreReleaseIdentifier::Numeric(n)),
+                        None => {
+                            return Err(SemVerError::InvalidPreRelease(pre_release.to_string()));
+                        }
+                    }
+                } else {
+                    pre_release_identifiers
+                        .push(PreReleaseIdentifier::AlphaNumeric(identifier.to_string()));
+                }
+            }
+        }
+
+        let mut build_identifiers = vec![];
+        if let Some(build) = build {
+            for identifier in build.split('.') {
+                if !is_valid_identifier(identifier) {
+                    return Err(SemVerError::InvalidBuild(build.to_string()));
+                }
+                build_identifiers.push(identifier.to_string());
+            }
+        }
+
+        Ok(SemVer {
+            major: numbers[0],
+            minor: numbers[1],
+            patch: numbers[2],
+            pre_release: pre_release_identifiers,
+            build: build_identifiers,
+        })
+    }
+}
+
+impl fmt::Display for SemVer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+
+        if !self.pre_release.is_empty() {
+            let identifiers: Vec<String> = self.pre_release.iter().map(|i| i.to_string()).collect();
+            write!(f, "-{}", identifiers.join("."))?;
+        }
+
+        if !self.build.is_empty() {
+            write!(f, "+{}", self.build.join("."))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for PreReleaseIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PreReleaseIdentifier::Numeric(n) => write!(f, "{}", n),
+            PreReleaseIdentifier::AlphaNumeric(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl fmt::Display for SemVerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SemVerError::Empty => write!(f, "version string is empty"),
+            SemVerError::InvalidCore(core) => {
+                write!(
+                    f,
+                    "invalid version core '{}', expected MAJOR.MINOR.PATCH",
+                    core
+                )
+            }
+            SemVerError::InvalidPreRelease(pre_release) => {
+                write!(f, "invalid pre-release '{}'", pre_release)
+            }
+            SemVerError::InvalidBuild(build) => write!(f, "invalid build metadata '{}'", build),
+        }
+    }
+}
+
+impl std::error::Error for SemVerError {}
+
+impl Ord for PreReleaseIdentifier {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (PreReleaseIdentifier::Numeric(a), PreReleaseIdentifier::Numeric(b)) => a.cmp(b),
+            (PreReleaseIdentifier::Numeric(_), PreReleaseIdentifier::AlphaNumeric(_)) => {
+                Ordering::Less
+            }
+            (PreReleaseIdentifier::AlphaNumeric(_), PreReleaseIdentifier::Numeric(_)) => {
+                Ordering::Greater
+            }
+            (PreReleaseIdentifier::AlphaNumeric(a), PreReleaseIdentifier::AlphaNumeric(b)) => {
+                a.cmp(b)
+            }
+        }
+    }
+}
+
+impl PartialOrd for PreReleaseIdentifier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SemVer {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.major
+            .cmp(&other.major)
+            .then(self.minor.cmp(&other.minor))
+            .then(self.patch.cmp(&other.patch))
+            .then_with(|| {
+                // A version without pre-release identifiers has a higher precedence
+                match (self.pre_release.is_empty(), other.pre_release.is_empty()) {
+                    (true, true) => Ordering::Equal,
+                    (true, false) => Ordering::Greater,
+                    (false, true) => Ordering::Less,
+                    (false, false) => self.pre_release.cmp(&other.pre_release),
+                }
+            })
+    }
+}
+
+impl PartialOrd for SemVer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for SemVer {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for SemVer {}
+
+impl Hash for SemVer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.major.hash(state);
+        self.minor.hash(state);
+        self.patch.hash(state);
+        self.pre_release.hash(state);
+    }
+}
+
+impl Serialize for SemVer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+struct SemVerVisitor;
+
+impl Visitor<'_> for SemVerVisitor {
+    type Value = SemVer;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a semantic version string such as 1.2.3-beta.1+build.5")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for SemVer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(SemVerVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn semver(value: &str) -> SemVer {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn numeric_parts_are_compared_as_numbers() {
+        assert!(semver("10.0.0") > semver("9.0.0"));
+        assert!(semver("1.10.0") > semver("1.9.0"));
+        assert!(semver("1.0.10") > semver("1.0.9"));
+    }
+
+    #[test]
+    fn pre_releases_follow_the_precedence_of_the_specification() {
+        let ordered = [
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1.0.0-rc.1",
+            "1.0.0",
+        ];
+
+        for pair in ordered.windows(2) {
+            assert!(
+                semver(pair[0]) < semver(pair[1]),
+                "{} < {}",
+                pair[0],
+                pair[1]
+            );
+        }
+    }
+
+    #[test]
+    fn numeric_pre_release_identifiers_sort_below_alphanumeric_ones() {
+        assert!(semver("1.0.0-1") < semver("1.0.0-a"));
+        assert!(semver("1.0.0-alpha.99") < semver("1.0.0-alpha.a"));
+        assert!(
+            PreReleaseIdentifier::Numeric(99) < PreReleaseIdentifier::AlphaNumeric("0a".into())
+        );
+    }
+
+    #[test]
+    fn build_metadata_is_ignored_by_equality_ordering_and_hashing() {
+        let a = semver("1.0.0+build.1");
+        let b = semver("1.0.0+build.2");
+
+        assert_eq!(a, b);
+        assert_eq!(a.cmp(&b), Ordering::Equal);
+        assert_eq!(HashSet::from([a, b]).len(), 1);
+        assert_ne!(semver("1.0.0-rc.1+build.1"), semver("1.0.0+build.1"));
+    }
+
+    #[test]
+    fn invalid_versions_are_rejected() {
+        assert_eq!("".parse::<SemVer>(), Err(SemVerError::Empty));
+        for value in ["01.0.0", "1.00.0", "1.0", "1.0.0.0", "v1.0.0", "1.0.x"] {
+            assert!(
+                matches!(value.parse::<SemVer>(), Err(SemVerError::InvalidCore(_))),
+                "{}",
+                value
+            );
+        }
+        for value in ["1.0.0-01", "1.0.0-", "1.0.0-alpha..1", "1.0.0-alpha_1"] {
+            assert!(
+                matches!(
+                    value.parse::<SemVer>(),
+                    Err(SemVerError::InvalidPreRelease(_))
+                ),
+                "{}",
+                value
+            );
+        }
+        for value in ["1.0.0+", "1.0.0+build..1", "1.0.0+build+1"] {
+            assert!(
+                matches!(value.parse::<SemVer>(), Err(SemVerError::InvalidBuild(_))),
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn serde_round_trips_the_version_string() {
+        let version = semver("1.2.3-beta.1+build.5");
+
+        let json = serde_json::to_string(&version).unwrap();
+        let parsed: SemVer = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(json, "\"1.2.3-beta.1+build.5\"");
+        assert_eq!(parsed.to_string(), "1.2.3-beta.1+build.5");
+        assert_eq!(parsed.build, vec!["build", "5"]);
+        assert!(serde_json::from_str::<SemVer>("\"1.2\"").is_err());
+        assert!(serde_json::from_str::<SemVer>("123").is_err());
+    }
+}
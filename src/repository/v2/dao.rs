@@ -1 +1,4 @@
+#[cfg(test)]
+pub(crate) mod in_memory_version_repository;
+pub(crate) mod mongo_version_repository;
 pub(crate) mod version_repository;
@@ -0,0 +1,121 @@
+use crate::errors::repository_error::RepositoryError;
+use crate::repository::in_memory::effective_limit;
+use crate::repository::semver::SemVer;
+use crate::repository::v2::dao::version_repository::VersionRepository;
+use crate::repository::v2::model::version::Version;
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+use std::sync::RwLock;
+
+/// A VersionRepository that keeps Version records in memory, ordered by ID
+#[derive(Default)]
+pub struct InMemoryVersionRepository {
+    versions: RwLock<BTreeMap<String, Version>>,
+}
+
+impl InMemoryVersionRepository {
+    /// Initialize a new InMemoryVersionRepository that contains the provided Version records
+    ///
+    /// # Arguments
+    ///
+    /// * `versions` - The Version records to store
+    ///
+    /// # Returns
+    ///
+    /// A new instance of InMemoryVersionRepository
+    pub fn with(versions: Vec<Version>) -> Self {
+        Self {
+            versions: RwLock::new(versions.into_iter().map(|v| (v.id.clone(), v)).collect()),
+        }
+    }
+}
+
+#[async_trait]
+impl VersionRepository for InMemoryVersionRepository {
+    async fn find_by_id(&self, id: &str) -> Result<Option<Version>, RepositoryError> {
+        Ok(self.versions.read().unwrap().get(id).cloned())
+    }
+
+    async fn get_all_sorted_by_id(
+        &self,
+        limit: Option<i64>,
+    ) -> Result<Vec<Version>, RepositoryError> {
+        Ok(self
+            .versions
+            .read()
+            .unwrap()
+            .values()
+            .take(effective_limit(limit))
+            .cloned()
+            .collect())
+    }
+
+    async fn get_all_with_id_greater_than(
+        &self,
+        id: &str,
+        limit: Option<i64>,
+    ) -> Result<Vec<Version>, RepositoryError> {
+        Ok(self
+            .versions
+            .read()
+            .unwrap()
+            .values()
+            .filter(|v| v.id.as_str() > id)
+            .take(effective_limit(limit))
+            .cloned()
+            .collect())
+    }
+
+    async fn exists_by_semver(
+        &self,
+        semver: &SemVer,
+        exclude_id: Option<&str>,
+    ) -> Result<bool, RepositoryError> {
+        Ok(self
+            .versions
+            .read()
+            .unwrap()
+            .values()
+            .filter(|v| exclude_id != Some(v.id.as_str()))
+            .any(|v| &v.semver == semver))
+    }
+
+    async fn create(&self, version: &Version) -> Result<(), RepositoryError> {
+        let mut versions = self.versions.write().unwrap();
+        if versions.contains_key(&version.id)
+            || versions.values().any(|v| v.semver == version.semver)
+        {
+            return Err(RepositoryError::Duplicate);
+        }
+
+        versions.insert(version.id.clone(), version.clone());
+        Ok(())
+    }
+
+    async fn update(&self, version: &Version) -> Result<Option<Version>, RepositoryError> {
+        let mut versions = self.versions.write().unwrap();
+        if versions
+            .values()
+            .any(|v| v.id != version.id && v.semver == version.semver)
+        {
+            return Err(RepositoryError::Duplicate);
+        }
+
+        let existing = match versions.get_mut(&version.id) {
+            Some(existing) => existing,
+            None => return Ok(None),
+        };
+
+        existing.name = version.name.clone();
+        existing.description = version.description.clone();
+        existing.semver = version.semver.clone();
+        existing.platforms = version.platforms.clone();
+        existing.updated_at = version.updated_at.clone();
+
+        Ok(Some(existing.clone()))
+    }
+
+    async fn delete_by_id(&self, id: &str) -> Result<bool, RepositoryError> {
+        Ok(self.versions.write().unwrap().remove(id).is_some())
+    }
+}
@@ -0,0 +1,151 @@
+use crate::errors::repository_error::RepositoryError;
+use crate::repository::semver::{SemVer, same_precedence};
+use crate::repository::v2::dao::version_repository::VersionRepository;
+use crate::repository::v2::model::version::Version;
+use async_trait::async_trait;
+use futures::TryStreamExt;
+use mongodb::bson::{Document, doc, to_bson, to_document};
+use mongodb::options::{IndexOptions, ReturnDocument};
+use mongodb::{Collection, Database, IndexModel};
+
+/// The field that holds the semantic version without build metadata, which is unique
+const SEMVER_PRECEDENCE_FIELD: &str = "semverPrecedence";
+
+/// A VersionRepository that stores Version records in a MongoDB collection
+#[derive(Clone)]
+pub struct MongoVersionRepository {
+    collection: Collection<Version>,
+}
+
+impl MongoVersionRepository {
+    /// Initialize a new MongoVersionRepository
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - The MongoDB database
+    /// * `collection` - The name of the MongoDB collection to use for Version records
+    ///
+    /// # Returns
+    ///
+    /// A new instance of MongoVersionRepository
+    pub fn new(db: &Database, collection: &str) -> Self {
+        Self {
+            collection: db.collection::<Version>(collection),
+        }
+    }
+
+    /// Create the unique index on the semantic version without build metadata
+    ///
+    /// The index is sparse, so Versions that were stored before the field existed do not
+    /// conflict with each other until they are updated.
+    ///
+    /// # Returns
+    ///
+    /// An empty Result once the index exists
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index cannot be created, for example because two Versions already
+    /// share a semantic version
+    pub async fn create_indexes(&self) -> Result<(), RepositoryError> {
+        let index = IndexModel::builder()
+            .keys(doc! { SEMVER_PRECEDENCE_FIELD: 1 })
+            .options(IndexOptions::builder().unique(true).sparse(true).build())
+            .build();
+
+        self.collection.create_index(index).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl VersionRepository for MongoVersionRepository {
+    async fn find_by_id(&self, id: &str) -> Result<Option<Version>, RepositoryError> {
+        let filter = doc! { "_id": id };
+        Ok(self.collection.find_one(filter).await?)
+    }
+
+    async fn get_all_sorted_by_id(
+        &self,
+        limit: Option<i64>,
+    ) -> Result<Vec<Version>, RepositoryError> {
+        let sort = doc! {"_id": 1};
+        let limit = limit.unwrap_or(0);
+
+        let cursor = self
+            .collection
+            .find(doc! {})
+            .sort(sort)
+            .limit(limit)
+            .await?;
+
+        Ok(cursor.try_collect().await?)
+    }
+
+    async fn get_all_with_id_greater_than(
+        &self,
+        id: &str,
+        limit: Option<i64>,
+    ) -> Result<Vec<Version>, RepositoryError> {
+        let filter = doc! { "_id": { "$gt": id } };
+        let sort = doc! {"_id": 1};
+        let limit = limit.unwrap_or(0);
+
+        let cursor = self.collection.find(filter).limit(limit).sort(sort).await?;
+
+        Ok(cursor.try_collect().await?)
+    }
+
+    async fn exists_by_semver(
+        &self,
+        semver: &SemVer,
+        exclude_id: Option<&str>,
+    ) -> Result<bool, RepositoryError> {
+        let mut filter = doc! { "semver": same_precedence(semver) };
+        if let Some(id) = exclude_id {
+            filter.insert("_id", doc! { "$ne": id });
+        }
+
+        let count = self.collection.count_documents(filter).limit(1).await?;
+
+        Ok(count > 0)
+    }
+
+    async fn create(&self, version: &Version) -> Result<(), RepositoryError> {
+        let mut document = to_document(version)?;
+        document.insert(
+            SEMVER_PRECEDENCE_FIELD,
+            version.semver.without_build().to_string(),
+        );
+
+        self.collection
+            .clone_with_type::<Document>()
+            .insert_one(document)
+            .await?;
+        Ok(())
+    }
+
+    async fn update(&self, version: &Version) -> Result<Option<Version>, RepositoryError> {
+        let update = doc! {
+            "$set": {
+                "name": &version.name,
+                "description": &version.description,
+                "semver": version.semver.to_string(),
+                SEMVER_PRECEDENCE_FIELD: version.semver.without_build().to_string(),
+                "platforms": to_bson(&version.platforms)?,
+                "updatedAt": &version.updated_at,
+            }
+        };
+
+        Ok(self
+            .collection
+            .find_one_and_update(doc! { "_id": &version.id }, update)
+            .return_document(ReturnDocument::After)
+            .await?)
+    }
+
+    async fn delete_by_id(&self, id: &str) -> Result<bool, RepositoryError> {
+        let res = self.collection.delete_one(doc! { "_id": id }).await?;
+        Ok(res.deleted_count > 0)
+    }
+}
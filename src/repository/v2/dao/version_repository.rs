@@ -1,56 +1,27 @@
+use crate::errors::repository_error::RepositoryError;
+use crate::repository::semver::SemVer;
 use crate::repository::v2::model::version::Version;
-use futures::TryStreamExt;
-use mongodb::Database;
-use mongodb::bson::doc;
-
-#[derive(Clone)]
-pub struct VersionRepository {
-    collection: String,
-}
-
-impl VersionRepository {
-    /// Initialize a new VersionRepository
-    ///
-    /// # Arguments
-    ///
-    /// * `collection` - The name of the MongoDB collection to use for Version records
-    ///
-    /// # Returns
-    ///
-    /// A new instance of VersionRepository
-    pub fn new(collection: String) -> Self {
-        Self { collection }
-    }
+use async_trait::async_trait;
 
+/// A store of Version records
+#[async_trait]
+pub trait VersionRepository: Send + Sync {
     /// Find a single Version by its ID
     ///
     /// # Arguments
     ///
     /// * `id` - The ID of the Version
-    /// * `db` - The MongoDB database
     ///
     /// # Returns
     ///
-    /// A result containing an Option with the Version if found, or None if not found, or an error if the MongoDB query fails
-    pub async fn find_by_id(
-        &self,
-        id: &str,
-        db: &Database,
-    ) -> Result<Option<Version>, mongodb::error::Error> {
-        let collection = db.collection::<Version>(&self.collection);
-        let filter = doc! { "_id": id };
-        match collection.find_one(filter).await {
-            Ok(version) => Ok(version),
-            Err(e) => Err(e),
-        }
-    }
+    /// A result containing an Option with the Version if found, or None if not found, or an error if the underlying store fails
+    async fn find_by_id(&self, id: &str) -> Result<Option<Version>, RepositoryError>;
 
     /// Find all Version records sorted by ID
     ///
     /// # Arguments
     ///
-    /// * `limit` - The maximum number of Version structs to return
-    /// * `db` - The MongoDB database
+    /// * `limit` - The maximum number of Version structs to return, or None for no limit
     ///
     /// # Returns
     ///
@@ -58,35 +29,18 @@ impl VersionRepository {
     ///
     /// # Errors
     ///
-    /// Returns an error if the MongoDB query fails
-    pub async fn get_all_sorted_by_id(
+    /// Returns an error if the underlying store fails
+    async fn get_all_sorted_by_id(
         &self,
         limit: Option<i64>,
-        db: &Database,
-    ) -> Result<Vec<Version>, mongodb::error::Error> {
-        let sort = doc! {"_id": 1};
-        let limit = limit.unwrap_or(0);
-
-        let cursor = match db
-            .collection::<Version>(&self.collection)
-            .find(doc! {})
-            .sort(sort)
-            .limit(limit)
-            .await
-        {
-            Ok(d) => d,
-            Err(e) => return Err(e),
-        };
-
-        Ok(cursor.try_collect().await.unwrap_or_else(|_| vec![]))
-    }
+    ) -> Result<Vec<Version>, RepositoryError>;
 
     /// Find all Version records with an ID greater than the provided ID
     ///
     /// # Arguments
     ///
     /// * `id` - The ID from which to start searching
-    /// * `limit` - The maximum number of Version structs to return
+    /// * `limit` - The maximum number of Version structs to return, or None for no limit
     ///
     /// # Returns
     ///
@@ -94,28 +48,77 @@ impl VersionRepository {
     ///
     /// # Errors
     ///
-    /// Returns an error if the MongoDB query fails
-    pub async fn get_all_with_id_greater_than(
+    /// Returns an error if the underlying store fails
+    async fn get_all_with_id_greater_than(
         &self,
         id: &str,
         limit: Option<i64>,
-        db: &Database,
-    ) -> Result<Vec<Version>, mongodb::error::Error> {
-        let filter = doc! { "_id": { "$gt": id } };
-        let sort = doc! {"_id": 1};
-        let limit = limit.unwrap_or(0);
+    ) -> Result<Vec<Version>, RepositoryError>;
 
-        let cursor = match db
-            .collection::<Version>(&self.collection)
-            .find(filter)
-            .limit(limit)
-            .sort(sort)
-            .await
-        {
-            Ok(d) => d,
-            Err(e) => return Err(e),
-        };
+    /// Check whether a Version with the provided semantic version exists
+    ///
+    /// # Arguments
+    ///
+    /// * `semver` - The semantic version
+    /// * `exclude_id` - The optional ID of a Version to ignore
+    ///
+    /// # Returns
+    ///
+    /// A Result containing true if another Version with the semantic version exists, otherwise false
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying store fails
+    async fn exists_by_semver(
+        &self,
+        semver: &SemVer,
+        exclude_id: Option<&str>,
+    ) -> Result<bool, RepositoryError>;
 
-        Ok(cursor.try_collect().await.unwrap_or_else(|_| vec![]))
-    }
+    /// Insert a new Version
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The Version to insert
+    ///
+    /// # Returns
+    ///
+    /// An empty Result if the Version was inserted
+    ///
+    /// # Errors
+    ///
+    /// Returns a duplicate error if a Version with the same ID or the same semantic version, ignoring
+    /// build metadata, already exists, or an error if the underlying store fails
+    async fn create(&self, version: &Version) -> Result<(), RepositoryError>;
+
+    /// Replace the name, description, semantic version, platforms and modification timestamp of a Version
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The new state of the Version, identified by its ID
+    ///
+    /// # Returns
+    ///
+    /// A result containing an Option with the updated Version, or None if the Version was not found
+    ///
+    /// # Errors
+    ///
+    /// Returns a duplicate error if another Version has the same semantic version, ignoring build
+    /// metadata, or an error if the underlying store fails
+    async fn update(&self, version: &Version) -> Result<Option<Version>, RepositoryError>;
+
+    /// Delete a single Version by its ID
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the Version
+    ///
+    /// # Returns
+    ///
+    /// A Result containing true if the Version was deleted, or false if it was not found
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying store fails
+    async fn delete_by_id(&self, id: &str) -> Result<bool, RepositoryError>;
 }
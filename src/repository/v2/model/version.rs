@@ -1,3 +1,4 @@
+use crate::repository::semver::SemVer;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -10,7 +11,7 @@ pub struct Version {
     pub updated_at: String,
     pub name: Option<String>,
     pub description: Option<String>,
-    pub semver: String,
+    pub semver: SemVer,
     pub platforms: Vec<Platform>,
 }
 
@@ -0,0 +1,2 @@
+pub(crate) mod dao;
+pub(crate) mod model;
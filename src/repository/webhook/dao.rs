@@ -0,0 +1,8 @@
+#[cfg(test)]
+pub(crate) mod in_memory_webhook_delivery_repository;
+#[cfg(test)]
+pub(crate) mod in_memory_webhook_repository;
+pub(crate) mod mongo_webhook_delivery_repository;
+pub(crate) mod mongo_webhook_repository;
+pub(crate) mod webhook_delivery_repository;
+pub(crate) mod webhook_repository;
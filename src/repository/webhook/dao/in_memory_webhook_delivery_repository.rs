@@ -0,0 +1,62 @@
+use crate::errors::repository_error::RepositoryError;
+use crate::repository::in_memory::effective_limit;
+use crate::repository::webhook::dao::webhook_delivery_repository::WebhookDeliveryRepository;
+use crate::repository::webhook::model::webhook_delivery::{DeliveryStatus, WebhookDelivery};
+use async_trait::async_trait;
+use std::sync::RwLock;
+
+/// A WebhookDeliveryRepository that keeps WebhookDelivery records in memory
+#[derive(Default)]
+pub struct InMemoryWebhookDeliveryRepository {
+    deliveries: RwLock<Vec<WebhookDelivery>>,
+}
+
+#[async_trait]
+impl WebhookDeliveryRepository for InMemoryWebhookDeliveryRepository {
+    async fn create(&self, delivery: &WebhookDelivery) -> Result<(), RepositoryError> {
+        self.deliveries.write().unwrap().push(delivery.clone());
+        Ok(())
+    }
+
+    async fn claim_due(
+        &self,
+        now: &str,
+        lease_until: &str,
+    ) -> Result<Option<WebhookDelivery>, RepositoryError> {
+        let mut deliveries = self.deliveries.write().unwrap();
+        let due = deliveries
+            .iter_mut()
+            .filter(|d| d.status == DeliveryStatus::Pending && d.next_attempt_at.as_str() <= now)
+            .min_by(|a, b| a.next_attempt_at.cmp(&b.next_attempt_at));
+
+        Ok(due.map(|d| {
+            d.next_attempt_at = lease_until.to_string();
+            d.clone()
+        }))
+    }
+
+    async fn update(&self, delivery: &WebhookDelivery) -> Result<(), RepositoryError> {
+        let mut deliveries = self.deliveries.write().unwrap();
+        if let Some(existing) = deliveries.iter_mut().find(|d| d.id == delivery.id) {
+            *existing = delivery.clone();
+        }
+        Ok(())
+    }
+
+    async fn find_by_webhook_id(
+        &self,
+        webhook_id: &str,
+        limit: Option<i64>,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+        Ok(self
+            .deliveries
+            .read()
+            .unwrap()
+            .iter()
+            .rev()
+            .filter(|d| d.webhook_id == webhook_id)
+            .take(effective_limit(limit))
+            .cloned()
+            .collect())
+    }
+}
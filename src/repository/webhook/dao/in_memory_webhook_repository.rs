@@ -0,0 +1,45 @@
+use crate::errors::repository_error::RepositoryError;
+use crate::repository::webhook::dao::webhook_repository::WebhookRepository;
+use crate::repository::webhook::model::webhook::Webhook;
+use async_trait::async_trait;
+use std::sync::RwLock;
+
+/// A WebhookRepository that keeps Webhooks in memory, in the order they were created
+#[derive(Default)]
+pub struct InMemoryWebhookRepository {
+    webhooks: RwLock<Vec<Webhook>>,
+}
+
+#[async_trait]
+impl WebhookRepository for InMemoryWebhookRepository {
+    async fn get_by_id(&self, id: &str) -> Result<Option<Webhook>, RepositoryError> {
+        Ok(self
+            .webhooks
+            .read()
+            .unwrap()
+            .iter()
+            .find(|w| w.id == id)
+            .cloned())
+    }
+
+    async fn get_all(&self) -> Result<Vec<Webhook>, RepositoryError> {
+        Ok(self.webhooks.read().unwrap().clone())
+    }
+
+    async fn create(&self, webhook: &Webhook) -> Result<(), RepositoryError> {
+        let mut webhooks = self.webhooks.write().unwrap();
+        if webhooks.iter().any(|w| w.id == webhook.id) {
+            return Err(RepositoryError::Duplicate);
+        }
+
+        webhooks.push(webhook.clone());
+        Ok(())
+    }
+
+    async fn delete_by_id(&self, id: &str) -> Result<bool, RepositoryError> {
+        let mut webhooks = self.webhooks.write().unwrap();
+        let count = webhooks.len();
+        webhooks.retain(|w| w.id != id);
+        Ok(webhooks.len() < count)
+    }
+}
@@ -0,0 +1,78 @@
+use crate::errors::repository_error::RepositoryError;
+use crate::repository::webhook::dao::webhook_delivery_repository::WebhookDeliveryRepository;
+use crate::repository::webhook::model::webhook_delivery::WebhookDelivery;
+use async_trait::async_trait;
+use futures::TryStreamExt;
+use mongodb::bson::doc;
+use mongodb::options::ReturnDocument;
+use mongodb::{Collection, Database};
+
+/// A WebhookDeliveryRepository that stores WebhookDelivery records in a MongoDB collection
+#[derive(Clone)]
+pub struct MongoWebhookDeliveryRepository {
+    collection: Collection<WebhookDelivery>,
+}
+
+impl MongoWebhookDeliveryRepository {
+    /// Initialize a new MongoWebhookDeliveryRepository
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - The MongoDB database
+    /// * `collection` - The name of the collection
+    ///
+    /// # Returns
+    ///
+    /// A new instance of MongoWebhookDeliveryRepository
+    pub fn new(db: &Database, collection: &str) -> Self {
+        Self {
+            collection: db.collection::<WebhookDelivery>(collection),
+        }
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryRepository for MongoWebhookDeliveryRepository {
+    async fn create(&self, delivery: &WebhookDelivery) -> Result<(), RepositoryError> {
+        self.collection.insert_one(delivery).await?;
+        Ok(())
+    }
+
+    async fn claim_due(
+        &self,
+        now: &str,
+        lease_until: &str,
+    ) -> Result<Option<WebhookDelivery>, RepositoryError> {
+        let filter = doc! { "status": "pending", "nextAttemptAt": { "$lte": now } };
+        let update = doc! { "$set": { "nextAttemptAt": lease_until } };
+
+        Ok(self
+            .collection
+            .find_one_and_update(filter, update)
+            .sort(doc! { "nextAttemptAt": 1 })
+            .return_document(ReturnDocument::After)
+            .await?)
+    }
+
+    async fn update(&self, delivery: &WebhookDelivery) -> Result<(), RepositoryError> {
+        self.collection
+            .replace_one(doc! { "_id": &delivery.id }, delivery)
+            .await?;
+        Ok(())
+    }
+
+    async fn find_by_webhook_id(
+        &self,
+        webhook_id: &str,
+        limit: Option<i64>,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+        let cursor = self
+            .collection
+            .find(doc! { "webhookId": webhook_id })
+            .sort(doc! { "createdAt": -1 })
+            .limit(limit.unwrap_or(0))
+            .await?;
+
+        Ok(cursor.try_collect().await?)
+    }
+}
@@ -0,0 +1,58 @@
+use crate::errors::repository_error::RepositoryError;
+use crate::repository::webhook::dao::webhook_repository::WebhookRepository;
+use crate::repository::webhook::model::webhook::Webhook;
+use async_trait::async_trait;
+use futures::TryStreamExt;
+use mongodb::bson::doc;
+use mongodb::{Collection, Database};
+
+/// A WebhookRepository that stores Webhooks in a MongoDB collection
+#[derive(Clone)]
+pub struct MongoWebhookRepository {
+    collection: Collection<Webhook>,
+}
+
+impl MongoWebhookRepository {
+    /// Initialize a new MongoWebhookRepository
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - The MongoDB database
+    /// * `collection` - The name of the collection
+    ///
+    /// # Returns
+    ///
+    /// A new instance of MongoWebhookRepository
+    pub fn new(db: &Database, collection: &str) -> Self {
+        Self {
+            collection: db.collection::<Webhook>(collection),
+        }
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for MongoWebhookRepository {
+    async fn get_by_id(&self, id: &str) -> Result<Option<Webhook>, RepositoryError> {
+        Ok(self.collection.find_one(doc! { "_id": id }).await?)
+    }
+
+    async fn get_all(&self) -> Result<Vec<Webhook>, RepositoryError> {
+        let cursor = self
+            .collection
+            .find(doc! {})
+            .sort(doc! { "createdAt": 1 })
+            .await?;
+
+        Ok(cursor.try_collect().await?)
+    }
+
+    async fn create(&self, webhook: &Webhook) -> Result<(), RepositoryError> {
+        self.collection.insert_one(webhook).await?;
+        Ok(())
+    }
+
+    async fn delete_by_id(&self, id: &str) -> Result<bool, RepositoryError> {
+        let res = self.collection.delete_one(doc! { "_id": id }).await?;
+        Ok(res.deleted_count > 0)
+    }
+}
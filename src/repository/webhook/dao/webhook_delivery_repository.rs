@@ -0,0 +1,64 @@
+use crate::errors::repository_error::RepositoryError;
+use crate::repository::webhook::model::webhook_delivery::WebhookDelivery;
+use async_trait::async_trait;
+
+/// A store of WebhookDelivery records, which doubles as the retry queue
+#[async_trait]
+pub trait WebhookDeliveryRepository: Send + Sync {
+    /// Store a new WebhookDelivery
+    ///
+    /// # Arguments
+    ///
+    /// * `delivery` - The WebhookDelivery to store
+    ///
+    /// # Returns
+    ///
+    /// An empty result, or an error if the underlying store fails
+    async fn create(&self, delivery: &WebhookDelivery) -> Result<(), RepositoryError>;
+
+    /// Claim the pending WebhookDelivery that has been due the longest
+    ///
+    /// The next attempt of the claimed delivery is atomically postponed, so other instances of
+    /// the API do not send it concurrently and it is retried if this instance stops.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time
+    /// * `lease_until` - The time until which the delivery is claimed
+    ///
+    /// # Returns
+    ///
+    /// A result containing an Option with the claimed WebhookDelivery, or None if nothing is due
+    async fn claim_due(
+        &self,
+        now: &str,
+        lease_until: &str,
+    ) -> Result<Option<WebhookDelivery>, RepositoryError>;
+
+    /// Replace the state of an existing WebhookDelivery
+    ///
+    /// # Arguments
+    ///
+    /// * `delivery` - The new state of the WebhookDelivery, identified by its ID
+    ///
+    /// # Returns
+    ///
+    /// An empty result, or an error if the underlying store fails
+    async fn update(&self, delivery: &WebhookDelivery) -> Result<(), RepositoryError>;
+
+    /// Find the most recent WebhookDelivery records of a Webhook
+    ///
+    /// # Arguments
+    ///
+    /// * `webhook_id` - The ID of the Webhook
+    /// * `limit` - The maximum number of WebhookDelivery records to return
+    ///
+    /// # Returns
+    ///
+    /// A result containing a Vec of WebhookDelivery records, newest first
+    async fn find_by_webhook_id(
+        &self,
+        webhook_id: &str,
+        limit: Option<i64>,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError>;
+}
@@ -0,0 +1,47 @@
+use crate::errors::repository_error::RepositoryError;
+use crate::repository::webhook::model::webhook::Webhook;
+use async_trait::async_trait;
+
+/// A store of Webhook subscriptions
+#[async_trait]
+pub trait WebhookRepository: Send + Sync {
+    /// Find a single Webhook by its ID
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the Webhook
+    ///
+    /// # Returns
+    ///
+    /// A result containing an Option with the Webhook if found, or None if not found, or an error if the underlying store fails
+    async fn get_by_id(&self, id: &str) -> Result<Option<Webhook>, RepositoryError>;
+
+    /// Find all Webhooks sorted by their creation date
+    ///
+    /// # Returns
+    ///
+    /// A result containing a Vec of Webhooks, or an error if the underlying store fails
+    async fn get_all(&self) -> Result<Vec<Webhook>, RepositoryError>;
+
+    /// Store a new Webhook
+    ///
+    /// # Arguments
+    ///
+    /// * `webhook` - The Webhook to store
+    ///
+    /// # Returns
+    ///
+    /// An empty result, or a duplicate error if a Webhook with the same ID already exists
+    async fn create(&self, webhook: &Webhook) -> Result<(), RepositoryError>;
+
+    /// Delete a Webhook by its ID
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the Webhook
+    ///
+    /// # Returns
+    ///
+    /// A result containing true if a Webhook was deleted, or false if it did not exist
+    async fn delete_by_id(&self, id: &str) -> Result<bool, RepositoryError>;
+}
@@ -0,0 +1,2 @@
+pub(crate) mod webhook;
+pub(crate) mod webhook_delivery;
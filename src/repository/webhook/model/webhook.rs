@@ -0,0 +1,38 @@
+use crate::repository::application::model::release_channel::ReleaseChannel;
+use serde::{Deserialize, Serialize};
+
+/// A subscriber that is notified when a Release is published
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Webhook {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub url: String,
+    /// The shared secret with which the payloads are signed
+    pub secret: String,
+    /// The ID of the only Application to be notified about, or None for every Application
+    #[serde(rename = "applicationId")]
+    pub application_id: Option<String>,
+    /// The release channel whose visible Releases to be notified about, or None for every Release
+    pub channel: Option<ReleaseChannel>,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+}
+
+impl Webhook {
+    /// Check whether this Webhook subscribes to a Release of an Application
+    ///
+    /// # Arguments
+    ///
+    /// * `application_id` - The ID of the Application
+    /// * `channel` - The channel of the Release
+    ///
+    /// # Returns
+    ///
+    /// True if both the application and channel filters match, otherwise false
+    pub fn matches(&self, application_id: &str, channel: &ReleaseChannel) -> bool {
+        self.application_id
+            .as_deref()
+            .is_none_or(|id| id == application_id)
+            && self.channel.as_ref().is_none_or(|c| c.includes(channel))
+    }
+}
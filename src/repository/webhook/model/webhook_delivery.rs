@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+/// The state of a WebhookDelivery
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DeliveryStatus {
+    /// The payload has not been accepted yet and will be (re)sent at `nextAttemptAt`
+    Pending,
+    /// The subscriber accepted the payload
+    Delivered,
+    /// Every attempt failed, or the Webhook was deleted
+    Failed,
+}
+
+/// A payload that is sent, or was sent, to a Webhook
+///
+/// Timestamps are RFC 3339 strings in UTC with millisecond precision, so they sort chronologically.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WebhookDelivery {
+    #[serde(rename = "_id")]
+    pub id: String,
+    #[serde(rename = "webhookId")]
+    pub webhook_id: String,
+    pub event: String,
+    /// The JSON body, which is signed as-is on every attempt
+    pub payload: String,
+    pub status: DeliveryStatus,
+    pub attempts: u32,
+    #[serde(rename = "nextAttemptAt")]
+    pub next_attempt_at: String,
+    #[serde(rename = "lastStatusCode")]
+    pub last_status_code: Option<u16>,
+    #[serde(rename = "lastError")]
+    pub last_error: Option<String>,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: String,
+}
@@ -1,13 +1,38 @@
+use crate::services::api_key::api_key_service::ApiKeyService;
 use crate::services::application::application_service::ApplicationService;
+use crate::services::cache::cache_invalidation_service::CacheInvalidationService;
+use crate::services::event::event_service::EventService;
+use crate::services::health::health_service::HealthService;
+use crate::services::info::info_service::InfoService;
+use crate::services::jwt::jwt_service::JwtService;
+use crate::services::metrics::metrics_service::MetricsService;
 use crate::services::version::version_service::VersionService;
+use crate::services::webhook::webhook_service::WebhookService;
 
+pub(crate) mod api_key;
 pub(crate) mod application;
+pub(crate) mod cache;
+pub(crate) mod event;
+pub(crate) mod health;
+pub(crate) mod info;
+pub(crate) mod jwt;
+pub(crate) mod metrics;
+pub(crate) mod validation;
 pub(crate) mod version;
+pub(crate) mod webhook;
 
 #[derive(Clone)]
 pub struct Services {
     pub application_service: ApplicationService,
     pub version_service: VersionService,
+    pub api_key_service: ApiKeyService,
+    pub jwt_service: JwtService,
+    pub health_service: HealthService,
+    pub metrics_service: MetricsService,
+    pub info_service: InfoService,
+    pub event_service: EventService,
+    pub webhook_service: WebhookService,
+    pub cache_invalidation_service: Option<CacheInvalidationService>,
 }
 
 impl Services {
@@ -17,14 +42,42 @@ impl Services {
     ///
     /// * `application_service` - An instance of `ApplicationService`
     /// * `version_service` - An instance of `VersionService`
+    /// * `api_key_service` - An instance of `ApiKeyService`
+    /// * `jwt_service` - An instance of `JwtService`
+    /// * `health_service` - An instance of `HealthService`
+    /// * `metrics_service` - An instance of `MetricsService`
+    /// * `info_service` - An instance of `InfoService`
+    /// * `event_service` - An instance of `EventService`
+    /// * `webhook_service` - An instance of `WebhookService`
+    /// * `cache_invalidation_service` - An optional instance of `CacheInvalidationService`, which is None if changes made elsewhere are not followed
     ///
     /// # Returns
     ///
     /// A new instance of `Services`
-    pub fn new(application_service: ApplicationService, version_service: VersionService) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        application_service: ApplicationService,
+        version_service: VersionService,
+        api_key_service: ApiKeyService,
+        jwt_service: JwtService,
+        health_service: HealthService,
+        metrics_service: MetricsService,
+        info_service: InfoService,
+        event_service: EventService,
+        webhook_service: WebhookService,
+        cache_invalidation_service: Option<CacheInvalidationService>,
+    ) -> Self {
         Services {
             application_service,
             version_service,
+            api_key_service,
+            jwt_service,
+            health_service,
+            metrics_service,
+            info_service,
+            event_service,
+            webhook_service,
+            cache_invalidation_service,
         }
     }
 }
@@ -0,0 +1,137 @@
+use crate::errors::repository_error::RepositoryError;
+use crate::repository::api_key::dao::api_key_repository::ApiKeyRepository;
+use crate::repository::api_key::model::api_key::ApiKey;
+use log::{info, warn};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct ApiKeyService {
+    api_key_repository: Arc<dyn ApiKeyRepository>,
+}
+
+impl ApiKeyService {
+    /// Initialize a new ApiKeyService
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key_repository` - The `ApiKeyRepository` to look up ApiKey records in
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `ApiKeyService`
+    pub fn new(api_key_repository: Arc<dyn ApiKeyRepository>) -> Self {
+        Self { api_key_repository }
+    }
+
+    /// Hash the secret of an API key
+    ///
+    /// Only hashes of secrets are stored, so a leaked collection cannot be used to authenticate.
+    ///
+    /// # Arguments
+    ///
+    /// * `secret` - The secret that was presented by a client
+    ///
+    /// # Returns
+    ///
+    /// The hex-encoded SHA-256 hash of the secret
+    pub fn hash(secret: &str) -> String {
+        hex::encode(Sha256::digest(secret.as_bytes()))
+    }
+
+    /// Find the active ApiKey that belongs to a secret
+    ///
+    /// # Arguments
+    ///
+    /// * `secret` - The secret that was presented by a client
+    ///
+    /// # Returns
+    ///
+    /// A result containing an Option with the ApiKey, or None if the secret is unknown, revoked or expired
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository fails
+    pub async fn authenticate(&self, secret: &str) -> Result<Option<ApiKey>, RepositoryError> {
+        let api_key = match self
+            .api_key_repository
+            .find_by_hash(&Self::hash(secret))
+            .await?
+        {
+            Some(api_key) => api_key,
+            None => {
+                warn!("Rejected unknown API key");
+                return Ok(None);
+            }
+        };
+
+        if api_key.revoked.unwrap_or(false) {
+            warn!("Rejected revoked API key {}", api_key.id);
+            return Ok(None);
+        }
+
+        if let Some(expires_at) = &api_key.expires_at {
+            match chrono::DateTime::parse_from_rfc3339(expires_at) {
+                Ok(expires_at) if expires_at > chrono::Utc::now() => {}
+                _ => {
+                    warn!("Rejected expired API key {}", api_key.id);
+                    return Ok(None);
+                }
+            }
+        }
+
+        info!("Authenticated API key {}", api_key.id);
+        Ok(Some(api_key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::api_key::dao::in_memory_api_key_repository::InMemoryApiKeyRepository;
+
+    fn api_key(id: &str, secret: &str, expires_at: Option<&str>, revoked: bool) -> ApiKey {
+        ApiKey {
+            id: id.to_string(),
+            name: id.to_string(),
+            hash: ApiKeyService::hash(secret),
+            scopes: vec![],
+            created_at: String::from("2026-01-01T00:00:00Z"),
+            expires_at: expires_at.map(String::from),
+            revoked: Some(revoked),
+        }
+    }
+
+    #[actix_web::test]
+    async fn authenticate_only_accepts_active_keys() {
+        let service = ApiKeyService::new(Arc::new(InMemoryApiKeyRepository::with(vec![
+            api_key("active", "active-secret", None, false),
+            api_key("revoked", "revoked-secret", None, true),
+            api_key(
+                "expired",
+                "expired-secret",
+                Some("2000-01-01T00:00:00Z"),
+                false,
+            ),
+        ])));
+
+        let active = service.authenticate("active-secret").await.unwrap();
+
+        assert_eq!(active.map(|k| k.id).as_deref(), Some("active"));
+        assert!(
+            service
+                .authenticate("revoked-secret")
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            service
+                .authenticate("expired-secret")
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(service.authenticate("unknown").await.unwrap().is_none());
+    }
+}
@@ -1,11 +1,42 @@
+use crate::errors::repository_error::RepositoryError;
+use crate::errors::service_error::ServiceError;
 use crate::repository::application::dao::application_repository::ApplicationRepository;
-use crate::repository::application::model::application::Application;
-use log::info;
-use mongodb::Database;
+use crate::repository::application::model::application::{
+    Application, ApplicationPlatform, Release,
+};
+use crate::repository::application::model::application_changes::ApplicationChanges;
+use crate::repository::application::model::release_channel::ReleaseChannel;
+use crate::repository::semver::SemVer;
+use crate::services::cache::ttl_cache::{CacheConfig, TtlCache};
+use crate::services::event::event_service::{EventKind, EventService, PublishedRelease};
+use crate::services::metrics::metrics_service::MetricsService;
+use crate::services::validation::{require_absolute_url, require_not_blank};
+use crate::services::webhook::webhook_service::WebhookService;
+use log::{error, info};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// The outcome of comparing a client's current version against the available releases
+pub enum UpdateCheck {
+    /// The application does not exist or has no releases for the requested platform and architecture
+    NotFound,
+    /// The client already runs the newest applicable release
+    UpToDate,
+    /// A newer release is available
+    Available(Box<Release>),
+}
+
+/// The key of a cached page of Applications: the ID to start after and the limit
+type PageKey = (Option<String>, Option<i64>);
 
 #[derive(Clone)]
 pub struct ApplicationService {
-    application_repository: ApplicationRepository,
+    application_repository: Arc<dyn ApplicationRepository>,
+    applications: TtlCache<String, Option<Application>>,
+    application_pages: TtlCache<PageKey, Vec<Application>>,
+    event_service: Option<EventService>,
+    changes_followed: bool,
+    webhook_service: Option<WebhookService>,
 }
 
 impl ApplicationService {
@@ -13,23 +44,114 @@ impl ApplicationService {
     ///
     /// # Arguments
     ///
-    /// * `application_repository` - An instance of `ApplicationRepository`
+    /// * `application_repository` - The `ApplicationRepository` to store Applications in
     ///
     /// # Returns
     ///
-    /// A new instance of `ApplicationService`
-    pub fn new(application_repository: ApplicationRepository) -> Self {
+    /// A new instance of `ApplicationService` that does not cache Applications
+    pub fn new(application_repository: Arc<dyn ApplicationRepository>) -> Self {
         Self {
             application_repository,
+            applications: TtlCache::new("applications", CacheConfig::disabled()),
+            application_pages: TtlCache::new("application_pages", CacheConfig::disabled()),
+            event_service: None,
+            changes_followed: false,
+            webhook_service: None,
+        }
+    }
+
+    /// Cache Applications and pages of Applications in memory
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The TTL and maximum number of entries of each cache
+    /// * `metrics_service` - The `MetricsService` to count cache hits and misses with
+    ///
+    /// # Returns
+    ///
+    /// The ApplicationService with caching enabled
+    pub fn with_cache(mut self, config: CacheConfig, metrics_service: &MetricsService) -> Self {
+        self.applications =
+            TtlCache::new("applications", config).with_metrics(metrics_service.clone());
+        self.application_pages =
+            TtlCache::new("application_pages", config).with_metrics(metrics_service.clone());
+        self
+    }
+
+    /// Publish an event whenever an Application or one of its Releases changes
+    ///
+    /// # Arguments
+    ///
+    /// * `event_service` - The `EventService` to publish the events to
+    ///
+    /// # Returns
+    ///
+    /// The ApplicationService that publishes its changes
+    pub fn with_events(mut self, event_service: &EventService) -> Self {
+        self.event_service = Some(event_service.clone());
+        self
+    }
+
+    /// Leave the events about created, updated and deleted Applications and published Releases
+    /// to the change stream of the application collection
+    ///
+    /// The change stream also reports changes made elsewhere, so publishing them here as well
+    /// would send every change twice.
+    ///
+    /// # Returns
+    ///
+    /// The ApplicationService that does not publish events itself
+    pub fn with_followed_changes(mut self) -> Self {
+        self.changes_followed = true;
+        self
+    }
+
+    /// Notify registered Webhooks whenever a Release is published
+    ///
+    /// # Arguments
+    ///
+    /// * `webhook_service` - The `WebhookService` to queue the notifications with
+    ///
+    /// # Returns
+    ///
+    /// The ApplicationService that notifies Webhooks
+    pub fn with_webhooks(mut self, webhook_service: &WebhookService) -> Self {
+        self.webhook_service = Some(webhook_service.clone());
+        self
+    }
+
+    /// Publish an event if events are enabled and the change stream does not publish it
+    fn publish(&self, kind: EventKind, id: &str, release: Option<PublishedRelease>) {
+        if self.changes_followed {
+            return;
+        }
+
+        if let Some(event_service) = &self.event_service {
+            event_service.publish(kind, id, release);
         }
     }
 
+    /// Remove an Application and every page of Applications from the caches
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the Application that was modified
+    pub fn invalidate(&self, id: &str) {
+        self.applications.invalidate(&id.to_string());
+        self.application_pages.invalidate_all();
+    }
+
+    /// Remove every Application and every page of Applications from the caches
+    pub fn invalidate_all(&self) {
+        self.applications.invalidate_all();
+        self.application_pages.invalidate_all();
+    }
+
     /// Find a single Application by its ID
     ///
     /// # Arguments
     ///
     /// * `id` - The ID of the Application
-    /// * `db` - The MongoDB database
     ///
     /// # Returns
     ///
@@ -37,14 +159,12 @@ impl ApplicationService {
     ///
     /// # Errors
     ///
-    /// Returns an error if the MongoDB query fails
-    pub async fn get_by_id(
-        &self,
-        id: &str,
-        db: &Database,
-    ) -> Result<Option<Application>, mongodb::error::Error> {
+    /// Returns an error if the repository fails
+    pub async fn get_by_id(&self, id: &str) -> Result<Option<Application>, RepositoryError> {
         info!("Retrieving application with ID: {}", id);
-        self.application_repository.get_by_id(id, db).await
+        self.applications
+            .get_or_load(id.to_string(), || self.application_repository.get_by_id(id))
+            .await
     }
 
     /// Find all Applications sorted by ID
@@ -52,7 +172,6 @@ impl ApplicationService {
     /// # Arguments
     ///
     /// * `limit` - The maximum number of Applications to return
-    /// * `db` - The MongoDB database
     ///
     /// # Returns
     ///
@@ -60,18 +179,19 @@ impl ApplicationService {
     ///
     /// # Errors
     ///
-    /// Returns an error if the MongoDB query fails
+    /// Returns an error if the repository fails
     pub async fn get_all_sorted_by_id(
         &self,
         limit: Option<i64>,
-        db: &Database,
-    ) -> Result<Vec<Application>, mongodb::error::Error> {
+    ) -> Result<Vec<Application>, RepositoryError> {
         info!(
             "Retrieving all applications sorted by ID with limit: {}",
             limit.unwrap_or(0)
         );
-        self.application_repository
-            .get_all_sorted_by_id(limit, db)
+        self.application_pages
+            .get_or_load((None, limit), || {
+                self.application_repository.get_all_sorted_by_id(limit)
+            })
             .await
     }
 
@@ -88,20 +208,711 @@ impl ApplicationService {
     ///
     /// # Errors
     ///
-    /// Returns an error if the MongoDB query fails
+    /// Returns an error if the repository fails
     pub async fn get_all_with_id_greater_than(
         &self,
         id: &str,
         limit: Option<i64>,
-        db: &Database,
-    ) -> Result<Vec<Application>, mongodb::error::Error> {
+    ) -> Result<Vec<Application>, RepositoryError> {
         info!(
             "Fetching all applications with ID greater than: {} with limit: {}",
             id,
             limit.unwrap_or(0)
         );
-        self.application_repository
-            .get_all_with_id_greater_than(id, limit, db)
+        self.application_pages
+            .get_or_load((Some(id.to_string()), limit), || {
+                self.application_repository
+                    .get_all_with_id_greater_than(id, limit)
+            })
             .await
     }
+
+    /// Create a new Application
+    ///
+    /// The creation and modification timestamps are set to the current time.
+    ///
+    /// # Arguments
+    ///
+    /// * `application` - The Application to create
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the created Application
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the Application is invalid, a conflict error if an
+    /// Application with the same ID already exists, or a database error if the repository fails
+    pub async fn create(&self, mut application: Application) -> Result<Application, ServiceError> {
+        info!("Creating application with ID: {}", application.id);
+
+        require_not_blank("id", &application.id)?;
+        require_not_blank("name", &application.name)?;
+        if let Some(platforms) = &application.platforms {
+            Self::validate_platforms(platforms)?;
+        }
+
+        let now = chrono::Utc::now().to_rfc3339();
+        application.created_at = now.clone();
+        application.updated_at = now;
+
+        let created = self.application_repository.create(&application).await;
+        self.invalidate(&application.id);
+
+        match created {
+            Ok(()) => {
+                self.publish(EventKind::ApplicationCreated, &application.id, None);
+                Ok(application)
+            }
+            Err(RepositoryError::Duplicate) => Err(ServiceError::Conflict(format!(
+                "An application with ID {} already exists",
+                application.id
+            ))),
+            Err(e) => Err(ServiceError::Database(e)),
+        }
+    }
+
+    /// Apply a set of changes to an existing Application
+    ///
+    /// The modification timestamp is set to the current time, the creation timestamp is retained.
+    /// Webhooks are notified about every Release that the new platforms add to an architecture.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the Application
+    /// * `changes` - The changes to apply
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the updated Application
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the changes are invalid, a not found error if the
+    /// Application does not exist, or a database error if the repository fails
+    pub async fn update(
+        &self,
+        id: &str,
+        changes: ApplicationChanges,
+    ) -> Result<Application, ServiceError> {
+        info!("Updating application with ID: {}", id);
+
+        if let Some(name) = &changes.name {
+            require_not_blank("name", name)?;
+        }
+
+        if let Some(Some(platforms)) = &changes.platforms {
+            Self::validate_platforms(platforms)?;
+        }
+
+        let previous = match (&changes.platforms, &self.webhook_service) {
+            (Some(Some(_)), Some(_)) => self.application_repository.get_by_id(id).await?,
+            _ => None,
+        };
+
+        let updated = self
+            .application_repository
+            .update(id, &changes, &chrono::Utc::now().to_rfc3339())
+            .await;
+        self.invalidate(id);
+
+        match updated? {
+            Some(application) => {
+                self.publish(EventKind::ApplicationUpdated, id, None);
+                if let Some(previous) = &previous {
+                    for (platform, architecture, release) in
+                        Self::added_releases(previous, &application)
+                    {
+                        self.notify_webhooks(id, platform, architecture, release)
+                            .await;
+                    }
+                }
+                Ok(application)
+            }
+            None => Err(ServiceError::NotFound(format!(
+                "Application with ID {} was not found",
+                id
+            ))),
+        }
+    }
+
+    /// Delete an Application by its ID
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the Application
+    ///
+    /// # Returns
+    ///
+    /// An empty Result if the Application was deleted
+    ///
+    /// # Errors
+    ///
+    /// Returns a not found error if the Application does not exist, or a database error if the repository fails
+    pub async fn delete(&self, id: &str) -> Result<(), ServiceError> {
+        info!("Deleting application with ID: {}", id);
+
+        let deleted = self.application_repository.delete_by_id(id).await;
+        self.invalidate(id);
+
+        if deleted? {
+            self.publish(EventKind::ApplicationDeleted, id, None);
+            Ok(())
+        } else {
+            Err(ServiceError::NotFound(format!(
+                "Application with ID {} was not found",
+                id
+            )))
+        }
+    }
+
+    /// Publish a new Release into an existing Architecture of an Application
+    ///
+    /// Platform and architecture names are matched case-insensitively. The Release is appended
+    /// using a single conditional update, so concurrent publications of the same semantic version
+    /// cannot both succeed.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the Application
+    /// * `platform` - The name of the platform
+    /// * `architecture` - The name of the architecture
+    /// * `release` - The Release to publish
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the published Release
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the Release is invalid, a not found error if the Application,
+    /// platform or architecture does not exist, a conflict error if the semantic version was already
+    /// released, or a database error if the repository fails
+    pub async fn add_release(
+        &self,
+        id: &str,
+        platform: &str,
+        architecture: &str,
+        release: Release,
+    ) -> Result<Release, ServiceError> {
+        info!(
+            "Publishing release {} of application with ID: {} for {}/{}",
+            release.semver, id, platform, architecture
+        );
+
+        Self::validate_release(&release)?;
+
+        let application = match self.application_repository.get_by_id(id).await? {
+            Some(application) => application,
+            None => {
+                return Err(ServiceError::NotFound(format!(
+                    "Application with ID {} was not found",
+                    id
+                )));
+            }
+        };
+
+        let platform = application
+            .platforms
+            .iter()
+            .flatten()
+            .find(|p| p.platform_name.eq_ignore_ascii_case(platform))
+            .ok_or_else(|| {
+                ServiceError::NotFound(format!(
+                    "Platform {} of application with ID {} was not found",
+                    platform, id
+                ))
+            })?;
+
+        let target = platform
+            .architectures
+            .iter()
+            .flatten()
+            .find(|a| a.name.eq_ignore_ascii_case(architecture))
+            .ok_or_else(|| {
+                ServiceError::NotFound(format!(
+                    "Architecture {} of {} was not found",
+                    architecture, platform.platform_name
+                ))
+            })?;
+
+        if target
+            .releases
+            .iter()
+            .flatten()
+            .any(|r| r.semver == release.semver)
+        {
+            return Err(ServiceError::Conflict(format!(
+                "Release {} of {}/{} already exists",
+                release.semver, platform.platform_name, target.name
+            )));
+        }
+
+        let pushed = self
+            .application_repository
+            .push_release(
+                id,
+                &platform.platform_name,
+                &target.name,
+                &release,
+                target.releases.is_none(),
+                &chrono::Utc::now().to_rfc3339(),
+            )
+            .await;
+        self.invalidate(id);
+
+        if !pushed? {
+            return Err(ServiceError::Conflict(format!(
+                "Release {} of {}/{} could not be published because it already exists or the application was modified concurrently",
+                release.semver, platform.platform_name, target.name
+            )));
+        }
+
+        self.publish(
+            EventKind::ReleasePublished,
+            id,
+            Some(PublishedRelease {
+                platform: platform.platform_name.clone(),
+                architecture: target.name.clone(),
+                semver: release.semver.to_string(),
+            }),
+        );
+
+        self.notify_webhooks(id, &platform.platform_name, &target.name, &release)
+            .await;
+
+        Ok(release)
+    }
+
+    /// Queue notifications about a published Release if Webhooks are enabled
+    ///
+    /// The Release was already published, so failing to queue the notifications is only logged.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the Application
+    /// * `platform` - The name of the platform of the Release
+    /// * `architecture` - The name of the architecture of the Release
+    /// * `release` - The published Release
+    async fn notify_webhooks(
+        &self,
+        id: &str,
+        platform: &str,
+        architecture: &str,
+        release: &Release,
+    ) {
+        if let Some(webhook_service) = &self.webhook_service
+            && let Err(e) = webhook_service
+                .enqueue_release(id, platform, architecture, release)
+                .await
+        {
+            error!(
+                "Error queueing webhook deliveries for release {} of application with ID {}: {}",
+                release.semver, id, e
+            );
+        }
+    }
+
+    /// Find the Releases of an updated Application that the previous state of the Application
+    /// did not have in the same platform and architecture
+    ///
+    /// # Arguments
+    ///
+    /// * `previous` - The Application before the update
+    /// * `updated` - The Application after the update
+    ///
+    /// # Returns
+    ///
+    /// The names of the platform and architecture of every added Release, with the Release
+    fn added_releases<'a>(
+        previous: &Application,
+        updated: &'a Application,
+    ) -> Vec<(&'a str, &'a str, &'a Release)> {
+        let mut added = vec![];
+
+        for platform in updated.platforms.iter().flatten() {
+            for architecture in platform.architectures.iter().flatten() {
+                let existing: Vec<&Release> = previous
+                    .platforms
+                    .iter()
+                    .flatten()
+                    .filter(|p| {
+                        p.platform_name
+                            .eq_ignore_ascii_case(&platform.platform_name)
+                    })
+                    .flat_map(|p| p.architectures.iter().flatten())
+                    .filter(|a| a.name.eq_ignore_ascii_case(&architecture.name))
+                    .flat_map(|a| a.releases.iter().flatten())
+                    .collect();
+
+                for release in architecture.releases.iter().flatten() {
+                    if !existing.iter().any(|r| r.semver == release.semver) {
+                        added.push((
+                            platform.platform_name.as_str(),
+                            architecture.name.as_str(),
+                            release,
+                        ));
+                    }
+                }
+            }
+        }
+
+        added
+    }
+
+    /// Validate the nested platforms, architectures and releases of an Application
+    ///
+    /// Platform names must be unique within an Application, architecture names must be unique
+    /// within a platform and semantic versions must be unique within an architecture. Names are
+    /// compared case-insensitively because lookups are case-insensitive as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `platforms` - The platforms to validate
+    ///
+    /// # Returns
+    ///
+    /// An empty Result if the platforms are valid
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error describing the first violation that was found
+    pub fn validate_platforms(platforms: &[ApplicationPlatform]) -> Result<(), ServiceError> {
+        let mut platform_names = HashSet::new();
+
+        for platform in platforms {
+            require_not_blank("platformName", &platform.platform_name)?;
+            if !platform_names.insert(platform.platform_name.to_ascii_lowercase()) {
+                return Err(ServiceError::Validation(format!(
+                    "platform {} is defined more than once",
+                    platform.platform_name
+                )));
+            }
+
+            let mut architecture_names = HashSet::new();
+            for architecture in platform.architectures.iter().flatten() {
+                require_not_blank("architecture name", &architecture.name)?;
+                if !architecture_names.insert(architecture.name.to_ascii_lowercase()) {
+                    return Err(ServiceError::Validation(format!(
+                        "architecture {} of platform {} is defined more than once",
+                        architecture.name, platform.platform_name
+                    )));
+                }
+
+                let mut versions = HashSet::new();
+                for release in architecture.releases.iter().flatten() {
+                    Self::validate_release(release)?;
+                    if !versions.insert(&release.semver) {
+                        return Err(ServiceError::Validation(format!(
+                            "release {} of {}/{} is defined more than once",
+                            release.semver, platform.platform_name, architecture.name
+                        )));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validate a single Release
+    ///
+    /// # Arguments
+    ///
+    /// * `release` - The Release to validate
+    ///
+    /// # Returns
+    ///
+    /// An empty Result if the Release is valid
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if a URL of the Release is not an absolute URL
+    pub fn validate_release(release: &Release) -> Result<(), ServiceError> {
+        require_absolute_url("downloadUrl", &release.download_url)?;
+        if let Some(info_url) = &release.info_url {
+            require_absolute_url("infoUrl", info_url)?;
+        }
+        Ok(())
+    }
+
+    /// Check whether a newer release than the provided version is available for an Application
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the Application
+    /// * `current` - The version the client is currently running
+    /// * `platform` - The name of the platform the client runs on
+    /// * `architecture` - The name of the architecture the client runs on
+    /// * `channel` - The release channel the client is subscribed to
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the UpdateCheck outcome
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository fails
+    pub async fn check_for_update(
+        &self,
+        id: &str,
+        current: &SemVer,
+        platform: &str,
+        architecture: &str,
+        channel: &ReleaseChannel,
+    ) -> Result<UpdateCheck, RepositoryError> {
+        info!(
+            "Checking for updates of application with ID: {} from version {} on {}/{} ({})",
+            id, current, platform, architecture, channel
+        );
+
+        let application = match self.get_by_id(id).await? {
+            Some(application) => application,
+            None => return Ok(UpdateCheck::NotFound),
+        };
+
+        match Self::find_latest_release(&application, Some(platform), Some(architecture), channel) {
+            Some(release) => {
+                if release.semver > *current {
+                    Ok(UpdateCheck::Available(Box::new(release.clone())))
+                } else {
+                    Ok(UpdateCheck::UpToDate)
+                }
+            }
+            None => Ok(UpdateCheck::NotFound),
+        }
+    }
+
+    /// Find the newest Release of an Application
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the Application
+    /// * `platform` - The optional name of the platform to restrict the search to
+    /// * `architecture` - The optional name of the architecture to restrict the search to
+    /// * `channel` - The release channel to take into account
+    ///
+    /// # Returns
+    ///
+    /// A Result containing an Option with the newest Release, or None if the Application or a matching Release is not found
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository fails
+    pub async fn get_latest_release(
+        &self,
+        id: &str,
+        platform: Option<&str>,
+        architecture: Option<&str>,
+        channel: &ReleaseChannel,
+    ) -> Result<Option<Release>, RepositoryError> {
+        info!(
+            "Retrieving latest release of application with ID: {} for {}/{} ({})",
+            id,
+            platform.unwrap_or("*"),
+            architecture.unwrap_or("*"),
+            channel
+        );
+
+        let application = match self.get_by_id(id).await? {
+            Some(application) => application,
+            None => return Ok(None),
+        };
+
+        Ok(Self::find_latest_release(&application, platform, architecture, channel).cloned())
+    }
+
+    /// Remove all Releases of an Application that are not visible on a release channel
+    ///
+    /// # Arguments
+    ///
+    /// * `application` - The Application to filter
+    /// * `channel` - The release channel to retain
+    pub fn retain_channel(application: &mut Application, channel: &ReleaseChannel) {
+        application
+            .platforms
+            .iter_mut()
+            .flatten()
+            .flat_map(|p| p.architectures.iter_mut().flatten())
+            .flat_map(|a| a.releases.iter_mut())
+            .for_each(|releases| releases.retain(|r| channel.includes(&r.effective_channel())));
+    }
+
+    /// Find the Release with the highest semantic version of an Application
+    ///
+    /// Platform and architecture names are matched case-insensitively. When no platform or
+    /// architecture is provided, all of them are taken into account.
+    ///
+    /// # Arguments
+    ///
+    /// * `application` - The Application to search
+    /// * `platform` - The optional name of the platform
+    /// * `architecture` - The optional name of the architecture
+    /// * `channel` - The release channel to take into account
+    ///
+    /// # Returns
+    ///
+    /// An Option containing the newest Release, or None if no Release matches
+    pub fn find_latest_release<'a>(
+        application: &'a Application,
+        platform: Option<&str>,
+        architecture: Option<&str>,
+        channel: &ReleaseChannel,
+    ) -> Option<&'a Release> {
+        application
+            .platforms
+            .iter()
+            .flatten()
+            .filter(|p| platform.is_none_or(|n| p.platform_name.eq_ignore_ascii_case(n)))
+            .flat_map(|p| p.architectures.iter().flatten())
+            .filter(|a| architecture.is_none_or(|n| a.name.eq_ignore_ascii_case(n)))
+            .flat_map(|a| a.releases.iter().flatten())
+            .filter(|r| channel.includes(&r.effective_channel()))
+            .max_by(|a, b| a.semver.cmp(&b.semver))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::application::dao::in_memory_application_repository::InMemoryApplicationRepository;
+    use crate::tests::{applications, release};
+
+    fn service(applications: Vec<Application>) -> ApplicationService {
+        ApplicationService::new(Arc::new(InMemoryApplicationRepository::with(applications)))
+    }
+
+    #[actix_web::test]
+    async fn create_rejects_duplicate_ids() {
+        let service = service(applications());
+        let duplicate = applications()
+            .into_iter()
+            .find(|a| a.id == "deadhash")
+            .unwrap();
+
+        let res = service.create(duplicate).await;
+
+        assert!(matches!(res, Err(ServiceError::Conflict(_))));
+    }
+
+    #[actix_web::test]
+    async fn update_applies_changes_and_keeps_creation_timestamp() {
+        let service = service(applications());
+        let changes = ApplicationChanges {
+            name: Some(String::from("PK Finder")),
+            description: Some(Some(String::from("Find product keys"))),
+            platforms: None,
+        };
+
+        let updated = service.update("deadhash", changes).await.unwrap();
+
+        assert_eq!(updated.name, "PK Finder");
+        assert_eq!(updated.description.as_deref(), Some("Find product keys"));
+        assert_eq!(updated.created_at, "2026-01-01T00:00:00+00:00");
+        assert_ne!(updated.updated_at, updated.created_at);
+        assert!(updated.platforms.is_some());
+    }
+
+    #[actix_web::test]
+    async fn update_of_unknown_application_is_not_found() {
+        let service = service(vec![]);
+
+        let res = service
+            .update("pk-finder", ApplicationChanges::default())
+            .await;
+
+        assert!(matches!(res, Err(ServiceError::NotFound(_))));
+    }
+
+    #[actix_web::test]
+    async fn add_release_matches_names_case_insensitively() {
+        let service = service(applications());
+
+        service
+            .add_release("advanced-passgen", "windows", "ARM64", release("1.0.0"))
+            .await
+            .unwrap();
+        service
+            .add_release("advanced-passgen", "WINDOWS", "arm64", release("1.1.0"))
+            .await
+            .unwrap();
+
+        let latest = service
+            .get_latest_release(
+                "advanced-passgen",
+                Some("Windows"),
+                Some("arm64"),
+                &ReleaseChannel::Stable,
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(latest.semver.to_string(), "1.1.0");
+    }
+
+    #[actix_web::test]
+    async fn add_release_rejects_existing_versions_and_unknown_architectures() {
+        let service = service(applications());
+
+        let duplicate = service
+            .add_release("deadhash", "Windows", "x64", release("1.0.0"))
+            .await;
+        let unknown = service
+            .add_release("deadhash", "Windows", "arm64", release("1.1.0"))
+            .await;
+
+        assert!(matches!(duplicate, Err(ServiceError::Conflict(_))));
+        assert!(matches!(unknown, Err(ServiceError::NotFound(_))));
+    }
+
+    #[actix_web::test]
+    async fn push_release_treats_build_metadata_as_the_same_release() {
+        let repository = InMemoryApplicationRepository::with(applications());
+
+        let pushed = repository
+            .push_release(
+                "deadhash",
+                "Windows",
+                "x64",
+                &release("1.0.0+build.2"),
+                false,
+                "2026-01-02T00:00:00+00:00",
+            )
+            .await
+            .unwrap();
+
+        assert!(!pushed);
+    }
+
+    #[actix_web::test]
+    async fn check_for_update_respects_the_release_channel() {
+        let service = service(applications());
+        let current: SemVer = "1.1.0".parse().unwrap();
+
+        let stable = service
+            .check_for_update(
+                "advanced-passgen",
+                &current,
+                "Windows",
+                "x64",
+                &ReleaseChannel::Stable,
+            )
+            .await
+            .unwrap();
+        let beta = service
+            .check_for_update(
+                "advanced-passgen",
+                &current,
+                "Windows",
+                "x64",
+                &ReleaseChannel::Beta,
+            )
+            .await
+            .unwrap();
+
+        assert!(matches!(stable, UpdateCheck::UpToDate));
+        assert!(
+            matches!(beta, UpdateCheck::Available(r) if r.semver.to_string() == "2.0.0-beta.1")
+        );
+    }
 }
@@ -0,0 +1,2 @@
+pub(crate) mod cache_invalidation_service;
+pub(crate) mod ttl_cache;
@@ -0,0 +1,470 @@
+use crate::errors::repository_error::RepositoryError;
+use crate::repository::change::dao::change_stream_repository::ChangeStreamRepository;
+use crate::repository::change::model::document_change::{ChangeOperation, DocumentChange};
+use crate::services::application::application_service::ApplicationService;
+use crate::services::event::event_service::{EventKind, EventService, PublishedRelease};
+use crate::services::version::version_service::VersionService;
+use futures::StreamExt;
+use log::{info, warn};
+use mongodb::change_stream::event::ResumeToken;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The delay before the first attempt to reopen a change stream that failed
+const MIN_RETRY_DELAY: Duration = Duration::from_secs(1);
+/// The maximum delay between attempts to reopen a change stream
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// Invalidates the cached Applications and Versions when their collections change outside of
+/// this instance of the API, and publishes events about every change to an Application
+#[derive(Clone)]
+pub struct CacheInvalidationService {
+    application_service: ApplicationService,
+    version_service: VersionService,
+    application_changes: Arc<dyn ChangeStreamRepository>,
+    version_changes: Arc<dyn ChangeStreamRepository>,
+    event_service: Option<EventService>,
+}
+
+impl CacheInvalidationService {
+    /// Initialize a new CacheInvalidationService
+    ///
+    /// # Arguments
+    ///
+    /// * `application_service` - The `ApplicationService` whose caches to invalidate
+    /// * `version_service` - The `VersionService` whose caches to invalidate
+    /// * `application_changes` - The `ChangeStreamRepository` of the application collection
+    /// * `version_changes` - The `ChangeStreamRepository` of the version collection
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `CacheInvalidationService`
+    pub fn new(
+        application_service: ApplicationService,
+        version_service: VersionService,
+        application_changes: Arc<dyn ChangeStreamRepository>,
+        version_changes: Arc<dyn ChangeStreamRepository>,
+    ) -> Self {
+        Self {
+            application_service,
+            version_service,
+            application_changes,
+            version_changes,
+            event_service: None,
+        }
+    }
+
+    /// Publish an event whenever an Application is created, updated or deleted, by this or any
+    /// other instance of the API or directly in MongoDB
+    ///
+    /// # Arguments
+    ///
+    /// * `event_service` - The `EventService` to publish the events to
+    ///
+    /// # Returns
+    ///
+    /// The CacheInvalidationService that publishes the changes to Applications
+    pub fn with_events(mut self, event_service: &EventService) -> Self {
+        self.event_service = Some(event_service.clone());
+        self
+    }
+
+    /// Follow the change streams of both collections until the process exits
+    ///
+    /// Streams that fail are reopened with an exponential backoff, resuming after the last
+    /// processed change.
+    pub async fn run(self) {
+        let applications =
+            Self::follow_forever("applications", &*self.application_changes, |change| {
+                self.application_changed(change)
+            });
+        let versions = Self::follow_forever("versions", &*self.version_changes, |change| {
+            self.version_changed(change)
+        });
+
+        futures::join!(applications, versions);
+    }
+
+    /// Invalidate a changed Application and publish an event about the change
+    ///
+    /// An update that added Releases is published as one release-published event per Release
+    /// instead of an application-updated event.
+    ///
+    /// # Arguments
+    ///
+    /// * `change` - The change to the application collection, or None if everything may have changed
+    fn application_changed(&self, change: Option<&DocumentChange>) {
+        match change.and_then(|c| c.document_id.as_deref()) {
+            Some(id) => self.application_service.invalidate(id),
+            None => self.application_service.invalidate_all(),
+        }
+
+        if let Some(event_service) = &self.event_service
+            && let Some(change) = change
+            && let Some(id) = &change.document_id
+        {
+            if change.operation == ChangeOperation::Update && !change.added_releases.is_empty() {
+                for release in &change.added_releases {
+                    event_service.publish(
+                        EventKind::ReleasePublished,
+                        id,
+                        Some(PublishedRelease {
+                            platform: release.platform.clone(),
+                            architecture: release.architecture.clone(),
+                            semver: release.semver.clone(),
+                        }),
+                    );
+                }
+                return;
+            }
+
+            let kind = match change.operation {
+                ChangeOperation::Insert => EventKind::ApplicationCreated,
+                ChangeOperation::Update => EventKind::ApplicationUpdated,
+                ChangeOperation::Delete => EventKind::ApplicationDeleted,
+                ChangeOperation::Invalidate => return,
+            };
+            event_service.publish(kind, id, None);
+        }
+    }
+
+    /// Invalidate a changed Version
+    ///
+    /// # Arguments
+    ///
+    /// * `change` - The change to the version collection, or None if everything may have changed
+    fn version_changed(&self, change: Option<&DocumentChange>) {
+        match change.and_then(|c| c.document_id.as_deref()) {
+            Some(id) => self.version_service.invalidate(id),
+            None => self.version_service.invalidate_all(),
+        }
+    }
+
+    /// Follow a change stream, reopening it whenever it ends or fails
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the collection, used for logging
+    /// * `repository` - The `ChangeStreamRepository` to open the streams with
+    /// * `on_change` - The function that handles a change, or a change to everything if it is None
+    async fn follow_forever(
+        name: &str,
+        repository: &dyn ChangeStreamRepository,
+        on_change: impl Fn(Option<&DocumentChange>),
+    ) {
+        let mut resume_token = None;
+        let mut retry_delay = MIN_RETRY_DELAY;
+
+        loop {
+            match Self::follow(repository, &mut resume_token, &on_change).await {
+                Ok(()) => {
+                    info!("Change stream of {} ended, reopening it", name);
+                    retry_delay = MIN_RETRY_DELAY;
+                }
+                Err(e) => {
+                    warn!(
+                        "Change stream of {} failed, reopening it in {:?}: {}",
+                        name, retry_delay, e
+                    );
+                    actix_web::rt::time::sleep(retry_delay).await;
+                    retry_delay = (retry_delay * 2).min(MAX_RETRY_DELAY);
+                }
+            }
+        }
+    }
+
+    /// Open a change stream once and handle every change until the stream ends
+    ///
+    /// Without a resume token, changes that were made before the stream was opened are unknown,
+    /// so everything is treated as changed. The resume token is kept when the stream fails with a
+    /// transient error, so the next stream resumes after the last processed change. It is only
+    /// discarded when the stream cannot be resumed, for example because the change was removed
+    /// from the oplog, and after the collection was dropped or renamed, because such streams
+    /// cannot be resumed either.
+    ///
+    /// # Arguments
+    ///
+    /// * `repository` - The `ChangeStreamRepository` to open the stream with
+    /// * `resume_token` - The token of the last processed change, updated as changes are processed
+    /// * `on_change` - The function that handles a change, or a change to everything if it is None
+    ///
+    /// # Returns
+    ///
+    /// An empty Result once the stream ended
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stream could not be opened or failed
+    pub async fn follow(
+        repository: &dyn ChangeStreamRepository,
+        resume_token: &mut Option<ResumeToken>,
+        on_change: &impl Fn(Option<&DocumentChange>),
+    ) -> Result<(), RepositoryError> {
+        let mut stream = match repository.watch(resume_token.clone()).await {
+            Ok(stream) => stream,
+            Err(e) => return Err(Self::discard_lost_resume_token(resume_token, e)),
+        };
+
+        if resume_token.is_none() {
+            on_change(None);
+        }
+
+        while let Some(change) = stream.next().await {
+            let change = change.map_err(|e| Self::discard_lost_resume_token(resume_token, e))?;
+            on_change(Some(&change));
+            *resume_token = change.document_id.map(|_| change.resume_token);
+        }
+
+        Ok(())
+    }
+
+    /// Discard the resume token if a change stream failed with an error it cannot resume from
+    ///
+    /// # Arguments
+    ///
+    /// * `resume_token` - The token of the last processed change
+    /// * `error` - The error the stream failed with
+    ///
+    /// # Returns
+    ///
+    /// The error the stream failed with
+    fn discard_lost_resume_token(
+        resume_token: &mut Option<ResumeToken>,
+        error: RepositoryError,
+    ) -> RepositoryError {
+        if error.invalidates_resume_token() {
+            *resume_token = None;
+        }
+
+        error
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::application::dao::application_repository::ApplicationRepository;
+    use crate::repository::application::dao::in_memory_application_repository::InMemoryApplicationRepository;
+    use crate::repository::application::model::application_changes::ApplicationChanges;
+    use crate::repository::change::dao::in_memory_change_stream_repository::InMemoryChangeStreamRepository;
+    use crate::repository::change::model::document_change::AddedRelease;
+    use crate::repository::v2::dao::in_memory_version_repository::InMemoryVersionRepository;
+    use crate::services::cache::ttl_cache::CacheConfig;
+    use crate::services::metrics::metrics_service::MetricsService;
+    use crate::tests::{applications, versions};
+    use futures::StreamExt;
+    use mongodb::bson::{Bson, doc, from_bson};
+    use mongodb::error::{CommandError, ErrorKind};
+    use std::cell::RefCell;
+
+    fn token(data: &str) -> ResumeToken {
+        from_bson(Bson::Document(doc! { "_data": data })).unwrap()
+    }
+
+    fn change(id: Option<&str>, data: &str) -> Result<DocumentChange, RepositoryError> {
+        Ok(DocumentChange {
+            operation: match id {
+                Some(_) => ChangeOperation::Update,
+                None => ChangeOperation::Invalidate,
+            },
+            document_id: id.map(String::from),
+            added_releases: vec![],
+            resume_token: token(data),
+        })
+    }
+
+    fn failure() -> Result<DocumentChange, RepositoryError> {
+        Err(RepositoryError::Database(mongodb::error::Error::custom(
+            "connection reset",
+        )))
+    }
+
+    fn history_lost() -> RepositoryError {
+        let error: CommandError = from_bson(Bson::Document(doc! {
+            "code": 286,
+            "codeName": "ChangeStreamHistoryLost",
+            "errmsg": "Resume of change stream was not possible",
+        }))
+        .unwrap();
+        RepositoryError::Database(ErrorKind::Command(error).into())
+    }
+
+    #[actix_web::test]
+    async fn follow_invalidates_documents_that_changed_elsewhere() {
+        let repository = Arc::new(InMemoryApplicationRepository::with(applications()));
+        let service = ApplicationService::new(repository.clone()).with_cache(
+            CacheConfig::new(Duration::from_secs(60), 10),
+            &MetricsService::new(),
+        );
+        let changes =
+            InMemoryChangeStreamRepository::with(vec![vec![change(Some("deadhash"), "1")]]);
+
+        service.get_by_id("deadhash").await.unwrap();
+        let changed = ApplicationChanges {
+            name: Some(String::from("DeadHash 2")),
+            ..Default::default()
+        };
+        repository
+            .update("deadhash", &changed, "2026-02-01T00:00:00+00:00")
+            .await
+            .unwrap();
+        let stale = service.get_by_id("deadhash").await.unwrap().unwrap();
+        CacheInvalidationService::follow(&changes, &mut Some(token("0")), &|change| match change
+            .and_then(|c| c.document_id.as_deref())
+        {
+            Some(id) => service.invalidate(id),
+            None => service.invalidate_all(),
+        })
+        .await
+        .unwrap();
+        let fresh = service.get_by_id("deadhash").await.unwrap().unwrap();
+
+        assert_eq!(stale.name, "deadhash");
+        assert_eq!(fresh.name, "DeadHash 2");
+    }
+
+    #[actix_web::test]
+    async fn follow_resumes_after_the_last_processed_change() {
+        let changes = InMemoryChangeStreamRepository::with(vec![
+            vec![change(Some("a"), "1"), change(Some("b"), "2"), failure()],
+            vec![change(None, "3")],
+            vec![],
+        ]);
+        let invalidated = RefCell::new(vec![]);
+        let invalidate = |change: Option<&DocumentChange>| {
+            invalidated
+                .borrow_mut()
+                .push(change.and_then(|c| c.document_id.clone()))
+        };
+        let mut resume_token = None;
+
+        let failed =
+            CacheInvalidationService::follow(&changes, &mut resume_token, &invalidate).await;
+        let dropped =
+            CacheInvalidationService::follow(&changes, &mut resume_token, &invalidate).await;
+        let reopened =
+            CacheInvalidationService::follow(&changes, &mut resume_token, &invalidate).await;
+        let unavailable =
+            CacheInvalidationService::follow(&changes, &mut resume_token, &invalidate).await;
+
+        assert!(failed.is_err());
+        assert!(dropped.is_ok());
+        assert!(reopened.is_ok());
+        assert!(unavailable.is_err());
+        assert_eq!(
+            changes.resume_tokens(),
+            vec![None, Some(token("2")), None, None]
+        );
+        assert_eq!(
+            invalidated.into_inner(),
+            vec![
+                None,
+                Some(String::from("a")),
+                Some(String::from("b")),
+                None,
+                None,
+            ]
+        );
+    }
+
+    #[actix_web::test]
+    async fn follow_keeps_the_resume_token_until_the_history_is_lost() {
+        let changes = InMemoryChangeStreamRepository::with(vec![
+            vec![change(Some("a"), "1"), failure()],
+            vec![Err(history_lost())],
+            vec![change(Some("b"), "2")],
+        ]);
+        let invalidated = RefCell::new(vec![]);
+        let invalidate = |change: Option<&DocumentChange>| {
+            invalidated
+                .borrow_mut()
+                .push(change.and_then(|c| c.document_id.clone()))
+        };
+        let mut resume_token = Some(token("0"));
+
+        let failed =
+            CacheInvalidationService::follow(&changes, &mut resume_token, &invalidate).await;
+        let lost = CacheInvalidationService::follow(&changes, &mut resume_token, &invalidate).await;
+        let reopened =
+            CacheInvalidationService::follow(&changes, &mut resume_token, &invalidate).await;
+        let unavailable =
+            CacheInvalidationService::follow(&changes, &mut resume_token, &invalidate).await;
+
+        assert!(failed.is_err());
+        assert!(lost.unwrap_err().invalidates_resume_token());
+        assert!(reopened.is_ok());
+        assert!(!unavailable.unwrap_err().invalidates_resume_token());
+        assert_eq!(
+            changes.resume_tokens(),
+            vec![Some(token("0")), Some(token("1")), None, Some(token("2")),]
+        );
+        assert_eq!(resume_token, Some(token("2")));
+        assert_eq!(
+            invalidated.into_inner(),
+            vec![Some(String::from("a")), None, Some(String::from("b"))]
+        );
+    }
+
+    #[actix_web::test]
+    async fn application_changes_are_published_as_events() {
+        let event_service = EventService::new(Duration::from_secs(15), 10);
+        let service = CacheInvalidationService::new(
+            ApplicationService::new(Arc::new(
+                InMemoryApplicationRepository::with(applications()),
+            )),
+            VersionService::new(Arc::new(InMemoryVersionRepository::with(versions()))),
+            Arc::new(InMemoryChangeStreamRepository::default()),
+            Arc::new(InMemoryChangeStreamRepository::default()),
+        )
+        .with_events(&event_service);
+        let subscription = event_service.subscribe(None).unwrap();
+
+        for operation in [
+            ChangeOperation::Insert,
+            ChangeOperation::Update,
+            ChangeOperation::Delete,
+        ] {
+            service.application_changed(Some(&DocumentChange {
+                operation,
+                document_id: Some(String::from("pk-finder")),
+                added_releases: vec![],
+                resume_token: token("1"),
+            }));
+        }
+        service.application_changed(Some(&DocumentChange {
+            operation: ChangeOperation::Update,
+            document_id: Some(String::from("pk-finder")),
+            added_releases: vec![AddedRelease {
+                platform: String::from("Windows"),
+                architecture: String::from("x64"),
+                semver: String::from("2.1.0"),
+            }],
+            resume_token: token("2"),
+        }));
+        service.application_changed(change(None, "2").ok().as_ref());
+        service.application_changed(None);
+        drop(event_service);
+        drop(service);
+        let received: Vec<String> = subscription
+            .receiver
+            .map(|e| match e.release {
+                Some(r) => format!(
+                    "{} {} {}/{} {}",
+                    e.kind, e.application_id, r.platform, r.architecture, r.semver
+                ),
+                None => format!("{} {}", e.kind, e.application_id),
+            })
+            .collect()
+            .await;
+
+        assert_eq!(
+            received,
+            vec![
+                "application-created pk-finder",
+                "application-updated pk-finder",
+                "application-deleted pk-finder",
+                "release-published pk-finder Windows/x64 2.1.0",
+            ]
+        );
+    }
+}
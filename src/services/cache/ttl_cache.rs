@@ -0,0 +1,298 @@
+use crate::services::metrics::metrics_service::MetricsService;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// The configuration of an in-memory cache
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheConfig {
+    pub ttl: Duration,
+    pub max_entries: usize,
+}
+
+impl CacheConfig {
+    /// Initialize a new CacheConfig
+    ///
+    /// # Arguments
+    ///
+    /// * `ttl` - The time after which an entry expires
+    /// * `max_entries` - The maximum number of entries the cache holds
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `CacheConfig`
+    pub fn new(ttl: Duration, max_entries: usize) -> Self {
+        Self { ttl, max_entries }
+    }
+
+    /// Initialize a CacheConfig that does not cache anything
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `CacheConfig` with a TTL of zero
+    pub fn disabled() -> Self {
+        Self::new(Duration::ZERO, 0)
+    }
+
+    /// Check whether entries are cached at all
+    ///
+    /// # Returns
+    ///
+    /// True if both the TTL and the maximum number of entries are greater than zero
+    pub fn is_enabled(&self) -> bool {
+        !self.ttl.is_zero() && self.max_entries > 0
+    }
+}
+
+struct CacheEntry<V> {
+    value: V,
+    expires_at: Instant,
+}
+
+struct CacheState<K, V> {
+    entries: HashMap<K, CacheEntry<V>>,
+    /// Incremented on every invalidation, so loads that started before it are not stored
+    generation: u64,
+}
+
+/// A thread-safe in-memory cache whose entries expire after a fixed time
+///
+/// Clones share the same entries.
+#[derive(Clone)]
+pub struct TtlCache<K, V> {
+    name: String,
+    config: CacheConfig,
+    state: Arc<Mutex<CacheState<K, V>>>,
+    metrics: Option<MetricsService>,
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> TtlCache<K, V> {
+    /// Initialize a new TtlCache
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the cache, used as the `cache` label of its metrics
+    /// * `config` - The TTL and maximum number of entries of the cache
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `TtlCache` without entries
+    pub fn new(name: &str, config: CacheConfig) -> Self {
+        Self {
+            name: name.to_string(),
+            config,
+            state: Arc::new(Mutex::new(CacheState {
+                entries: HashMap::new(),
+                generation: 0,
+            })),
+            metrics: None,
+        }
+    }
+
+    /// Count the hits and misses of the cache
+    ///
+    /// # Arguments
+    ///
+    /// * `metrics` - The `MetricsService` to record lookups with
+    ///
+    /// # Returns
+    ///
+    /// The TtlCache that records its lookups
+    pub fn with_metrics(mut self, metrics: MetricsService) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Get the value of a key, or load and store it if it is not cached
+    ///
+    /// Errors are not cached. A loaded value is discarded if the cache was invalidated while it
+    /// was being loaded, because it may predate the write that caused the invalidation.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the value
+    /// * `load` - The function that loads the value on a miss
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the cached or loaded value
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the `load` function
+    pub async fn get_or_load<F, Fut, E>(&self, key: K, load: F) -> Result<V, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<V, E>>,
+    {
+        if !self.config.is_enabled() {
+            return load().await;
+        }
+
+        let generation = {
+            let mut state = self.lock();
+            let now = Instant::now();
+            let cached = match state.entries.get(&key) {
+                Some(entry) if entry.expires_at > now => Some(entry.value.clone()),
+                Some(_) => {
+                    state.entries.remove(&key);
+                    None
+                }
+                None => None,
+            };
+
+            self.observe_lookup(cached.is_some());
+            if let Some(value) = cached {
+                return Ok(value);
+            }
+            state.generation
+        };
+
+        let value = load().await?;
+
+        let mut state = self.lock();
+        if state.generation == generation {
+            self.evict(&mut state);
+            state.entries.insert(
+                key,
+                CacheEntry {
+                    value: value.clone(),
+                    expires_at: Instant::now() + self.config.ttl,
+                },
+            );
+        }
+
+        Ok(value)
+    }
+
+    /// Remove a single key from the cache
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to remove
+    pub fn invalidate(&self, key: &K) {
+        let mut state = self.lock();
+        state.generation += 1;
+        state.entries.remove(key);
+    }
+
+    /// Remove every key from the cache
+    pub fn invalidate_all(&self) {
+        let mut state = self.lock();
+        state.generation += 1;
+        state.entries.clear();
+    }
+
+    /// Make room for a new entry by removing expired entries and, if the cache is still full,
+    /// the entry that expires first
+    fn evict(&self, state: &mut CacheState<K, V>) {
+        if state.entries.len() < self.config.max_entries {
+            return;
+        }
+
+        let now = Instant::now();
+        state.entries.retain(|_, e| e.expires_at > now);
+
+        while state.entries.len() >= self.config.max_entries {
+            let oldest = state
+                .entries
+                .iter()
+                .min_by_key(|(_, e)| e.expires_at)
+                .map(|(k, _)| k.clone());
+            match oldest {
+                Some(key) => state.entries.remove(&key),
+                None => break,
+            };
+        }
+    }
+
+    fn observe_lookup(&self, hit: bool) {
+        if let Some(metrics) = &self.metrics {
+            metrics.observe_cache_lookup(&self.name, hit);
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, CacheState<K, V>> {
+        // The state is consistent after every statement, so a poisoned lock can be reused
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::Infallible;
+
+    async fn load(cache: &TtlCache<String, u32>, key: &str, value: u32) -> u32 {
+        cache
+            .get_or_load(key.to_string(), || async { Ok::<_, Infallible>(value) })
+            .await
+            .unwrap()
+    }
+
+    #[actix_web::test]
+    async fn get_or_load_serves_cached_values_until_they_are_invalidated() {
+        let cache = TtlCache::new("test", CacheConfig::new(Duration::from_secs(60), 10));
+
+        let first = load(&cache, "a", 1).await;
+        let cached = load(&cache, "a", 2).await;
+        cache.invalidate(&String::from("a"));
+        let reloaded = load(&cache, "a", 3).await;
+
+        assert_eq!((first, cached, reloaded), (1, 1, 3));
+    }
+
+    #[actix_web::test]
+    async fn get_or_load_reloads_expired_values() {
+        let cache = TtlCache::new("test", CacheConfig::new(Duration::from_millis(10), 10));
+
+        load(&cache, "a", 1).await;
+        actix_web::rt::time::sleep(Duration::from_millis(20)).await;
+
+        assert_eq!(load(&cache, "a", 2).await, 2);
+    }
+
+    #[actix_web::test]
+    async fn get_or_load_evicts_the_oldest_entry_when_full() {
+        let cache = TtlCache::new("test", CacheConfig::new(Duration::from_secs(60), 2));
+
+        load(&cache, "a", 1).await;
+        load(&cache, "b", 2).await;
+        load(&cache, "c", 3).await;
+
+        assert_eq!(load(&cache, "a", 4).await, 4);
+        assert_eq!(load(&cache, "c", 5).await, 3);
+    }
+
+    #[actix_web::test]
+    async fn get_or_load_discards_values_loaded_across_an_invalidation() {
+        let cache = TtlCache::new("test", CacheConfig::new(Duration::from_secs(60), 10));
+
+        let stale = cache
+            .get_or_load(String::from("a"), || async {
+                cache.invalidate_all();
+                Ok::<_, Infallible>(1)
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(stale, 1);
+        assert_eq!(load(&cache, "a", 2).await, 2);
+    }
+
+    #[actix_web::test]
+    async fn get_or_load_counts_hits_and_misses() {
+        let metrics = MetricsService::new();
+        let cache = TtlCache::new("test", CacheConfig::new(Duration::from_secs(60), 10))
+            .with_metrics(metrics.clone());
+
+        load(&cache, "a", 1).await;
+        load(&cache, "a", 1).await;
+        load(&cache, "a", 1).await;
+        let rendered = metrics.render();
+
+        assert!(rendered.contains("cache_lookups_total{cache=\"test\",result=\"hit\"} 2"));
+        assert!(rendered.contains("cache_lookups_total{cache=\"test\",result=\"miss\"} 1"));
+    }
+}
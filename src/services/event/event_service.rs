@@ -0,0 +1,312 @@
+use futures::channel::mpsc;
+use std::collections::VecDeque;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+/// The number of published events that are kept for clients that resume a stream
+const HISTORY_SIZE: usize = 1000;
+/// The number of events that may be queued for a subscriber before it is disconnected
+const SUBSCRIBER_CAPACITY: usize = 64;
+
+/// The kind of change that an ApplicationEvent describes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventKind {
+    ApplicationCreated,
+    ApplicationUpdated,
+    ApplicationDeleted,
+    ReleasePublished,
+}
+
+impl fmt::Display for EventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            EventKind::ApplicationCreated => "application-created",
+            EventKind::ApplicationUpdated => "application-updated",
+            EventKind::ApplicationDeleted => "application-deleted",
+            EventKind::ReleasePublished => "release-published",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The identifier of an ApplicationEvent
+///
+/// Sequence numbers restart at zero whenever the process starts, so they are prefixed with the
+/// time at which the EventService was created. Identifiers of a previous run then never match
+/// the events of the current run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventId {
+    /// The time at which the EventService was created, in milliseconds since the Unix epoch
+    pub epoch: i64,
+    /// The sequence number of the event, which increases with every published event
+    pub sequence: u64,
+}
+
+impl fmt::Display for EventId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.epoch, self.sequence)
+    }
+}
+
+impl FromStr for EventId {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (epoch, sequence) = s.split_once('-').ok_or(())?;
+
+        Ok(EventId {
+            epoch: epoch.parse().map_err(|_| ())?,
+            sequence: sequence.parse().map_err(|_| ())?,
+        })
+    }
+}
+
+/// The platform, architecture and semantic version of a published Release
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublishedRelease {
+    pub platform: String,
+    pub architecture: String,
+    pub semver: String,
+}
+
+/// A change to an Application
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApplicationEvent {
+    pub id: EventId,
+    pub kind: EventKind,
+    pub application_id: String,
+    pub release: Option<PublishedRelease>,
+    pub occurred_at: String,
+}
+
+/// The events that a subscriber receives
+pub struct Subscription {
+    /// The retained events that were published after the last event the subscriber received
+    pub missed: Vec<ApplicationEvent>,
+    /// The events that are published from now on. The receiver ends when the subscriber falls
+    /// too far behind.
+    pub receiver: mpsc::Receiver<ApplicationEvent>,
+}
+
+struct EventState {
+    last_id: u64,
+    history: VecDeque<ApplicationEvent>,
+    subscribers: Vec<mpsc::Sender<ApplicationEvent>>,
+}
+
+#[derive(Clone)]
+pub struct EventService {
+    epoch: i64,
+    heartbeat_interval: Duration,
+    max_subscribers: usize,
+    state: Arc<Mutex<EventState>>,
+}
+
+impl EventService {
+    /// Initialize a new EventService
+    ///
+    /// # Arguments
+    ///
+    /// * `heartbeat_interval` - The interval at which idle event streams are kept alive
+    /// * `max_subscribers` - The maximum number of subscribers that may be connected at once
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `EventService` without subscribers
+    pub fn new(heartbeat_interval: Duration, max_subscribers: usize) -> Self {
+        Self {
+            epoch: chrono::Utc::now().timestamp_millis(),
+            heartbeat_interval,
+            max_subscribers,
+            state: Arc::new(Mutex::new(EventState {
+                last_id: 0,
+                history: VecDeque::new(),
+                subscribers: vec![],
+            })),
+        }
+    }
+
+    /// Get the interval at which idle event streams are kept alive
+    ///
+    /// # Returns
+    ///
+    /// The heartbeat interval
+    pub fn heartbeat_interval(&self) -> Duration {
+        self.heartbeat_interval
+    }
+
+    /// Publish an event to every subscriber
+    ///
+    /// Subscribers whose queue is full are disconnected, so they can resume from the history.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind of change
+    /// * `application_id` - The ID of the Application that changed
+    /// * `release` - The Release that was published, if any
+    ///
+    /// # Returns
+    ///
+    /// The published ApplicationEvent
+    pub fn publish(
+        &self,
+        kind: EventKind,
+        application_id: &str,
+        release: Option<PublishedRelease>,
+    ) -> ApplicationEvent {
+        let mut state = self.lock();
+        state.last_id += 1;
+
+        let event = ApplicationEvent {
+            id: EventId {
+                epoch: self.epoch,
+                sequence: state.last_id,
+            },
+            kind,
+            application_id: application_id.to_string(),
+            release,
+            occurred_at: chrono::Utc::now().to_rfc3339(),
+        };
+
+        if state.history.len() == HISTORY_SIZE {
+            state.history.pop_front();
+        }
+        state.history.push_back(event.clone());
+        state
+            .subscribers
+            .retain_mut(|s| s.try_send(event.clone()).is_ok());
+
+        event
+    }
+
+    /// Subscribe to all events that are published from now on
+    ///
+    /// # Arguments
+    ///
+    /// * `last_event_id` - The ID of the last event the subscriber received, if it is resuming
+    ///
+    /// # Returns
+    ///
+    /// An Option containing the Subscription with the retained events that the subscriber missed,
+    /// or None if the maximum number of subscribers is connected. Nothing was missed when the ID
+    /// was issued by another run of the process or has not been issued yet, because the
+    /// subscriber cannot be placed in the history.
+    pub fn subscribe(&self, last_event_id: Option<EventId>) -> Option<Subscription> {
+        let mut state = self.lock();
+
+        state.subscribers.retain(|s| !s.is_closed());
+        if state.subscribers.len() >= self.max_subscribers {
+            return None;
+        }
+
+        let missed = match last_event_id {
+            Some(last_event_id)
+                if last_event_id.epoch == self.epoch && last_event_id.sequence <= state.last_id =>
+            {
+                state
+                    .history
+                    .iter()
+                    .filter(|e| e.id.sequence > last_event_id.sequence)
+                    .cloned()
+                    .collect()
+            }
+            _ => vec![],
+        };
+
+        let (sender, receiver) = mpsc::channel(SUBSCRIBER_CAPACITY);
+        state.subscribers.push(sender);
+
+        Some(Subscription { missed, receiver })
+    }
+
+    fn lock(&self) -> MutexGuard<'_, EventState> {
+        // The state is consistent after every statement, so a poisoned lock can be reused
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+
+    #[actix_web::test]
+    async fn subscribe_replays_missed_events_before_live_events() {
+        let service = EventService::new(Duration::from_secs(15), 10);
+
+        let first = service.publish(EventKind::ApplicationCreated, "deadhash", None);
+        service.publish(EventKind::ApplicationUpdated, "deadhash", None);
+        let mut subscription = service.subscribe(Some(first.id)).unwrap();
+        service.publish(EventKind::ApplicationDeleted, "deadhash", None);
+
+        let missed: Vec<u64> = subscription.missed.iter().map(|e| e.id.sequence).collect();
+        let live = subscription.receiver.next().await.unwrap();
+        assert_eq!(missed, vec![2]);
+        assert_eq!(live.id.sequence, 3);
+        assert_eq!(live.kind.to_string(), "application-deleted");
+    }
+
+    #[actix_web::test]
+    async fn publish_disconnects_subscribers_that_fall_behind() {
+        let service = EventService::new(Duration::from_secs(15), 10);
+        let subscription = service.subscribe(None).unwrap();
+
+        for _ in 0..SUBSCRIBER_CAPACITY * 2 {
+            service.publish(EventKind::ApplicationUpdated, "deadhash", None);
+        }
+        let received: Vec<ApplicationEvent> = subscription.receiver.collect().await;
+
+        assert!(received.len() >= SUBSCRIBER_CAPACITY);
+        assert!(received.len() < SUBSCRIBER_CAPACITY * 2);
+        assert!(service.lock().subscribers.is_empty());
+    }
+
+    #[actix_web::test]
+    async fn subscribe_rejects_subscribers_beyond_the_maximum() {
+        let service = EventService::new(Duration::from_secs(15), 2);
+
+        let first = service.subscribe(None).unwrap();
+        let _second = service.subscribe(None).unwrap();
+        let rejected = service.subscribe(None);
+        drop(first);
+        let replacement = service.subscribe(None);
+
+        assert!(rejected.is_none());
+        assert!(replacement.is_some());
+    }
+
+    #[test]
+    fn subscribe_does_not_resume_from_ids_of_another_run() {
+        let service = EventService::new(Duration::from_secs(15), 10);
+
+        let first = service.publish(EventKind::ApplicationCreated, "deadhash", None);
+        service.publish(EventKind::ApplicationUpdated, "deadhash", None);
+        let previous_run = EventId {
+            epoch: first.id.epoch - 1,
+            ..first.id
+        };
+        let not_issued = EventId {
+            sequence: 3,
+            ..first.id
+        };
+
+        assert_eq!(first.id.to_string().parse(), Ok(first.id));
+        assert_eq!(service.subscribe(Some(first.id)).unwrap().missed.len(), 1);
+        assert!(
+            service
+                .subscribe(Some(previous_run))
+                .unwrap()
+                .missed
+                .is_empty()
+        );
+        assert!(
+            service
+                .subscribe(Some(not_issued))
+                .unwrap()
+                .missed
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1 @@
+pub(crate) mod health_service;
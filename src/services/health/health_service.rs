@@ -0,0 +1,165 @@
+use crate::repository::health::dao::health_repository::HealthRepository;
+use log::warn;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// The status of a component that the API depends on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthStatus {
+    Up,
+    Down,
+}
+
+/// The outcome of a single readiness check
+#[derive(Debug, Clone)]
+pub struct ComponentHealth {
+    pub name: String,
+    pub status: HealthStatus,
+    pub latency: Duration,
+    pub details: Option<String>,
+}
+
+/// The outcome of all readiness checks
+#[derive(Debug, Clone)]
+pub struct Readiness {
+    pub components: Vec<ComponentHealth>,
+}
+
+impl Readiness {
+    /// Get the overall status of the API
+    ///
+    /// # Returns
+    ///
+    /// Up if every component is up, otherwise Down
+    pub fn status(&self) -> HealthStatus {
+        if self.components.iter().all(|c| c.status == HealthStatus::Up) {
+            HealthStatus::Up
+        } else {
+            HealthStatus::Down
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct HealthService {
+    health_repository: Arc<dyn HealthRepository>,
+    application_collection: String,
+    version_collection: String,
+}
+
+impl HealthService {
+    /// Initialize a new HealthService
+    ///
+    /// # Arguments
+    ///
+    /// * `health_repository` - The `HealthRepository` to probe the database with
+    /// * `application_collection` - The name of the collection that must hold Application records
+    /// * `version_collection` - The name of the collection that must hold Version records
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `HealthService`
+    pub fn new(
+        health_repository: Arc<dyn HealthRepository>,
+        application_collection: &str,
+        version_collection: &str,
+    ) -> Self {
+        Self {
+            health_repository,
+            application_collection: application_collection.to_string(),
+            version_collection: version_collection.to_string(),
+        }
+    }
+
+    /// Check whether the API is ready to serve requests
+    ///
+    /// The database is pinged and the application and version collections must exist.
+    ///
+    /// # Returns
+    ///
+    /// The Readiness with the status and latency of every component
+    pub async fn readiness(&self) -> Readiness {
+        let start = Instant::now();
+        let ping = self.health_repository.ping().await;
+        let mut components = vec![ComponentHealth {
+            name: String::from("mongodb"),
+            status: if ping.is_ok() {
+                HealthStatus::Up
+            } else {
+                HealthStatus::Down
+            },
+            latency: start.elapsed(),
+            details: ping.err().map(|e| {
+                warn!("Readiness check failed to ping the database: {}", e);
+                String::from("The database did not respond to a ping")
+            }),
+        }];
+
+        let start = Instant::now();
+        let collection_names = self.health_repository.collection_names().await;
+        let latency = start.elapsed();
+
+        for (name, collection) in [
+            ("applications", &self.application_collection),
+            ("versions", &self.version_collection),
+        ] {
+            let details = match &collection_names {
+                Ok(names) if names.contains(collection) => None,
+                Ok(_) => Some(format!("Collection {} does not exist", collection)),
+                Err(e) => {
+                    warn!("Readiness check failed to list the collections: {}", e);
+                    Some(String::from("The collections could not be listed"))
+                }
+            };
+
+            components.push(ComponentHealth {
+                name: name.to_string(),
+                status: if details.is_none() {
+                    HealthStatus::Up
+                } else {
+                    HealthStatus::Down
+                },
+                latency,
+                details,
+            });
+        }
+
+        Readiness { components }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::health::dao::in_memory_health_repository::InMemoryHealthRepository;
+
+    #[actix_web::test]
+    async fn readiness_requires_every_collection() {
+        let ready = HealthService::new(
+            Arc::new(InMemoryHealthRepository::with(&["apps", "versions"])),
+            "apps",
+            "versions",
+        );
+        let missing = HealthService::new(
+            Arc::new(InMemoryHealthRepository::with(&["apps"])),
+            "apps",
+            "versions",
+        );
+
+        let ready = ready.readiness().await;
+        let missing = missing.readiness().await;
+
+        assert_eq!(ready.status(), HealthStatus::Up);
+        assert_eq!(missing.status(), HealthStatus::Down);
+        let versions = missing
+            .components
+            .iter()
+            .find(|c| c.name == "versions")
+            .unwrap();
+        assert_eq!(versions.status, HealthStatus::Down);
+        assert_eq!(
+            versions.details.as_deref(),
+            Some("Collection versions does not exist")
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use chrono::{DateTime, Utc};
+use std::time::{Duration, Instant};
+
+/// The metadata that was embedded into the binary at build time
+#[derive(Debug, Clone)]
+pub struct BuildInfo {
+    pub version: &'static str,
+    pub git_commit: &'static str,
+    pub build_timestamp: Option<DateTime<Utc>>,
+    pub rustc_version: &'static str,
+    pub features: Vec<&'static str>,
+}
+
+#[derive(Clone)]
+pub struct InfoService {
+    started_at: DateTime<Utc>,
+    started: Instant,
+}
+
+impl InfoService {
+    /// Initialize a new InfoService that considers the process started now
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `InfoService`
+    pub fn new() -> Self {
+        Self {
+            started_at: Utc::now(),
+            started: Instant::now(),
+        }
+    }
+
+    /// Get the metadata of the running build
+    ///
+    /// # Returns
+    ///
+    /// The BuildInfo that was embedded by the build script
+    pub fn build_info(&self) -> BuildInfo {
+        BuildInfo {
+            version: env!("CARGO_PKG_VERSION"),
+            git_commit: env!("BUILD_GIT_COMMIT"),
+            build_timestamp: env!("BUILD_TIMESTAMP")
+                .parse::<i64>()
+                .ok()
+                .and_then(|t| DateTime::from_timestamp(t, 0)),
+            rustc_version: env!("BUILD_RUSTC_VERSION"),
+            features: env!("BUILD_FEATURES")
+                .split(',')
+                .filter(|f| !f.is_empty())
+                .collect(),
+        }
+    }
+
+    /// Get the moment the process was started
+    ///
+    /// # Returns
+    ///
+    /// The start time of the process
+    pub fn started_at(&self) -> DateTime<Utc> {
+        self.started_at
+    }
+
+    /// Get the time that passed since the process was started
+    ///
+    /// # Returns
+    ///
+    /// The uptime of the process
+    pub fn uptime(&self) -> Duration {
+        self.started.elapsed()
+    }
+}
+
+impl Default for InfoService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
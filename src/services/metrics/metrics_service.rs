@@ -0,0 +1,204 @@
+use log::error;
+use mongodb::event::command::CommandEvent;
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounterVec, Opts, Registry, TextEncoder,
+};
+use std::time::Duration;
+
+/// The buckets of the latency histograms, in seconds
+const LATENCY_BUCKETS: &[f64] = &[
+    0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// The route label of requests that did not match any route
+pub const UNMATCHED_ROUTE: &str = "unmatched";
+
+#[derive(Clone)]
+pub struct MetricsService {
+    registry: Registry,
+    http_requests: IntCounterVec,
+    http_request_duration: HistogramVec,
+    database_operation_duration: HistogramVec,
+    cache_lookups: IntCounterVec,
+}
+
+impl MetricsService {
+    /// Initialize a new MetricsService with its own registry
+    ///
+    /// Process metrics are registered as well on platforms that support them.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `MetricsService`
+    pub fn new() -> Self {
+        let registry = Registry::new();
+
+        let http_requests = IntCounterVec::new(
+            Opts::new("http_requests_total", "The number of handled HTTP requests"),
+            &["method", "route", "status"],
+        )
+        .expect("http_requests_total is a valid metric");
+        let http_request_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "http_request_duration_seconds",
+                "The time it took to handle HTTP requests",
+            )
+            .buckets(LATENCY_BUCKETS.to_vec()),
+            &["method", "route", "status"],
+        )
+        .expect("http_request_duration_seconds is a valid metric");
+        let database_operation_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "mongodb_operation_duration_seconds",
+                "The time it took to execute MongoDB commands, including the network round-trip",
+            )
+            .buckets(LATENCY_BUCKETS.to_vec()),
+            &["command", "outcome"],
+        )
+        .expect("mongodb_operation_duration_seconds is a valid metric");
+        let cache_lookups = IntCounterVec::new(
+            Opts::new(
+                "cache_lookups_total",
+                "The number of lookups in the in-memory caches",
+            ),
+            &["cache", "result"],
+        )
+        .expect("cache_lookups_total is a valid metric");
+
+        registry
+            .register(Box::new(http_requests.clone()))
+            .expect("http_requests_total is registered once");
+        registry
+            .register(Box::new(http_request_duration.clone()))
+            .expect("http_request_duration_seconds is registered once");
+        registry
+            .register(Box::new(database_operation_duration.clone()))
+            .expect("mongodb_operation_duration_seconds is registered once");
+        registry
+            .register(Box::new(cache_lookups.clone()))
+            .expect("cache_lookups_total is registered once");
+
+        #[cfg(target_os = "linux")]
+        registry
+            .register(Box::new(
+                prometheus::process_collector::ProcessCollector::for_self(),
+            ))
+            .expect("process metrics are registered once");
+
+        Self {
+            registry,
+            http_requests,
+            http_request_duration,
+            database_operation_duration,
+            cache_lookups,
+        }
+    }
+
+    /// Record a handled HTTP request
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The HTTP method of the request
+    /// * `route` - The route pattern that matched the request
+    /// * `status` - The status code of the response
+    /// * `duration` - The time it took to handle the request
+    pub fn observe_request(&self, method: &str, route: &str, status: u16, duration: Duration) {
+        let status = status.to_string();
+        let labels = [method, route, status.as_str()];
+
+        self.http_requests.with_label_values(&labels).inc();
+        self.http_request_duration
+            .with_label_values(&labels)
+            .observe(duration.as_secs_f64());
+    }
+
+    /// Record an executed MongoDB command
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The name of the command, e.g. `find` or `insert`
+    /// * `succeeded` - Whether the command succeeded
+    /// * `duration` - The time it took to execute the command
+    pub fn observe_database_operation(&self, command: &str, succeeded: bool, duration: Duration) {
+        let outcome = if succeeded { "success" } else { "failure" };
+
+        self.database_operation_duration
+            .with_label_values(&[command, outcome])
+            .observe(duration.as_secs_f64());
+    }
+
+    /// Record a lookup in an in-memory cache
+    ///
+    /// # Arguments
+    ///
+    /// * `cache` - The name of the cache
+    /// * `hit` - Whether the cache held the requested entry
+    pub fn observe_cache_lookup(&self, cache: &str, hit: bool) {
+        let result = if hit { "hit" } else { "miss" };
+
+        self.cache_lookups.with_label_values(&[cache, result]).inc();
+    }
+
+    /// Record a MongoDB command monitoring event
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The event that was published by the MongoDB driver
+    pub fn observe_command_event(&self, event: CommandEvent) {
+        match event {
+            CommandEvent::Succeeded(e) => {
+                self.observe_database_operation(&e.command_name, true, e.duration)
+            }
+            CommandEvent::Failed(e) => {
+                self.observe_database_operation(&e.command_name, false, e.duration)
+            }
+            _ => {}
+        }
+    }
+
+    /// Render all metrics in the Prometheus text exposition format
+    ///
+    /// # Returns
+    ///
+    /// The rendered metrics
+    pub fn render(&self) -> String {
+        let mut buffer = vec![];
+        if let Err(e) = TextEncoder::new().encode(&self.registry.gather(), &mut buffer) {
+            error!("Error encoding metrics: {}", e);
+        }
+
+        String::from_utf8(buffer).unwrap_or_default()
+    }
+}
+
+impl Default for MetricsService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_includes_observed_requests_and_operations() {
+        let service = MetricsService::new();
+
+        service.observe_request(
+            "GET",
+            "/api/v1/applications/{id}",
+            200,
+            Duration::from_millis(3),
+        );
+        service.observe_database_operation("find", true, Duration::from_millis(1));
+        let rendered = service.render();
+
+        assert!(rendered.contains(
+            "http_requests_total{method=\"GET\",route=\"/api/v1/applications/{id}\",status=\"200\"} 1"
+        ));
+        assert!(rendered.contains(
+            "mongodb_operation_duration_seconds_count{command=\"find\",outcome=\"success\"} 1"
+        ));
+    }
+}
@@ -0,0 +1,50 @@
+use crate::errors::service_error::ServiceError;
+use url::Url;
+
+/// Ensure that a value is not empty or whitespace only
+///
+/// # Arguments
+///
+/// * `field` - The name of the field, used in the error message
+/// * `value` - The value to check
+///
+/// # Returns
+///
+/// An empty Result if the value is not blank
+///
+/// # Errors
+///
+/// Returns a validation error if the value is blank
+pub fn require_not_blank(field: &str, value: &str) -> Result<(), ServiceError> {
+    if value.trim().is_empty() {
+        return Err(ServiceError::Validation(format!(
+            "{} must not be empty",
+            field
+        )));
+    }
+    Ok(())
+}
+
+/// Ensure that a value is an absolute HTTP or HTTPS URL
+///
+/// # Arguments
+///
+/// * `field` - The name of the field, used in the error message
+/// * `value` - The value to check
+///
+/// # Returns
+///
+/// An empty Result if the value is an absolute URL
+///
+/// # Errors
+///
+/// Returns a validation error if the value is not an absolute HTTP or HTTPS URL
+pub fn require_absolute_url(field: &str, value: &str) -> Result<(), ServiceError> {
+    match Url::parse(value) {
+        Ok(url) if (url.scheme() == "http" || url.scheme() == "https") && url.has_host() => Ok(()),
+        _ => Err(ServiceError::Validation(format!(
+            "{} must be an absolute HTTP(S) URL, got '{}'",
+            field, value
+        ))),
+    }
+}
@@ -1,11 +1,21 @@
+use crate::errors::repository_error::RepositoryError;
+use crate::errors::service_error::ServiceError;
 use crate::repository::v2::dao::version_repository::VersionRepository;
 use crate::repository::v2::model::version::Version;
+use crate::services::cache::ttl_cache::{CacheConfig, TtlCache};
+use crate::services::metrics::metrics_service::MetricsService;
+use crate::services::validation::{require_absolute_url, require_not_blank};
 use log::info;
-use mongodb::Database;
+use std::sync::Arc;
+
+/// The key of a cached page of Versions: the ID to start after and the limit
+type PageKey = (Option<String>, Option<i64>);
 
 #[derive(Clone)]
 pub struct VersionService {
-    version_repository: VersionRepository,
+    version_repository: Arc<dyn VersionRepository>,
+    versions: TtlCache<String, Option<Version>>,
+    version_pages: TtlCache<PageKey, Vec<Version>>,
 }
 
 impl VersionService {
@@ -13,13 +23,50 @@ impl VersionService {
     ///
     /// # Arguments
     ///
-    /// * `version_repository` - An instance of `VersionRepository` to interact with the database
+    /// * `version_repository` - The `VersionRepository` to store Version records in
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `VersionService` that does not cache Version records
+    pub fn new(version_repository: Arc<dyn VersionRepository>) -> Self {
+        Self {
+            version_repository,
+            versions: TtlCache::new("versions", CacheConfig::disabled()),
+            version_pages: TtlCache::new("version_pages", CacheConfig::disabled()),
+        }
+    }
+
+    /// Cache Version records and pages of Version records in memory
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The TTL and maximum number of entries of each cache
+    /// * `metrics_service` - The `MetricsService` to count cache hits and misses with
     ///
     /// # Returns
     ///
-    /// A new instance of `VersionService`
-    pub fn new(version_repository: VersionRepository) -> Self {
-        Self { version_repository }
+    /// The VersionService with caching enabled
+    pub fn with_cache(mut self, config: CacheConfig, metrics_service: &MetricsService) -> Self {
+        self.versions = TtlCache::new("versions", config).with_metrics(metrics_service.clone());
+        self.version_pages =
+            TtlCache::new("version_pages", config).with_metrics(metrics_service.clone());
+        self
+    }
+
+    /// Remove a Version and every page of Version records from the caches
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the Version that was modified
+    pub fn invalidate(&self, id: &str) {
+        self.versions.invalidate(&id.to_string());
+        self.version_pages.invalidate_all();
+    }
+
+    /// Remove every Version and every page of Version records from the caches
+    pub fn invalidate_all(&self) {
+        self.versions.invalidate_all();
+        self.version_pages.invalidate_all();
     }
 
     /// Find a single Version by its ID
@@ -27,18 +74,15 @@ impl VersionService {
     /// # Arguments
     ///
     /// * `id` - The ID of the Version
-    /// * `db` - The MongoDB database
     ///
     /// # Returns
     ///
-    /// A result containing an Option with the Version if found, or None if not found, or an error if the MongoDB query fails
-    pub async fn find_by_id(
-        &self,
-        id: &str,
-        db: &Database,
-    ) -> Result<Option<Version>, mongodb::error::Error> {
+    /// A result containing an Option with the Version if found, or None if not found, or an error if the repository fails
+    pub async fn find_by_id(&self, id: &str) -> Result<Option<Version>, RepositoryError> {
         info!("Retrieving version with ID: {}", id);
-        self.version_repository.find_by_id(id, db).await
+        self.versions
+            .get_or_load(id.to_string(), || self.version_repository.find_by_id(id))
+            .await
     }
 
     /// Find all Version records sorted by ID
@@ -46,7 +90,6 @@ impl VersionService {
     /// # Arguments
     ///
     /// * `limit` - The maximum number of Version structs to return
-    /// * `db` - The MongoDB database
     ///
     /// # Returns
     ///
@@ -54,18 +97,19 @@ impl VersionService {
     ///
     /// # Errors
     ///
-    /// Returns an error if the MongoDB query fails
+    /// Returns an error if the repository fails
     pub async fn get_all_sorted_by_id(
         &self,
         limit: Option<i64>,
-        db: &Database,
-    ) -> Result<Vec<Version>, mongodb::error::Error> {
+    ) -> Result<Vec<Version>, RepositoryError> {
         info!(
             "Retrieving all versions sorted by ID with limit: {:?}",
             limit
         );
-        self.version_repository
-            .get_all_sorted_by_id(limit, db)
+        self.version_pages
+            .get_or_load((None, limit), || {
+                self.version_repository.get_all_sorted_by_id(limit)
+            })
             .await
     }
 
@@ -82,19 +126,275 @@ impl VersionService {
     ///
     /// # Errors
     ///
-    /// Returns an error if the MongoDB query fails
+    /// Returns an error if the repository fails
     pub async fn get_all_with_id_greater_than(
         &self,
         id: &str,
         limit: Option<i64>,
-        db: &Database,
-    ) -> Result<Vec<Version>, mongodb::error::Error> {
+    ) -> Result<Vec<Version>, RepositoryError> {
         info!(
             "Retrieving all versions with ID greater than {} sorted by ID with limit: {:?}",
             id, limit
         );
-        self.version_repository
-            .get_all_with_id_greater_than(id, limit, db)
+        self.version_pages
+            .get_or_load((Some(id.to_string()), limit), || {
+                self.version_repository
+                    .get_all_with_id_greater_than(id, limit)
+            })
             .await
     }
+
+    /// Create a new Version
+    ///
+    /// The creation and modification timestamps are set to the current time.
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The Version to create
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the created Version
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the Version is invalid, a conflict error if a Version with
+    /// the same ID or semantic version already exists, or a database error if the repository fails
+    pub async fn create(&self, mut version: Version) -> Result<Version, ServiceError> {
+        info!("Creating version with ID: {}", version.id);
+
+        require_not_blank("id", &version.id)?;
+        Self::validate(&version)?;
+        self.ensure_unique_semver(&version, None).await?;
+
+        let now = chrono::Utc::now().to_rfc3339();
+        version.created_at = now.clone();
+        version.updated_at = now;
+
+        let created = self.version_repository.create(&version).await;
+        self.invalidate(&version.id);
+
+        match created {
+            Ok(()) => Ok(version),
+            Err(RepositoryError::Duplicate) => {
+                if self
+                    .version_repository
+                    .find_by_id(&version.id)
+                    .await?
+                    .is_some()
+                {
+                    Err(ServiceError::Conflict(format!(
+                        "A version with ID {} already exists",
+                        version.id
+                    )))
+                } else {
+                    Err(Self::semver_conflict(&version))
+                }
+            }
+            Err(e) => Err(ServiceError::Database(e)),
+        }
+    }
+
+    /// Replace the name, description, semantic version and platforms of an existing Version
+    ///
+    /// The modification timestamp is set to the current time, the creation timestamp is retained.
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The new state of the Version, identified by its ID
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the updated Version
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the Version is invalid, a conflict error if another Version
+    /// with the same semantic version exists, a not found error if the Version does not exist, or
+    /// a database error if the repository fails
+    pub async fn update(&self, mut version: Version) -> Result<Version, ServiceError> {
+        info!("Updating version with ID: {}", version.id);
+
+        Self::validate(&version)?;
+        self.ensure_unique_semver(&version, Some(&version.id))
+            .await?;
+
+        version.updated_at = chrono::Utc::now().to_rfc3339();
+
+        let updated = self.version_repository.update(&version).await;
+        self.invalidate(&version.id);
+
+        match updated {
+            Ok(Some(version)) => Ok(version),
+            Err(RepositoryError::Duplicate) => Err(Self::semver_conflict(&version)),
+            Err(e) => Err(ServiceError::Database(e)),
+            Ok(None) => Err(ServiceError::NotFound(format!(
+                "Version with ID {} was not found",
+                version.id
+            ))),
+        }
+    }
+
+    /// Delete a Version by its ID
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the Version
+    ///
+    /// # Returns
+    ///
+    /// An empty Result if the Version was deleted
+    ///
+    /// # Errors
+    ///
+    /// Returns a not found error if the Version does not exist, or a database error if the repository fails
+    pub async fn delete(&self, id: &str) -> Result<(), ServiceError> {
+        info!("Deleting version with ID: {}", id);
+
+        let deleted = self.version_repository.delete_by_id(id).await;
+        self.invalidate(id);
+
+        if deleted? {
+            Ok(())
+        } else {
+            Err(ServiceError::NotFound(format!(
+                "Version with ID {} was not found",
+                id
+            )))
+        }
+    }
+
+    /// Validate the platforms of a Version
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The Version to validate
+    ///
+    /// # Returns
+    ///
+    /// An empty Result if the Version is valid
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the Version has no platforms or a platform URL is not absolute
+    fn validate(version: &Version) -> Result<(), ServiceError> {
+        if version.platforms.is_empty() {
+            return Err(ServiceError::Validation(String::from(
+                "platforms must contain at least one platform",
+            )));
+        }
+
+        for platform in &version.platforms {
+            require_absolute_url("downloadUrl", &platform.download_url)?;
+            if let Some(info_url) = &platform.info_url {
+                require_absolute_url("infoUrl", info_url)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Ensure that no other Version uses the same semantic version
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The Version to check
+    /// * `exclude_id` - The optional ID of the Version that is being updated
+    ///
+    /// # Returns
+    ///
+    /// An empty Result if the semantic version is unique
+    ///
+    /// # Errors
+    ///
+    /// Returns a conflict error if the semantic version is already in use, or a database error if the repository fails
+    async fn ensure_unique_semver(
+        &self,
+        version: &Version,
+        exclude_id: Option<&str>,
+    ) -> Result<(), ServiceError> {
+        if self
+            .version_repository
+            .exists_by_semver(&version.semver, exclude_id)
+            .await?
+        {
+            return Err(Self::semver_conflict(version));
+        }
+        Ok(())
+    }
+
+    /// Build the conflict error for a semantic version that is already in use
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The Version whose semantic version is already in use
+    ///
+    /// # Returns
+    ///
+    /// The conflict error
+    fn semver_conflict(version: &Version) -> ServiceError {
+        ServiceError::Conflict(format!(
+            "A version with semver {} already exists",
+            version.semver
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::v2::dao::in_memory_version_repository::InMemoryVersionRepository;
+    use crate::tests::version;
+
+    fn service(versions: Vec<Version>) -> VersionService {
+        VersionService::new(Arc::new(InMemoryVersionRepository::with(versions)))
+    }
+
+    #[actix_web::test]
+    async fn create_rejects_duplicate_semantic_versions() {
+        let service = service(vec![version("v1", "1.0.0")]);
+
+        let res = service.create(version("v2", "1.0.0")).await;
+
+        assert!(matches!(res, Err(ServiceError::Conflict(_))));
+    }
+
+    #[actix_web::test]
+    async fn create_rejects_semantic_versions_that_only_differ_in_build_metadata() {
+        let service = service(vec![version("v1", "1.0.0+a")]);
+
+        let res = service.create(version("v2", "1.0.0+b")).await;
+
+        assert!(matches!(res, Err(ServiceError::Conflict(_))));
+    }
+
+    #[actix_web::test]
+    async fn update_rejects_the_semantic_version_of_another_version() {
+        let service = service(vec![version("v1", "1.0.0"), version("v2", "2.0.0")]);
+
+        let res = service.update(version("v2", "1.0.0+b")).await;
+
+        assert!(matches!(res, Err(ServiceError::Conflict(_))));
+    }
+
+    #[actix_web::test]
+    async fn update_keeps_its_own_semantic_version() {
+        let service = service(vec![version("v1", "1.0.0")]);
+        let mut changed = version("v1", "1.0.0");
+        changed.name = Some(String::from("First"));
+
+        let updated = service.update(changed).await.unwrap();
+
+        assert_eq!(updated.name.as_deref(), Some("First"));
+        assert_eq!(updated.created_at, "2026-01-01T00:00:00+00:00");
+    }
+
+    #[actix_web::test]
+    async fn delete_of_unknown_version_is_not_found() {
+        let service = service(vec![]);
+
+        let res = service.delete("v1").await;
+
+        assert!(matches!(res, Err(ServiceError::NotFound(_))));
+    }
 }
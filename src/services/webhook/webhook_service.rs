@@ -0,0 +1,612 @@
+use crate::errors::repository_error::RepositoryError;
+use crate::errors::service_error::ServiceError;
+use crate::repository::application::model::application::Release;
+use crate::repository::application::model::release_channel::ReleaseChannel;
+use crate::repository::webhook::dao::webhook_delivery_repository::WebhookDeliveryRepository;
+use crate::repository::webhook::dao::webhook_repository::WebhookRepository;
+use crate::repository::webhook::model::webhook::Webhook;
+use crate::repository::webhook::model::webhook_delivery::{DeliveryStatus, WebhookDelivery};
+use crate::services::event::event_service::EventKind;
+use crate::services::validation::require_absolute_url;
+use crate::web::dto::application::application_dto::ReleaseDto;
+use chrono::{DateTime, SecondsFormat, Utc};
+use hmac::{Hmac, KeyInit, Mac};
+use log::{error, info, warn};
+use serde::Serialize;
+use sha2::Sha256;
+use std::sync::Arc;
+use std::time::Duration;
+use url::Url;
+
+/// The time after which a subscriber that does not respond is considered unreachable
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+/// The time for which a claimed delivery is hidden from other attempts
+const CLAIM_LEASE: Duration = Duration::from_secs(60);
+/// The interval at which due deliveries are picked up, including those of other instances
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+/// The minimum length of a secret that is provided by a subscriber
+const MIN_SECRET_LENGTH: usize = 16;
+
+/// The header that carries the kind of event
+pub const EVENT_HEADER: &str = "X-CodeDead-Event";
+/// The header that carries the ID of the delivery, which is the same for every attempt
+pub const DELIVERY_HEADER: &str = "X-CodeDead-Delivery";
+/// The header that carries the Unix time at which the payload was signed
+pub const TIMESTAMP_HEADER: &str = "X-CodeDead-Timestamp";
+/// The header that carries the HMAC-SHA256 signature of the timestamp and payload
+pub const SIGNATURE_HEADER: &str = "X-CodeDead-Signature";
+
+/// The backoff between the attempts of a delivery
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+    pub max_attempts: u32,
+}
+
+impl RetryPolicy {
+    /// Initialize a new RetryPolicy
+    ///
+    /// # Arguments
+    ///
+    /// * `initial_delay` - The delay after the first failed attempt
+    /// * `max_delay` - The maximum delay between two attempts
+    /// * `max_attempts` - The number of attempts after which a delivery has failed
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `RetryPolicy`
+    pub fn new(initial_delay: Duration, max_delay: Duration, max_attempts: u32) -> Self {
+        Self {
+            initial_delay,
+            max_delay,
+            max_attempts,
+        }
+    }
+
+    /// Determine the delay before the next attempt, doubling with every failed attempt
+    ///
+    /// # Arguments
+    ///
+    /// * `attempts` - The number of attempts that failed so far
+    ///
+    /// # Returns
+    ///
+    /// The delay before the next attempt
+    pub fn delay(&self, attempts: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempts.saturating_sub(1));
+        self.initial_delay
+            .saturating_mul(factor)
+            .min(self.max_delay)
+    }
+}
+
+/// The JSON body that is sent to subscribers when a Release is published
+///
+/// The Release is serialized the way the API returns it, so subscribers do not depend on the
+/// storage model.
+#[derive(Serialize)]
+struct ReleasePublishedPayload<'a> {
+    event: String,
+    #[serde(rename = "applicationId")]
+    application_id: &'a str,
+    platform: &'a str,
+    architecture: &'a str,
+    channel: ReleaseChannel,
+    release: ReleaseDto,
+    #[serde(rename = "occurredAt")]
+    occurred_at: &'a str,
+}
+
+/// Format a point in time the way deliveries store it, so that it sorts chronologically
+fn timestamp(time: DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+/// Notifies registered Webhooks about published Releases
+///
+/// Every notification is stored as a WebhookDelivery before it is sent, so the delivery log
+/// doubles as the retry queue and pending deliveries survive a restart.
+#[derive(Clone)]
+pub struct WebhookService {
+    webhook_repository: Arc<dyn WebhookRepository>,
+    delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+    retry_policy: RetryPolicy,
+    allow_http: bool,
+    client: reqwest::Client,
+}
+
+impl WebhookService {
+    /// Initialize a new WebhookService
+    ///
+    /// # Arguments
+    ///
+    /// * `webhook_repository` - The `WebhookRepository` to store Webhooks in
+    /// * `delivery_repository` - The `WebhookDeliveryRepository` to store deliveries in
+    /// * `retry_policy` - The backoff between the attempts of a delivery
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `WebhookService`
+    pub fn new(
+        webhook_repository: Arc<dyn WebhookRepository>,
+        delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+        retry_policy: RetryPolicy,
+    ) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .expect("Failed to initialize HTTP client");
+
+        Self {
+            webhook_repository,
+            delivery_repository,
+            retry_policy,
+            allow_http: false,
+            client,
+        }
+    }
+
+    /// Accept Webhooks with a plain `http` URL
+    ///
+    /// Payloads and signatures are then sent unencrypted, so this is only meant for subscribers
+    /// on a trusted network and for local development.
+    ///
+    /// # Returns
+    ///
+    /// The WebhookService that accepts `http` and `https` URLs
+    pub fn with_http_urls(mut self) -> Self {
+        self.allow_http = true;
+        self
+    }
+
+    /// Register a new Webhook
+    ///
+    /// The ID and creation timestamp are generated. A random secret is generated when the
+    /// Webhook does not have one.
+    ///
+    /// # Arguments
+    ///
+    /// * `webhook` - The Webhook to register
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the registered Webhook, including its secret
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the URL is not absolute, does not use `https` while plain
+    /// `http` URLs are not accepted, or the secret is too short, or a database error if the
+    /// repository fails
+    pub async fn register(&self, mut webhook: Webhook) -> Result<Webhook, ServiceError> {
+        info!("Registering webhook for {}", webhook.url);
+
+        require_absolute_url("url", &webhook.url)?;
+        if !self.allow_http && Url::parse(&webhook.url).is_ok_and(|u| u.scheme() != "https") {
+            return Err(ServiceError::Validation(format!(
+                "url must use https, got '{}'",
+                webhook.url
+            )));
+        }
+        if webhook.secret.is_empty() {
+            webhook.secret = format!(
+                "{}{}",
+                uuid::Uuid::new_v4().simple(),
+                uuid::Uuid::new_v4().simple()
+            );
+        } else if webhook.secret.len() < MIN_SECRET_LENGTH {
+            return Err(ServiceError::Validation(format!(
+                "secret must be at least {} characters long",
+                MIN_SECRET_LENGTH
+            )));
+        }
+
+        webhook.id = uuid::Uuid::new_v4().to_string();
+        webhook.created_at = chrono::Utc::now().to_rfc3339();
+
+        self.webhook_repository.create(&webhook).await?;
+        Ok(webhook)
+    }
+
+    /// Find all registered Webhooks
+    ///
+    /// # Returns
+    ///
+    /// A Result containing a Vec of Webhooks
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository fails
+    pub async fn get_all(&self) -> Result<Vec<Webhook>, RepositoryError> {
+        info!("Retrieving all webhooks");
+        self.webhook_repository.get_all().await
+    }
+
+    /// Find a single Webhook by its ID
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the Webhook
+    ///
+    /// # Returns
+    ///
+    /// A Result containing an Option with the Webhook if found, or None if not found
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository fails
+    pub async fn get_by_id(&self, id: &str) -> Result<Option<Webhook>, RepositoryError> {
+        info!("Retrieving webhook with ID: {}", id);
+        self.webhook_repository.get_by_id(id).await
+    }
+
+    /// Delete a Webhook by its ID
+    ///
+    /// Pending deliveries of the Webhook fail on their next attempt.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the Webhook
+    ///
+    /// # Returns
+    ///
+    /// An empty Result if the Webhook was deleted
+    ///
+    /// # Errors
+    ///
+    /// Returns a not found error if the Webhook does not exist, or a database error if the repository fails
+    pub async fn delete(&self, id: &str) -> Result<(), ServiceError> {
+        info!("Deleting webhook with ID: {}", id);
+
+        if self.webhook_repository.delete_by_id(id).await? {
+            Ok(())
+        } else {
+            Err(ServiceError::NotFound(format!(
+                "Webhook with ID {} was not found",
+                id
+            )))
+        }
+    }
+
+    /// Find the most recent deliveries of a Webhook
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the Webhook
+    /// * `limit` - The maximum number of deliveries to return
+    ///
+    /// # Returns
+    ///
+    /// A Result containing a Vec of deliveries, newest first
+    ///
+    /// # Errors
+    ///
+    /// Returns a not found error if the Webhook does not exist, or a database error if the repository fails
+    pub async fn deliveries(
+        &self,
+        id: &str,
+        limit: Option<i64>,
+    ) -> Result<Vec<WebhookDelivery>, ServiceError> {
+        info!("Retrieving deliveries of webhook with ID: {}", id);
+
+        if self.webhook_repository.get_by_id(id).await?.is_none() {
+            return Err(ServiceError::NotFound(format!(
+                "Webhook with ID {} was not found",
+                id
+            )));
+        }
+
+        Ok(self
+            .delivery_repository
+            .find_by_webhook_id(id, limit)
+            .await?)
+    }
+
+    /// Queue a notification about a published Release for every matching Webhook and start
+    /// sending them in the background
+    ///
+    /// # Arguments
+    ///
+    /// * `application_id` - The ID of the Application
+    /// * `platform` - The name of the platform of the Release
+    /// * `architecture` - The name of the architecture of the Release
+    /// * `release` - The published Release
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the number of queued deliveries
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository fails
+    pub async fn enqueue_release(
+        &self,
+        application_id: &str,
+        platform: &str,
+        architecture: &str,
+        release: &Release,
+    ) -> Result<usize, RepositoryError> {
+        let channel = release.effective_channel();
+        let webhooks: Vec<Webhook> = self
+            .webhook_repository
+            .get_all()
+            .await?
+            .into_iter()
+            .filter(|w| w.matches(application_id, &channel))
+            .collect();
+
+        if webhooks.is_empty() {
+            return Ok(0);
+        }
+
+        let now = timestamp(Utc::now());
+        let event = EventKind::ReleasePublished.to_string();
+        let payload = serde_json::to_string(&ReleasePublishedPayload {
+            event: event.clone(),
+            application_id,
+            platform,
+            architecture,
+            channel,
+            release: ReleaseDto::from(release.clone()),
+            occurred_at: &now,
+        })
+        .expect("A release payload can always be serialized");
+
+        for webhook in &webhooks {
+            self.delivery_repository
+                .create(&WebhookDelivery {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    webhook_id: webhook.id.clone(),
+                    event: event.clone(),
+                    payload: payload.clone(),
+                    status: DeliveryStatus::Pending,
+                    attempts: 0,
+                    next_attempt_at: now.clone(),
+                    last_status_code: None,
+                    last_error: None,
+                    created_at: now.clone(),
+                    updated_at: now.clone(),
+                })
+                .await?;
+        }
+
+        info!(
+            "Queued {} webhook deliveries for release {} of application with ID: {}",
+            webhooks.len(),
+            release.semver,
+            application_id
+        );
+        actix_web::rt::spawn(self.clone().deliver_due());
+
+        Ok(webhooks.len())
+    }
+
+    /// Send due deliveries every few seconds until the process exits
+    ///
+    /// This picks up retries that were scheduled before a restart or by another instance.
+    pub async fn run(self) {
+        let mut interval = actix_web::rt::time::interval(POLL_INTERVAL);
+        loop {
+            interval.tick().await;
+            self.clone().deliver_due().await;
+        }
+    }
+
+    /// Send every delivery that is due, one at a time
+    pub async fn deliver_due(self) {
+        loop {
+            let now = Utc::now();
+            let lease = chrono::Duration::from_std(CLAIM_LEASE).unwrap_or_default();
+            let claimed = self
+                .delivery_repository
+                .claim_due(&timestamp(now), &timestamp(now + lease))
+                .await;
+
+            match claimed {
+                Ok(Some(delivery)) => self.attempt(delivery).await,
+                Ok(None) => return,
+                Err(e) => {
+                    error!("Error claiming due webhook deliveries: {}", e);
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Send a delivery once and record the outcome
+    ///
+    /// A response with a 2xx status code marks the delivery as delivered. Otherwise the next
+    /// attempt is scheduled with an exponential backoff, until the maximum number of attempts
+    /// has been reached.
+    ///
+    /// # Arguments
+    ///
+    /// * `delivery` - The claimed delivery
+    async fn attempt(&self, mut delivery: WebhookDelivery) {
+        let webhook = match self
+            .webhook_repository
+            .get_by_id(&delivery.webhook_id)
+            .await
+        {
+            Ok(webhook) => webhook,
+            Err(e) => {
+                error!(
+                    "Error retrieving webhook of delivery {}: {}",
+                    delivery.id, e
+                );
+                return;
+            }
+        };
+
+        delivery.attempts += 1;
+        let outcome = match &webhook {
+            Some(webhook) => self.send(webhook, &delivery).await,
+            None => Err((None, String::from("The webhook was deleted"))),
+        };
+
+        let now = Utc::now();
+        delivery.updated_at = timestamp(now);
+        let mut retry = None;
+        match outcome {
+            Ok(status) => {
+                delivery.status = DeliveryStatus::Delivered;
+                delivery.last_status_code = Some(status);
+                delivery.last_error = None;
+            }
+            Err((status, message)) => {
+                delivery.last_status_code = status;
+                delivery.last_error = Some(message);
+                if webhook.is_none() || delivery.attempts >= self.retry_policy.max_attempts {
+                    warn!(
+                        "Webhook delivery {} failed after {} attempts",
+                        delivery.id, delivery.attempts
+                    );
+                    delivery.status = DeliveryStatus::Failed;
+                } else {
+                    let delay = self.retry_policy.delay(delivery.attempts);
+                    delivery.next_attempt_at =
+                        timestamp(now + chrono::Duration::from_std(delay).unwrap_or_default());
+                    retry = Some(delay);
+                }
+            }
+        }
+
+        if let Err(e) = self.delivery_repository.update(&delivery).await {
+            error!("Error updating webhook delivery {}: {}", delivery.id, e);
+            return;
+        }
+
+        if let Some(delay) = retry {
+            let service = self.clone();
+            actix_web::rt::spawn(async move {
+                actix_web::rt::time::sleep(delay).await;
+                service.deliver_due().await;
+            });
+        }
+    }
+
+    /// Post the payload of a delivery to a Webhook
+    ///
+    /// # Arguments
+    ///
+    /// * `webhook` - The Webhook to notify
+    /// * `delivery` - The delivery to send
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the status code of the response
+    ///
+    /// # Errors
+    ///
+    /// Returns the status code, if any, and a description of the failure if the request failed
+    /// or the subscriber responded with a status code other than 2xx
+    async fn send(
+        &self,
+        webhook: &Webhook,
+        delivery: &WebhookDelivery,
+    ) -> Result<u16, (Option<u16>, String)> {
+        let signed_at = Utc::now().timestamp().to_string();
+        let signature = Self::sign(&webhook.secret, &signed_at, &delivery.payload);
+
+        let response = self
+            .client
+            .post(&webhook.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(EVENT_HEADER, &delivery.event)
+            .header(DELIVERY_HEADER, &delivery.id)
+            .header(TIMESTAMP_HEADER, &signed_at)
+            .header(SIGNATURE_HEADER, signature)
+            .body(delivery.payload.clone())
+            .send()
+            .await
+            .map_err(|e| (None, e.to_string()))?;
+
+        let status = response.status();
+        if status.is_success() {
+            Ok(status.as_u16())
+        } else {
+            Err((
+                Some(status.as_u16()),
+                format!("The subscriber responded with {}", status),
+            ))
+        }
+    }
+
+    /// Sign a payload the way subscribers are expected to verify it
+    ///
+    /// # Arguments
+    ///
+    /// * `secret` - The secret of the Webhook
+    /// * `timestamp` - The Unix time at which the payload is sent
+    /// * `payload` - The JSON body
+    ///
+    /// # Returns
+    ///
+    /// The value of the signature header: `sha256=` followed by the hex-encoded HMAC-SHA256 of
+    /// `{timestamp}.{payload}`
+    pub fn sign(secret: &str, timestamp: &str, payload: &str) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(timestamp.as_bytes());
+        mac.update(b".");
+        mac.update(payload.as_bytes());
+        format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::webhook::dao::in_memory_webhook_delivery_repository::InMemoryWebhookDeliveryRepository;
+    use crate::repository::webhook::dao::in_memory_webhook_repository::InMemoryWebhookRepository;
+
+    fn webhook(url: &str) -> Webhook {
+        Webhook {
+            id: String::new(),
+            url: String::from(url),
+            secret: String::new(),
+            application_id: None,
+            channel: None,
+            created_at: String::new(),
+        }
+    }
+
+    #[test]
+    fn delay_doubles_up_to_the_maximum() {
+        let policy = RetryPolicy::new(Duration::from_secs(10), Duration::from_secs(60), 5);
+
+        let delays: Vec<u64> = (1..=5).map(|a| policy.delay(a).as_secs()).collect();
+
+        assert_eq!(delays, vec![10, 20, 40, 60, 60]);
+        assert_eq!(policy.delay(u32::MAX), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn sign_computes_the_hmac_of_the_timestamp_and_payload() {
+        // The expected value was computed with `printf '1700000000.{}' | openssl dgst -sha256 -hmac secret`
+        assert_eq!(
+            WebhookService::sign("secret", "1700000000", "{}"),
+            "sha256=b8569b78799ff9e3cbff0fc2d63a33a2b57f3282abd07c37ae5e8e7d79a5f163"
+        );
+    }
+
+    #[actix_web::test]
+    async fn register_requires_https_unless_http_urls_are_accepted() {
+        let service = WebhookService::new(
+            Arc::new(InMemoryWebhookRepository::default()),
+            Arc::new(InMemoryWebhookDeliveryRepository::default()),
+            RetryPolicy::new(Duration::from_secs(10), Duration::from_secs(60), 5),
+        );
+
+        let insecure = service.register(webhook("http://codedead.com/hook")).await;
+        let secure = service.register(webhook("https://codedead.com/hook")).await;
+        let accepted = service
+            .with_http_urls()
+            .register(webhook("http://codedead.com/hook"))
+            .await;
+
+        assert!(matches!(insecure, Err(ServiceError::Validation(_))));
+        assert!(secure.is_ok());
+        assert!(accepted.is_ok());
+    }
+}
@@ -0,0 +1,430 @@
+//! End-to-end tests that exercise the HTTP routes against in-memory repositories
+
+pub(crate) mod actuator_routes;
+pub(crate) mod appcast_routes;
+pub(crate) mod application_routes;
+pub(crate) mod authentication_routes;
+pub(crate) mod cors_routes;
+pub(crate) mod event_routes;
+pub(crate) mod feed_routes;
+pub(crate) mod package_routes;
+pub(crate) mod version_routes;
+pub(crate) mod webhook_routes;
+
+use crate::config::server_config::ServerConfig;
+use crate::errors::repository_error::RepositoryError;
+use crate::repository::api_key::dao::in_memory_api_key_repository::InMemoryApiKeyRepository;
+use crate::repository::api_key::model::api_key::ApiKey;
+use crate::repository::application::dao::application_repository::ApplicationRepository;
+use crate::repository::application::dao::in_memory_application_repository::InMemoryApplicationRepository;
+use crate::repository::application::model::application::{
+    Application, ApplicationPlatform, Architecture, Release,
+};
+use crate::repository::application::model::application_changes::ApplicationChanges;
+use crate::repository::health::dao::health_repository::HealthRepository;
+use crate::repository::health::dao::in_memory_health_repository::InMemoryHealthRepository;
+use crate::repository::semver::SemVer;
+use crate::repository::v2::dao::in_memory_version_repository::InMemoryVersionRepository;
+use crate::repository::v2::dao::version_repository::VersionRepository;
+use crate::repository::v2::model::version::{Platform, Version};
+use crate::repository::webhook::dao::in_memory_webhook_delivery_repository::InMemoryWebhookDeliveryRepository;
+use crate::repository::webhook::dao::in_memory_webhook_repository::InMemoryWebhookRepository;
+use crate::services::Services;
+use crate::services::api_key::api_key_service::ApiKeyService;
+use crate::services::application::application_service::ApplicationService;
+use crate::services::cache::ttl_cache::CacheConfig;
+use crate::services::event::event_service::EventService;
+use crate::services::health::health_service::HealthService;
+use crate::services::info::info_service::InfoService;
+use crate::services::jwt::jwt_service::JwtService;
+use crate::services::metrics::metrics_service::MetricsService;
+use crate::services::version::version_service::VersionService;
+use crate::services::webhook::webhook_service::{RetryPolicy, WebhookService};
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The base URL context of the test server
+pub const SERVER_CONTEXT: &str = "http://localhost:8080";
+/// The maximum page size of the test server, small enough to exercise pagination
+pub const MAX_FETCH_LIMIT: i64 = 2;
+/// The heartbeat interval of event streams, short enough to observe a heartbeat
+pub const HEARTBEAT_INTERVAL: Duration = Duration::from_millis(100);
+/// The maximum number of open event streams, small enough to exceed
+pub const MAX_SUBSCRIBERS: usize = 3;
+/// The delay before the first retry of a webhook delivery, short enough to observe retries
+pub const WEBHOOK_RETRY_DELAY: Duration = Duration::from_millis(50);
+/// The number of attempts after which a webhook delivery fails
+pub const WEBHOOK_MAX_ATTEMPTS: u32 = 3;
+/// An API key that carries every write scope
+pub const WRITE_KEY: &str = "write-key";
+/// An API key that carries no scopes at all
+pub const READ_ONLY_KEY: &str = "read-only-key";
+/// The HS256 secret with which the test server verifies bearer tokens
+pub const JWT_SECRET: &str = "test-shared-secret";
+/// The issuer that bearer tokens must be issued by
+pub const JWT_ISSUER: &str = "https://auth.codedead.com";
+/// The audience that bearer tokens must be issued for
+pub const JWT_AUDIENCE: &str = "codedead_api";
+
+/// Initialize the actix service with all routes of the API for a ServerConfig
+macro_rules! init_app {
+    ($config:expr) => {
+        actix_web::test::init_service(
+            actix_web::App::new()
+                .app_data(actix_web::web::Data::new($config))
+                .configure(crate::web::controller::Controller::configure_routes),
+        )
+        .await
+    };
+}
+
+pub(crate) use init_app;
+
+/// Sign a bearer token that the test server accepts for an hour
+///
+/// # Arguments
+///
+/// * `scope` - The space-delimited scopes of the token
+///
+/// # Returns
+///
+/// The HS256-signed JWT
+pub fn bearer_token(scope: &str) -> String {
+    let now = chrono::Utc::now().timestamp();
+    jsonwebtoken::encode(
+        &jsonwebtoken::Header::default(),
+        &serde_json::json!({
+            "sub": "release-bot",
+            "iss": JWT_ISSUER,
+            "aud": JWT_AUDIENCE,
+            "nbf": now,
+            "exp": now + 3600,
+            "scope": scope,
+        }),
+        &jsonwebtoken::EncodingKey::from_secret(JWT_SECRET.as_bytes()),
+    )
+    .unwrap()
+}
+
+/// Build a Release that is downloaded from a predictable URL
+pub fn release(semver: &str) -> Release {
+    Release {
+        name: Some(format!("Release {}", semver)),
+        description: None,
+        portable: Some(false),
+        release_date: Some(String::from("2026-01-01")),
+        release_type: None,
+        channel: None,
+        semver: semver.parse().unwrap(),
+        download_url: format!("https://codedead.com/downloads/{}.exe", semver),
+        info_url: None,
+        checksum: None,
+        size: None,
+        signature: None,
+    }
+}
+
+/// Build an Architecture with the provided releases
+fn architecture(name: &str, releases: Option<Vec<Release>>) -> Architecture {
+    Architecture {
+        name: name.to_string(),
+        url: String::from("https://codedead.com"),
+        releases,
+    }
+}
+
+/// Build an Application with the provided platforms
+fn application(id: &str, platforms: Option<Vec<ApplicationPlatform>>) -> Application {
+    Application {
+        id: id.to_string(),
+        created_at: String::from("2026-01-01T00:00:00+00:00"),
+        updated_at: String::from("2026-01-01T00:00:00+00:00"),
+        name: id.to_string(),
+        description: Some(format!("The {} application", id)),
+        platforms,
+    }
+}
+
+/// The Applications that every test store is seeded with
+///
+/// `advanced-passgen` has stable and beta releases on Windows, an architecture without releases
+/// and a single release on Linux. `deadhash` has a single release and `memplus` has no platforms.
+pub fn applications() -> Vec<Application> {
+    vec![
+        application(
+            "advanced-passgen",
+            Some(vec![
+                ApplicationPlatform {
+                    platform_name: String::from("Windows"),
+                    architectures: Some(vec![
+                        architecture(
+                            "x64",
+                            Some(vec![
+                                release("1.0.0"),
+                                release("1.1.0"),
+                                release("2.0.0-beta.1"),
+                            ]),
+                        ),
+                        architecture("arm64", None),
+                    ]),
+                },
+                ApplicationPlatform {
+                    platform_name: String::from("Linux"),
+                    architectures: Some(vec![architecture("x64", Some(vec![release("1.0.1")]))]),
+                },
+            ]),
+        ),
+        application(
+            "deadhash",
+            Some(vec![ApplicationPlatform {
+                platform_name: String::from("Windows"),
+                architectures: Some(vec![architecture("x64", Some(vec![release("1.0.0")]))]),
+            }]),
+        ),
+        application("memplus", None),
+    ]
+}
+
+/// Build a Version with a single Windows platform
+pub fn version(id: &str, semver: &str) -> Version {
+    Version {
+        id: id.to_string(),
+        created_at: String::from("2026-01-01T00:00:00+00:00"),
+        updated_at: String::from("2026-01-01T00:00:00+00:00"),
+        name: Some(format!("Version {}", semver)),
+        description: None,
+        semver: semver.parse().unwrap(),
+        platforms: vec![Platform {
+            name: Some(String::from("Windows")),
+            arch: Some(String::from("x64")),
+            portable: Some(false),
+            download_url: format!("https://codedead.com/downloads/{}.exe", semver),
+            info_url: None,
+        }],
+    }
+}
+
+/// The Version records that every test store is seeded with
+pub fn versions() -> Vec<Version> {
+    vec![
+        version("v1", "1.0.0"),
+        version("v2", "1.1.0"),
+        version("v3", "2.0.0"),
+    ]
+}
+
+/// Build an ApiKey record for a secret
+fn api_key(id: &str, secret: &str, scopes: &[&str]) -> ApiKey {
+    ApiKey {
+        id: id.to_string(),
+        name: id.to_string(),
+        hash: ApiKeyService::hash(secret),
+        scopes: scopes.iter().map(|s| s.to_string()).collect(),
+        created_at: String::from("2026-01-01T00:00:00Z"),
+        expires_at: None,
+        revoked: Some(false),
+    }
+}
+
+/// The name of the collection that holds Application records
+pub const APPLICATION_COLLECTION: &str = "applications";
+/// The name of the collection that holds Version records
+pub const VERSION_COLLECTION: &str = "versions";
+
+/// Build a ServerConfig around the provided repositories
+fn server_config(
+    application_repository: Arc<dyn ApplicationRepository>,
+    version_repository: Arc<dyn VersionRepository>,
+    health_repository: Arc<dyn HealthRepository>,
+) -> ServerConfig {
+    let api_key_repository = InMemoryApiKeyRepository::with(vec![
+        api_key(
+            "writer",
+            WRITE_KEY,
+            &["applications:write", "versions:write", "webhooks:manage"],
+        ),
+        api_key("reader", READ_ONLY_KEY, &[]),
+    ]);
+
+    let metrics_service = MetricsService::new();
+    let cache_config = CacheConfig::new(Duration::from_secs(60), 100);
+    let event_service = EventService::new(HEARTBEAT_INTERVAL, MAX_SUBSCRIBERS);
+    let webhook_service = WebhookService::new(
+        Arc::new(InMemoryWebhookRepository::default()),
+        Arc::new(InMemoryWebhookDeliveryRepository::default()),
+        RetryPolicy::new(
+            WEBHOOK_RETRY_DELAY,
+            WEBHOOK_RETRY_DELAY * 4,
+            WEBHOOK_MAX_ATTEMPTS,
+        ),
+    )
+    .with_http_urls();
+
+    let services = Services::new(
+        ApplicationService::new(application_repository)
+            .with_cache(cache_config, &metrics_service)
+            .with_events(&event_service)
+            .with_webhooks(&webhook_service),
+        VersionService::new(version_repository).with_cache(cache_config, &metrics_service),
+        ApiKeyService::new(Arc::new(api_key_repository)),
+        JwtService::new(
+            Some(String::from(JWT_ISSUER)),
+            Some(String::from(JWT_AUDIENCE)),
+        )
+        .with_hs256_secret(JWT_SECRET),
+        HealthService::new(
+            health_repository,
+            APPLICATION_COLLECTION,
+            VERSION_COLLECTION,
+        ),
+        metrics_service,
+        InfoService::new(),
+        event_service,
+        webhook_service,
+        None,
+    );
+
+    ServerConfig::new(
+        "127.0.0.1",
+        8080,
+        MAX_FETCH_LIMIT,
+        1,
+        services,
+        SERVER_CONTEXT,
+        vec![],
+    )
+}
+
+/// Build a ServerConfig whose repositories are seeded with the test fixtures
+pub fn seeded() -> ServerConfig {
+    server_config(
+        Arc::new(InMemoryApplicationRepository::with(applications())),
+        Arc::new(InMemoryVersionRepository::with(versions())),
+        Arc::new(InMemoryHealthRepository::with(&[
+            APPLICATION_COLLECTION,
+            VERSION_COLLECTION,
+        ])),
+    )
+}
+
+/// Build a ServerConfig whose repositories fail on every operation
+pub fn failing() -> ServerConfig {
+    server_config(
+        Arc::new(FailingRepository),
+        Arc::new(FailingRepository),
+        Arc::new(FailingRepository),
+    )
+}
+
+/// A repository that behaves like an unreachable database
+struct FailingRepository;
+
+/// The error that is returned by every FailingRepository operation
+fn unavailable() -> RepositoryError {
+    RepositoryError::Database(mongodb::error::Error::custom("database unavailable"))
+}
+
+#[async_trait]
+impl ApplicationRepository for FailingRepository {
+    async fn get_by_id(&self, _id: &str) -> Result<Option<Application>, RepositoryError> {
+        Err(unavailable())
+    }
+
+    async fn get_all_sorted_by_id(
+        &self,
+        _limit: Option<i64>,
+    ) -> Result<Vec<Application>, RepositoryError> {
+        Err(unavailable())
+    }
+
+    async fn get_all_with_id_greater_than(
+        &self,
+        _id: &str,
+        _limit: Option<i64>,
+    ) -> Result<Vec<Application>, RepositoryError> {
+        Err(unavailable())
+    }
+
+    async fn create(&self, _application: &Application) -> Result<(), RepositoryError> {
+        Err(unavailable())
+    }
+
+    async fn update(
+        &self,
+        _id: &str,
+        _changes: &ApplicationChanges,
+        _updated_at: &str,
+    ) -> Result<Option<Application>, RepositoryError> {
+        Err(unavailable())
+    }
+
+    async fn delete_by_id(&self, _id: &str) -> Result<bool, RepositoryError> {
+        Err(unavailable())
+    }
+
+    async fn push_release(
+        &self,
+        _id: &str,
+        _platform: &str,
+        _architecture: &str,
+        _release: &Release,
+        _initialize: bool,
+        _updated_at: &str,
+    ) -> Result<bool, RepositoryError> {
+        Err(unavailable())
+    }
+}
+
+#[async_trait]
+impl VersionRepository for FailingRepository {
+    async fn find_by_id(&self, _id: &str) -> Result<Option<Version>, RepositoryError> {
+        Err(unavailable())
+    }
+
+    async fn get_all_sorted_by_id(
+        &self,
+        _limit: Option<i64>,
+    ) -> Result<Vec<Version>, RepositoryError> {
+        Err(unavailable())
+    }
+
+    async fn get_all_with_id_greater_than(
+        &self,
+        _id: &str,
+        _limit: Option<i64>,
+    ) -> Result<Vec<Version>, RepositoryError> {
+        Err(unavailable())
+    }
+
+    async fn exists_by_semver(
+        &self,
+        _semver: &SemVer,
+        _exclude_id: Option<&str>,
+    ) -> Result<bool, RepositoryError> {
+        Err(unavailable())
+    }
+
+    async fn create(&self, _version: &Version) -> Result<(), RepositoryError> {
+        Err(unavailable())
+    }
+
+    async fn update(&self, _version: &Version) -> Result<Option<Version>, RepositoryError> {
+        Err(unavailable())
+    }
+
+    async fn delete_by_id(&self, _id: &str) -> Result<bool, RepositoryError> {
+        Err(unavailable())
+    }
+}
+
+#[async_trait]
+impl HealthRepository for FailingRepository {
+    async fn ping(&self) -> Result<(), RepositoryError> {
+        Err(unavailable())
+    }
+
+    async fn collection_names(&self) -> Result<Vec<String>, RepositoryError> {
+        Err(unavailable())
+    }
+}
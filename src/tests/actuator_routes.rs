@@ -0,0 +1,157 @@
+use crate::tests::{failing, init_app, seeded};
+use actix_web::http::{StatusCode, header};
+use actix_web::test;
+use serde_json::{Value, json};
+
+#[actix_web::test]
+async fn health_is_ok() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/actuators/health")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+}
+
+#[actix_web::test]
+async fn health_does_not_depend_on_the_database() {
+    let app = init_app!(failing());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/actuators/health")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+}
+
+#[actix_web::test]
+async fn unknown_routes_are_not_found() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/actuators/unknown")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["instance"], "/api/v1/actuators/unknown");
+}
+
+#[actix_web::test]
+async fn liveness_does_not_depend_on_the_database() {
+    let app = init_app!(failing());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/actuators/health/liveness")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body, json!({ "status": "UP" }));
+}
+
+#[actix_web::test]
+async fn readiness_reports_every_component() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/actuators/health/readiness")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["status"], "UP");
+    for component in ["mongodb", "applications", "versions"] {
+        assert_eq!(body["components"][component]["status"], "UP");
+        assert!(body["components"][component]["latencyMs"].is_number());
+    }
+}
+
+#[actix_web::test]
+async fn readiness_is_unavailable_when_the_database_is_down() {
+    let app = init_app!(failing());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/actuators/health/readiness")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["status"], "DOWN");
+    assert_eq!(body["components"]["mongodb"]["status"], "DOWN");
+    assert_eq!(body["components"]["versions"]["status"], "DOWN");
+}
+
+#[actix_web::test]
+async fn metrics_count_requests_by_route_pattern() {
+    let app = init_app!(seeded());
+
+    for id in ["advanced-passgen", "deadhash", "unknown", "deadhash"] {
+        let req = test::TestRequest::get()
+            .uri(&format!("/api/v1/applications/{}", id))
+            .to_request();
+        test::call_service(&app, req).await;
+    }
+    let req = test::TestRequest::get()
+        .uri("/api/v1/actuators/metrics")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        resp.headers().get(header::CONTENT_TYPE).unwrap(),
+        "text/plain; version=0.0.4"
+    );
+    let body = String::from_utf8(test::read_body(resp).await.to_vec()).unwrap();
+    assert!(body.contains(
+        "http_requests_total{method=\"GET\",route=\"/api/v1/applications/{id}\",status=\"200\"} 3"
+    ));
+    assert!(body.contains(
+        "http_requests_total{method=\"GET\",route=\"/api/v1/applications/{id}\",status=\"404\"} 1"
+    ));
+    assert!(body.contains("http_request_duration_seconds_bucket"));
+    assert!(body.contains("cache_lookups_total{cache=\"applications\",result=\"miss\"} 3"));
+    assert!(body.contains("cache_lookups_total{cache=\"applications\",result=\"hit\"} 1"));
+}
+
+#[actix_web::test]
+async fn info_describes_the_build_and_configuration() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/actuators/info")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["build"]["version"], env!("CARGO_PKG_VERSION"));
+    assert!(body["build"]["gitCommit"].is_string());
+    assert!(body["build"]["buildTimestamp"].is_string());
+    assert!(
+        body["build"]["rustcVersion"]
+            .as_str()
+            .unwrap()
+            .starts_with("rustc")
+    );
+    assert!(body["runtime"]["uptimeSeconds"].is_u64());
+    assert_eq!(
+        body["config"],
+        json!({
+            "host": "127.0.0.1",
+            "port": 8080,
+            "maxFetchLimit": 2,
+            "workers": 1,
+            "serverContext": "http://localhost:8080",
+            "corsAllowedOrigins": [],
+            "jwtEnabled": true
+        })
+    );
+}
@@ -0,0 +1,97 @@
+use crate::tests::{SERVER_CONTEXT, WRITE_KEY, init_app, seeded};
+use actix_web::http::{StatusCode, header};
+use actix_web::test;
+use serde_json::json;
+
+/// Fetch an appcast and return its body
+macro_rules! fetch_appcast {
+    ($app:expr, $uri:expr) => {{
+        let req = test::TestRequest::get().uri($uri).to_request();
+        let resp = test::call_service($app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            resp.headers().get(header::CONTENT_TYPE).unwrap(),
+            "application/rss+xml; charset=utf-8"
+        );
+        String::from_utf8(test::read_body(resp).await.to_vec()).unwrap()
+    }};
+}
+
+#[actix_web::test]
+async fn appcast_lists_the_stable_releases_of_a_platform_newest_first() {
+    let app = init_app!(seeded());
+
+    let body = fetch_appcast!(
+        &app,
+        "/api/v1/applications/advanced-passgen/platforms/windows/appcast.xml"
+    );
+
+    assert!(body.contains(
+        "<rss version=\"2.0\" xmlns:sparkle=\"http://www.andymatuschak.org/xml-namespaces/sparkle\">"
+    ));
+    assert!(body.contains(&format!(
+        "<link>{}/api/v1/applications/advanced-passgen/platforms/windows/appcast.xml</link>",
+        SERVER_CONTEXT
+    )));
+    assert_eq!(body.matches("<item>").count(), 2);
+    assert!(!body.contains("2.0.0-beta.1"));
+    assert!(!body.contains("1.0.1"));
+    assert!(body.find("1.1.0").unwrap() < body.find("1.0.0").unwrap());
+    assert!(body.contains("<sparkle:version>1.1.0</sparkle:version>"));
+    assert!(body.contains("<pubDate>Thu, 1 Jan 2026 00:00:00 +0000</pubDate>"));
+    assert!(body.contains(
+        "<enclosure url=\"https://codedead.com/downloads/1.1.0.exe\" sparkle:version=\"1.1.0\" sparkle:shortVersionString=\"1.1.0\" type=\"application/octet-stream\" sparkle:os=\"windows-x64\"/>"
+    ));
+}
+
+#[actix_web::test]
+async fn appcast_includes_release_notes_length_and_signature_when_available() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/applications/deadhash/platforms/windows/architectures/x64/releases")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .set_json(json!({
+            "semver": "1.1.0-rc.1+build.7",
+            "channel": "beta",
+            "downloadUrl": "https://codedead.com/downloads/deadhash-1.1.0.exe",
+            "infoUrl": "https://codedead.com/deadhash/changelog",
+            "size": 1048576,
+            "signature": "c2lnbmF0dXJl"
+        }))
+        .to_request();
+    test::call_service(&app, req).await;
+    let stable = fetch_appcast!(
+        &app,
+        "/api/v1/applications/deadhash/platforms/Windows/appcast.xml?arch=X64"
+    );
+    let beta = fetch_appcast!(
+        &app,
+        "/api/v1/applications/deadhash/platforms/Windows/appcast.xml?channel=beta"
+    );
+
+    assert_eq!(stable.matches("<item>").count(), 1);
+    assert_eq!(beta.matches("<item>").count(), 2);
+    assert!(beta.contains("<sparkle:version>1.1.0-rc.1+build.7</sparkle:version>"));
+    assert!(beta.contains("<sparkle:shortVersionString>1.1.0</sparkle:shortVersionString>"));
+    assert!(beta.contains(
+        "<sparkle:releaseNotesLink>https://codedead.com/deadhash/changelog</sparkle:releaseNotesLink>"
+    ));
+    assert!(beta.contains(" length=\"1048576\" sparkle:edSignature=\"c2lnbmF0dXJl\"/>"));
+}
+
+#[actix_web::test]
+async fn appcast_of_unknown_platform_or_architecture_is_not_found() {
+    let app = init_app!(seeded());
+
+    for uri in [
+        "/api/v1/applications/unknown/platforms/windows/appcast.xml",
+        "/api/v1/applications/memplus/platforms/windows/appcast.xml",
+        "/api/v1/applications/deadhash/platforms/windows/appcast.xml?arch=arm64",
+    ] {
+        let req = test::TestRequest::get().uri(uri).to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND, "{}", uri);
+    }
+}
@@ -0,0 +1,775 @@
+use crate::tests::{READ_ONLY_KEY, SERVER_CONTEXT, WRITE_KEY, failing, init_app, seeded};
+use actix_web::http::{StatusCode, header};
+use actix_web::test;
+use serde_json::{Value, json};
+
+/// Read the Link header of a response
+fn link(resp: &actix_web::dev::ServiceResponse) -> String {
+    resp.headers()
+        .get(header::LINK)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[actix_web::test]
+async fn find_by_id_returns_the_application() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/advanced-passgen")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["id"], "advanced-passgen");
+    assert_eq!(body["createdAt"], "2026-01-01T00:00:00+00:00");
+    let releases = &body["platforms"][0]["architectures"][0]["releases"];
+    assert_eq!(releases.as_array().unwrap().len(), 3);
+    assert_eq!(releases[0]["channel"], "stable");
+    assert_eq!(releases[2]["channel"], "beta");
+}
+
+#[actix_web::test]
+async fn find_by_id_filters_releases_by_channel() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/advanced-passgen?channel=stable")
+        .to_request();
+    let body: Value = test::call_and_read_body_json(&app, req).await;
+
+    let releases = body["platforms"][0]["architectures"][0]["releases"]
+        .as_array()
+        .unwrap();
+    assert_eq!(releases.len(), 2);
+    assert!(releases.iter().all(|r| r["channel"] == "stable"));
+}
+
+#[actix_web::test]
+async fn find_by_id_answers_conditional_requests_with_not_modified() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/deadhash")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    let etag = resp.headers().get(header::ETAG).unwrap().clone();
+    assert!(etag.to_str().unwrap().starts_with('"'));
+    assert_eq!(
+        resp.headers().get(header::LAST_MODIFIED).unwrap(),
+        "Thu, 01 Jan 2026 00:00:00 GMT"
+    );
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/deadhash")
+        .insert_header((header::IF_NONE_MATCH, etag.clone()))
+        .to_request();
+    let not_modified = test::call_service(&app, req).await;
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/deadhash")
+        .insert_header((header::IF_MODIFIED_SINCE, "Fri, 02 Jan 2026 00:00:00 GMT"))
+        .to_request();
+    let not_modified_since = test::call_service(&app, req).await;
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/deadhash?channel=stable")
+        .insert_header((header::IF_NONE_MATCH, etag.clone()))
+        .to_request();
+    let other_representation = test::call_service(&app, req).await;
+
+    assert_eq!(not_modified.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(not_modified.headers().get(header::ETAG), Some(&etag));
+    assert!(test::read_body(not_modified).await.is_empty());
+    assert_eq!(not_modified_since.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(other_representation.status(), StatusCode::OK);
+}
+
+#[actix_web::test]
+async fn find_by_id_changes_its_etag_when_the_application_changes() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/deadhash")
+        .to_request();
+    let etag = test::call_service(&app, req)
+        .await
+        .headers()
+        .get(header::ETAG)
+        .unwrap()
+        .clone();
+    let req = test::TestRequest::patch()
+        .uri("/api/v1/applications/deadhash")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .set_json(json!({ "name": "DeadHash" }))
+        .to_request();
+    test::call_service(&app, req).await;
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/deadhash")
+        .insert_header((header::IF_NONE_MATCH, etag))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+}
+
+#[actix_web::test]
+async fn find_by_id_of_unknown_application_is_not_found() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/unknown")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    assert_eq!(
+        resp.headers().get(header::CONTENT_TYPE).unwrap(),
+        "application/problem+json"
+    );
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(
+        body,
+        json!({
+            "type": "about:blank",
+            "title": "Not Found",
+            "status": 404,
+            "detail": "Application with ID unknown was not found",
+            "instance": "/api/v1/applications/unknown"
+        })
+    );
+}
+
+#[actix_web::test]
+async fn find_by_id_reports_database_errors() {
+    let app = init_app!(failing());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/advanced-passgen")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(
+        body["detail"],
+        "Error fetching application with ID advanced-passgen"
+    );
+}
+
+#[actix_web::test]
+async fn find_all_links_to_the_next_page_when_the_page_is_full() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        link(&resp),
+        format!(
+            "<{0}/api/v1/applications/?limit=2>; rel=first, <{0}/api/v1/applications/?page=deadhash&limit=2>; rel=next",
+            SERVER_CONTEXT
+        )
+    );
+    let body: Value = test::read_body_json(resp).await;
+    let ids: Vec<&str> = body
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|a| a["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(ids, vec!["advanced-passgen", "deadhash"]);
+}
+
+#[actix_web::test]
+async fn find_all_only_links_to_the_first_page_on_the_last_page() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/?page=deadhash&limit=10")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        link(&resp),
+        format!(
+            "<{}/api/v1/applications/?limit=2>; rel=first",
+            SERVER_CONTEXT
+        )
+    );
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body.as_array().unwrap().len(), 1);
+    assert_eq!(body[0]["id"], "memplus");
+}
+
+#[actix_web::test]
+async fn find_all_answers_conditional_requests_with_not_modified() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/")
+        .to_request();
+    let etag = test::call_service(&app, req)
+        .await
+        .headers()
+        .get(header::ETAG)
+        .unwrap()
+        .clone();
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/")
+        .insert_header((header::IF_NONE_MATCH, etag.clone()))
+        .to_request();
+    let not_modified = test::call_service(&app, req).await;
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/?page=deadhash")
+        .insert_header((header::IF_NONE_MATCH, etag))
+        .to_request();
+    let next_page = test::call_service(&app, req).await;
+
+    assert_eq!(not_modified.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(next_page.status(), StatusCode::OK);
+}
+
+#[actix_web::test]
+async fn find_all_ignores_if_modified_since_after_a_deletion() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/")
+        .to_request();
+    let listed = test::call_service(&app, req).await;
+    let req = test::TestRequest::delete()
+        .uri("/api/v1/applications/memplus")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .to_request();
+    test::call_service(&app, req).await;
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/")
+        .insert_header((header::IF_MODIFIED_SINCE, "Fri, 01 Jan 2100 00:00:00 GMT"))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert!(listed.headers().get(header::LAST_MODIFIED).is_none());
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = test::read_body_json(resp).await;
+    assert!(
+        body.as_array()
+            .unwrap()
+            .iter()
+            .all(|a| a["id"] != "memplus")
+    );
+}
+
+#[actix_web::test]
+async fn find_all_past_the_last_page_is_not_found() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/?page=memplus")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn find_all_reports_database_errors() {
+    let app = init_app!(failing());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[actix_web::test]
+async fn check_for_update_returns_the_newer_release() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri(
+            "/api/v1/applications/advanced-passgen/updates?current=1.0.0&platform=windows&arch=x64",
+        )
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["semver"], "1.1.0");
+}
+
+#[actix_web::test]
+async fn check_for_update_includes_pre_releases_on_the_beta_channel() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/advanced-passgen/updates?current=1.1.0&platform=Windows&arch=x64&channel=beta")
+        .to_request();
+    let body: Value = test::call_and_read_body_json(&app, req).await;
+
+    assert_eq!(body["semver"], "2.0.0-beta.1");
+}
+
+#[actix_web::test]
+async fn check_for_update_without_newer_release_has_no_content() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri(
+            "/api/v1/applications/advanced-passgen/updates?current=1.1.0&platform=Windows&arch=x64",
+        )
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+}
+
+#[actix_web::test]
+async fn check_for_update_rejects_invalid_versions() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/advanced-passgen/updates?current=1.0&platform=Windows&arch=x64")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn check_for_update_of_unknown_architecture_is_not_found() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/advanced-passgen/updates?current=1.0.0&platform=Windows&arch=arm64")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn check_for_update_reports_database_errors() {
+    let app = init_app!(failing());
+
+    let req = test::TestRequest::get()
+        .uri(
+            "/api/v1/applications/advanced-passgen/updates?current=1.0.0&platform=Windows&arch=x64",
+        )
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[actix_web::test]
+async fn find_latest_release_searches_all_platforms() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/advanced-passgen/latest")
+        .to_request();
+    let stable: Value = test::call_and_read_body_json(&app, req).await;
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/advanced-passgen/latest?prerelease=true")
+        .to_request();
+    let pre_release: Value = test::call_and_read_body_json(&app, req).await;
+
+    assert_eq!(stable["semver"], "1.1.0");
+    assert_eq!(pre_release["semver"], "2.0.0-beta.1");
+}
+
+#[actix_web::test]
+async fn find_latest_release_of_unknown_application_is_not_found() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/unknown/latest")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn find_latest_release_reports_database_errors() {
+    let app = init_app!(failing());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/advanced-passgen/latest")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[actix_web::test]
+async fn find_latest_platform_release_is_restricted_to_the_platform() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/advanced-passgen/platforms/linux/latest")
+        .to_request();
+    let body: Value = test::call_and_read_body_json(&app, req).await;
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/advanced-passgen/platforms/macos/latest")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(body["semver"], "1.0.1");
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn find_latest_architecture_release_is_restricted_to_the_architecture() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/advanced-passgen/platforms/Windows/architectures/x64/latest")
+        .to_request();
+    let body: Value = test::call_and_read_body_json(&app, req).await;
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/advanced-passgen/platforms/Windows/architectures/arm64/latest")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(body["semver"], "1.1.0");
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn download_redirects_to_the_latest_release() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/advanced-passgen/download?platform=Windows&arch=x64")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::FOUND);
+    assert_eq!(
+        resp.headers().get(header::LOCATION).unwrap(),
+        "https://codedead.com/downloads/1.1.0.exe"
+    );
+    assert_eq!(
+        resp.headers().get(header::CACHE_CONTROL).unwrap(),
+        "no-cache"
+    );
+}
+
+#[actix_web::test]
+async fn download_without_platform_is_a_bad_request() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/advanced-passgen/download")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["title"], "Bad Request");
+    assert_eq!(
+        body["instance"],
+        "/api/v1/applications/advanced-passgen/download"
+    );
+    assert!(
+        body["detail"]
+            .as_str()
+            .unwrap()
+            .starts_with("Invalid query string")
+    );
+}
+
+#[actix_web::test]
+async fn download_of_unknown_platform_is_not_found() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/advanced-passgen/download?platform=macOS")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn create_application_requires_credentials() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/applications/")
+        .set_json(json!({ "id": "pk-finder", "name": "PK Finder" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    assert!(resp.headers().contains_key(header::WWW_AUTHENTICATE));
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["status"], 401);
+    assert_eq!(body["instance"], "/api/v1/applications/");
+}
+
+#[actix_web::test]
+async fn create_application_requires_the_write_scope() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/applications/")
+        .insert_header(("X-Api-Key", READ_ONLY_KEY))
+        .set_json(json!({ "id": "pk-finder", "name": "PK Finder" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+}
+
+#[actix_web::test]
+async fn create_application_stores_the_application() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/applications/")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", WRITE_KEY)))
+        .set_json(json!({ "id": "pk-finder", "name": "PK Finder" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    assert_eq!(
+        resp.headers()
+            .get(header::LOCATION)
+            .unwrap()
+            .to_str()
+            .unwrap(),
+        format!("{}/api/v1/applications/pk-finder", SERVER_CONTEXT)
+    );
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["name"], "PK Finder");
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/pk-finder")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+}
+
+#[actix_web::test]
+async fn create_application_rejects_duplicates_and_invalid_data() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/applications/")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .set_json(json!({ "id": "deadhash", "name": "DeadHash" }))
+        .to_request();
+    let duplicate = test::call_service(&app, req).await;
+    let req = test::TestRequest::post()
+        .uri("/api/v1/applications/")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .set_json(json!({ "id": "pk-finder", "name": " " }))
+        .to_request();
+    let invalid = test::call_service(&app, req).await;
+
+    assert_eq!(duplicate.status(), StatusCode::CONFLICT);
+    assert_eq!(invalid.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn create_application_with_a_body_of_the_wrong_shape_is_unprocessable() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/applications/")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .set_json(json!({ "id": "pk-finder", "name": 42 }))
+        .to_request();
+    let malformed = test::TestRequest::post()
+        .uri("/api/v1/applications/")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .insert_header((header::CONTENT_TYPE, "application/json"))
+        .set_payload("{")
+        .to_request();
+    let unprocessable = test::call_service(&app, req).await;
+    let bad_request = test::call_service(&app, malformed).await;
+
+    assert_eq!(unprocessable.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(bad_request.status(), StatusCode::BAD_REQUEST);
+    let body: Value = test::read_body_json(unprocessable).await;
+    assert_eq!(body["title"], "Unprocessable Entity");
+    assert_eq!(body["instance"], "/api/v1/applications/");
+}
+
+#[actix_web::test]
+async fn create_application_reports_database_errors() {
+    let app = init_app!(failing());
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/applications/")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .set_json(json!({ "id": "pk-finder", "name": "PK Finder" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[actix_web::test]
+async fn replace_application_replaces_all_fields() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::put()
+        .uri("/api/v1/applications/deadhash")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .set_json(json!({ "name": "DeadHash" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["name"], "DeadHash");
+    assert_eq!(body["description"], Value::Null);
+    assert_eq!(body["platforms"], Value::Null);
+    assert_eq!(body["createdAt"], "2026-01-01T00:00:00+00:00");
+}
+
+#[actix_web::test]
+async fn replace_application_of_unknown_application_is_not_found() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::put()
+        .uri("/api/v1/applications/unknown")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .set_json(json!({ "name": "Unknown" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn patch_application_only_changes_provided_fields() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::patch()
+        .uri("/api/v1/applications/deadhash")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .set_json(json!({ "description": null }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["name"], "deadhash");
+    assert_eq!(body["description"], Value::Null);
+    assert!(body["platforms"].is_array());
+}
+
+#[actix_web::test]
+async fn delete_application_removes_the_application() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::delete()
+        .uri("/api/v1/applications/memplus")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .to_request();
+    let deleted = test::call_service(&app, req).await;
+    let req = test::TestRequest::delete()
+        .uri("/api/v1/applications/memplus")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .to_request();
+    let missing = test::call_service(&app, req).await;
+
+    assert_eq!(deleted.status(), StatusCode::NO_CONTENT);
+    assert_eq!(missing.status(), StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn writes_invalidate_cached_applications_and_pages() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/memplus")
+        .to_request();
+    test::call_service(&app, req).await;
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/?limit=2")
+        .to_request();
+    let before: Value = test::call_and_read_body_json(&app, req).await;
+    let req = test::TestRequest::delete()
+        .uri("/api/v1/applications/advanced-passgen")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .to_request();
+    test::call_service(&app, req).await;
+    let req = test::TestRequest::patch()
+        .uri("/api/v1/applications/memplus")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .set_json(json!({ "name": "MemPlus 2" }))
+        .to_request();
+    test::call_service(&app, req).await;
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/memplus")
+        .to_request();
+    let patched: Value = test::call_and_read_body_json(&app, req).await;
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/?limit=2")
+        .to_request();
+    let after: Value = test::call_and_read_body_json(&app, req).await;
+
+    assert_eq!(before[0]["id"], "advanced-passgen");
+    assert_eq!(after[0]["id"], "deadhash");
+    assert_eq!(patched["name"], "MemPlus 2");
+}
+
+#[actix_web::test]
+async fn create_release_publishes_into_the_architecture() {
+    let app = init_app!(seeded());
+    let release = json!({
+        "semver": "1.2.0",
+        "downloadUrl": "https://codedead.com/downloads/1.2.0.exe"
+    });
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/applications/advanced-passgen/platforms/windows/architectures/x64/releases")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .set_json(&release)
+        .to_request();
+    let created = test::call_service(&app, req).await;
+    let req = test::TestRequest::post()
+        .uri("/api/v1/applications/advanced-passgen/platforms/windows/architectures/x64/releases")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .set_json(&release)
+        .to_request();
+    let duplicate = test::call_service(&app, req).await;
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/advanced-passgen/latest")
+        .to_request();
+    let latest: Value = test::call_and_read_body_json(&app, req).await;
+
+    assert_eq!(created.status(), StatusCode::CREATED);
+    assert_eq!(duplicate.status(), StatusCode::CONFLICT);
+    assert_eq!(latest["semver"], "1.2.0");
+}
+
+#[actix_web::test]
+async fn create_release_into_unknown_architecture_is_not_found() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/applications/advanced-passgen/platforms/Windows/architectures/x86/releases")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .set_json(json!({
+            "semver": "1.2.0",
+            "downloadUrl": "https://codedead.com/downloads/1.2.0.exe"
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
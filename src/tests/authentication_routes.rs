@@ -0,0 +1,90 @@
+use crate::tests::{bearer_token, init_app, seeded};
+use actix_web::dev::ServiceResponse;
+use actix_web::http::{StatusCode, header};
+use actix_web::test;
+use serde_json::{Value, json};
+
+/// Create an application with an optional Authorization header
+fn create_application(authorization: Option<String>) -> test::TestRequest {
+    let req = test::TestRequest::post()
+        .uri("/api/v1/applications/")
+        .set_json(json!({ "id": "pk-finder", "name": "PK Finder" }));
+    match authorization {
+        Some(authorization) => req.insert_header((header::AUTHORIZATION, authorization)),
+        None => req,
+    }
+}
+
+/// Read the WWW-Authenticate challenge of a response
+fn challenge<B>(resp: &ServiceResponse<B>) -> String {
+    resp.headers()
+        .get(header::WWW_AUTHENTICATE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[actix_web::test]
+async fn requests_without_a_token_are_challenged() {
+    let app = init_app!(seeded());
+
+    let resp = test::call_service(&app, create_application(None).to_request()).await;
+
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(challenge(&resp), "Bearer realm=\"codedead_api\"");
+}
+
+#[actix_web::test]
+async fn requests_with_an_invalid_token_are_rejected() {
+    let app = init_app!(seeded());
+
+    let token = bearer_token("applications:write");
+    let tampered = format!("{}x", token);
+    let resp = test::call_service(
+        &app,
+        create_application(Some(format!("Bearer {}", tampered))).to_request(),
+    )
+    .await;
+
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(
+        challenge(&resp),
+        "Bearer realm=\"codedead_api\", error=\"invalid_token\", error_description=\"The bearer token is invalid or has expired\""
+    );
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["status"], 401);
+}
+
+#[actix_web::test]
+async fn requests_with_too_little_scope_are_forbidden() {
+    let app = init_app!(seeded());
+
+    let token = bearer_token("versions:write webhooks:manage");
+    let resp = test::call_service(
+        &app,
+        create_application(Some(format!("Bearer {}", token))).to_request(),
+    )
+    .await;
+
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    assert_eq!(
+        challenge(&resp),
+        "Bearer realm=\"codedead_api\", error=\"insufficient_scope\", scope=\"applications:write\""
+    );
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["status"], 403);
+}
+
+#[actix_web::test]
+async fn requests_with_a_token_of_the_required_scope_are_authorized() {
+    let app = init_app!(seeded());
+
+    let token = bearer_token("applications:write");
+    let resp = test::call_service(
+        &app,
+        create_application(Some(format!("Bearer {}", token))).to_request(),
+    )
+    .await;
+
+    assert_eq!(resp.status(), StatusCode::CREATED);
+}
@@ -0,0 +1,121 @@
+use crate::build_cors;
+use crate::tests::seeded;
+use actix_web::http::{Method, StatusCode, header};
+use actix_web::test;
+
+const WRITE_ORIGIN: &str = "https://codedead.com";
+const OTHER_ORIGIN: &str = "https://example.com";
+
+/// Initialize the actix service with all routes of the API behind the CORS middleware
+macro_rules! init_cors_app {
+    () => {
+        test::init_service(
+            actix_web::App::new()
+                .app_data(actix_web::web::Data::new(seeded()))
+                .wrap(build_cors(&[String::from(WRITE_ORIGIN)]))
+                .configure(crate::web::controller::Controller::configure_routes),
+        )
+        .await
+    };
+}
+
+/// Build a preflight request for a method and request headers from an origin
+fn preflight(uri: &str, origin: &str, method: &str, headers: &str) -> test::TestRequest {
+    test::TestRequest::default()
+        .method(Method::OPTIONS)
+        .uri(uri)
+        .insert_header((header::ORIGIN, origin))
+        .insert_header((header::ACCESS_CONTROL_REQUEST_METHOD, method))
+        .insert_header((header::ACCESS_CONTROL_REQUEST_HEADERS, headers))
+}
+
+#[actix_web::test]
+async fn reads_are_allowed_from_any_origin_and_expose_validators() {
+    let app = init_cors_app!();
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/?limit=1")
+        .insert_header((header::ORIGIN, OTHER_ORIGIN))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        resp.headers()
+            .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+            .unwrap(),
+        OTHER_ORIGIN
+    );
+    let exposed = resp
+        .headers()
+        .get(header::ACCESS_CONTROL_EXPOSE_HEADERS)
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_ascii_lowercase();
+    for name in ["link", "etag", "last-modified", "location"] {
+        assert!(exposed.contains(name), "{}", name);
+    }
+}
+
+#[actix_web::test]
+async fn conditional_and_event_stream_headers_pass_the_preflight() {
+    let app = init_cors_app!();
+
+    let resp = test::call_service(
+        &app,
+        preflight(
+            "/api/v1/events",
+            OTHER_ORIGIN,
+            "GET",
+            "if-none-match,if-modified-since,last-event-id",
+        )
+        .to_request(),
+    )
+    .await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+}
+
+#[actix_web::test]
+async fn writes_are_only_allowed_from_the_configured_origins() {
+    let app = init_cors_app!();
+
+    let allowed = test::call_service(
+        &app,
+        preflight(
+            "/api/v1/applications/",
+            WRITE_ORIGIN,
+            "POST",
+            "content-type,x-api-key",
+        )
+        .to_request(),
+    )
+    .await;
+    let rejected = test::call_service(
+        &app,
+        preflight(
+            "/api/v1/applications/",
+            OTHER_ORIGIN,
+            "POST",
+            "content-type,x-api-key",
+        )
+        .to_request(),
+    )
+    .await;
+
+    assert_eq!(allowed.status(), StatusCode::OK);
+    assert_eq!(
+        allowed
+            .headers()
+            .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+            .unwrap(),
+        WRITE_ORIGIN
+    );
+    assert_eq!(rejected.status(), StatusCode::BAD_REQUEST);
+    assert!(
+        !rejected
+            .headers()
+            .contains_key(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+    );
+}
@@ -0,0 +1,214 @@
+use crate::tests::{HEARTBEAT_INTERVAL, MAX_SUBSCRIBERS, WRITE_KEY, init_app, seeded};
+use actix_web::body::MessageBody;
+use actix_web::dev::ServiceResponse;
+use actix_web::http::{StatusCode, header};
+use actix_web::test;
+use serde_json::{Value, json};
+use std::future::poll_fn;
+use std::pin::Pin;
+use std::time::Duration;
+
+/// Read the next chunk of a streaming response body, failing if none arrives in time
+async fn next_chunk<B: MessageBody>(body: &mut Pin<Box<B>>) -> String {
+    let chunk = actix_web::rt::time::timeout(
+        HEARTBEAT_INTERVAL * 10,
+        poll_fn(|cx| body.as_mut().poll_next(cx)),
+    )
+    .await
+    .expect("the stream produced no chunk in time")
+    .expect("the stream ended")
+    .unwrap_or_else(|_| panic!("the stream failed"));
+
+    String::from_utf8(chunk.to_vec()).unwrap()
+}
+
+/// Read the next Server-Sent Event of a stream, skipping heartbeats
+async fn next_event<B: MessageBody>(body: &mut Pin<Box<B>>) -> (String, Value) {
+    loop {
+        let chunk = next_chunk(body).await;
+        if chunk.starts_with(':') {
+            continue;
+        }
+
+        let field = |name: &str| {
+            chunk
+                .lines()
+                .find_map(|l| l.strip_prefix(name))
+                .unwrap()
+                .to_string()
+        };
+        return (
+            field("event: "),
+            serde_json::from_str(&field("data: ")).unwrap(),
+        );
+    }
+}
+
+/// Open an event stream and return its body
+fn stream_body<B: MessageBody>(resp: ServiceResponse<B>) -> Pin<Box<B>> {
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        resp.headers().get(header::CONTENT_TYPE).unwrap(),
+        "text/event-stream"
+    );
+    assert_eq!(
+        resp.headers().get(header::CACHE_CONTROL).unwrap(),
+        "no-cache"
+    );
+    Box::pin(resp.into_body())
+}
+
+#[actix_web::test]
+async fn events_stream_every_change() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get().uri("/api/v1/events").to_request();
+    let mut body = stream_body(test::call_service(&app, req).await);
+    let req = test::TestRequest::post()
+        .uri("/api/v1/applications/")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .set_json(json!({ "id": "pk-finder", "name": "PK Finder" }))
+        .to_request();
+    test::call_service(&app, req).await;
+    let req = test::TestRequest::delete()
+        .uri("/api/v1/applications/memplus")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .to_request();
+    test::call_service(&app, req).await;
+
+    let (created, data) = next_event(&mut body).await;
+    let (deleted, _) = next_event(&mut body).await;
+    assert_eq!(created, "application-created");
+    assert_eq!(data["type"], "application-created");
+    assert_eq!(data["applicationId"], "pk-finder");
+    assert_eq!(deleted, "application-deleted");
+}
+
+#[actix_web::test]
+async fn application_events_only_stream_changes_to_the_application() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/advanced-passgen/events")
+        .to_request();
+    let mut body = stream_body(test::call_service(&app, req).await);
+    let req = test::TestRequest::patch()
+        .uri("/api/v1/applications/deadhash")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .set_json(json!({ "name": "DeadHash" }))
+        .to_request();
+    test::call_service(&app, req).await;
+    let req = test::TestRequest::post()
+        .uri("/api/v1/applications/advanced-passgen/platforms/windows/architectures/x64/releases")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .set_json(json!({
+            "semver": "1.2.0",
+            "downloadUrl": "https://codedead.com/downloads/1.2.0.exe"
+        }))
+        .to_request();
+    test::call_service(&app, req).await;
+
+    let (event, data) = next_event(&mut body).await;
+    assert_eq!(event, "release-published");
+    assert_eq!(data["applicationId"], "advanced-passgen");
+    assert_eq!(data["platform"], "Windows");
+    assert_eq!(data["architecture"], "x64");
+    assert_eq!(data["semver"], "1.2.0");
+}
+
+#[actix_web::test]
+async fn events_resume_after_the_last_event_id() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get().uri("/api/v1/events").to_request();
+    let mut first = stream_body(test::call_service(&app, req).await);
+    for name in ["First", "Second"] {
+        let req = test::TestRequest::patch()
+            .uri("/api/v1/applications/deadhash")
+            .insert_header(("X-Api-Key", WRITE_KEY))
+            .set_json(json!({ "name": name }))
+            .to_request();
+        test::call_service(&app, req).await;
+    }
+    let (_, received) = next_event(&mut first).await;
+    let last_event_id = received["id"].as_str().unwrap();
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/deadhash/events")
+        .insert_header(("Last-Event-ID", last_event_id))
+        .to_request();
+    let mut body = stream_body(test::call_service(&app, req).await);
+
+    let (event, data) = next_event(&mut body).await;
+    let (epoch, sequence) = last_event_id.split_once('-').unwrap();
+    assert_eq!(event, "application-updated");
+    assert_eq!(sequence, "1");
+    assert_eq!(data["id"], format!("{}-2", epoch));
+}
+
+#[actix_web::test]
+async fn events_do_not_resume_from_ids_of_a_previous_run() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::patch()
+        .uri("/api/v1/applications/deadhash")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .set_json(json!({ "name": "First" }))
+        .to_request();
+    test::call_service(&app, req).await;
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/deadhash/events")
+        .insert_header(("Last-Event-ID", "0-0"))
+        .to_request();
+    let mut body = stream_body(test::call_service(&app, req).await);
+
+    assert_eq!(next_chunk(&mut body).await, ": heartbeat\n\n");
+}
+
+#[actix_web::test]
+async fn events_send_heartbeats_while_idle() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get().uri("/api/v1/events").to_request();
+    let mut body = stream_body(test::call_service(&app, req).await);
+    let started = actix_web::rt::time::Instant::now();
+
+    assert_eq!(next_chunk(&mut body).await, ": heartbeat\n\n");
+    assert!(started.elapsed() >= HEARTBEAT_INTERVAL - Duration::from_millis(10));
+}
+
+#[actix_web::test]
+async fn application_events_of_unknown_application_is_not_found() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/unknown/events")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["instance"], "/api/v1/applications/unknown/events");
+}
+
+#[actix_web::test]
+async fn events_beyond_the_maximum_number_of_streams_are_refused() {
+    let app = init_app!(seeded());
+
+    let mut streams = vec![];
+    for _ in 0..MAX_SUBSCRIBERS {
+        let req = test::TestRequest::get().uri("/api/v1/events").to_request();
+        streams.push(stream_body(test::call_service(&app, req).await));
+    }
+    let req = test::TestRequest::get().uri("/api/v1/events").to_request();
+    let refused = test::call_service(&app, req).await;
+    streams.pop();
+    let req = test::TestRequest::get().uri("/api/v1/events").to_request();
+    let reopened = test::call_service(&app, req).await;
+
+    assert_eq!(refused.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(refused.headers().get(header::RETRY_AFTER).unwrap(), "30");
+    let body: Value = test::read_body_json(refused).await;
+    assert_eq!(body["title"], "Too Many Requests");
+    assert_eq!(body["instance"], "/api/v1/events");
+    assert_eq!(reopened.status(), StatusCode::OK);
+}
@@ -0,0 +1,134 @@
+use crate::tests::{SERVER_CONTEXT, WRITE_KEY, init_app, seeded};
+use actix_web::http::{StatusCode, header};
+use actix_web::test;
+use serde_json::json;
+
+/// Fetch a feed and return its content type and body
+macro_rules! fetch_feed {
+    ($app:expr, $uri:expr) => {{
+        let req = test::TestRequest::get().uri($uri).to_request();
+        let resp = test::call_service($app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let content_type = resp
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+        let body = test::read_body(resp).await;
+        (content_type, String::from_utf8(body.to_vec()).unwrap())
+    }};
+}
+
+#[actix_web::test]
+async fn application_atom_feed_lists_releases_newest_first() {
+    let app = init_app!(seeded());
+
+    let (content_type, body) = fetch_feed!(&app, "/api/v1/applications/advanced-passgen/feed.atom");
+
+    assert_eq!(content_type, "application/atom+xml; charset=utf-8");
+    assert!(body.starts_with(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<feed xmlns=\"http://www.w3.org/2005/Atom\">"
+    ));
+    assert!(body.contains(&format!(
+        "<link rel=\"self\" type=\"application/atom+xml\" href=\"{}/api/v1/applications/advanced-passgen/feed.atom\"/>",
+        SERVER_CONTEXT
+    )));
+    assert!(body.contains("  <updated>2026-01-01T00:00:00Z</updated>"));
+    assert_eq!(body.matches("<entry>").count(), 4);
+    let first = body.find("Release 2.0.0-beta.1 (Windows x64)").unwrap();
+    let last = body.find("Release 1.0.0 (Windows x64)").unwrap();
+    assert!(first < last);
+    assert!(body.contains(
+        "<link rel=\"enclosure\" type=\"application/octet-stream\" href=\"https://codedead.com/downloads/1.0.1.exe\"/>"
+    ));
+}
+
+#[actix_web::test]
+async fn application_rss_feed_restricts_releases_to_a_channel() {
+    let app = init_app!(seeded());
+
+    let (content_type, body) = fetch_feed!(
+        &app,
+        "/api/v1/applications/advanced-passgen/feed.rss?channel=stable"
+    );
+
+    assert_eq!(content_type, "application/rss+xml; charset=utf-8");
+    assert!(body.contains("<rss version=\"2.0\""));
+    assert!(body.contains("<lastBuildDate>Thu, 1 Jan 2026 00:00:00 +0000</lastBuildDate>"));
+    assert_eq!(body.matches("<item>").count(), 3);
+    assert!(!body.contains("2.0.0-beta.1"));
+    assert!(body.contains(
+        "<enclosure url=\"https://codedead.com/downloads/1.1.0.exe\" length=\"0\" type=\"application/octet-stream\"/>"
+    ));
+    assert!(body.contains("<pubDate>Thu, 1 Jan 2026 00:00:00 +0000</pubDate>"));
+}
+
+#[actix_web::test]
+async fn feeds_escape_release_data_and_follow_new_releases() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::post()
+        .uri("/api/v1/applications/deadhash/platforms/windows/architectures/x64/releases")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .set_json(json!({
+            "name": "Fish & Chips",
+            "description": "Fixes <script> injection",
+            "semver": "1.1.0",
+            "releaseDate": "2026-03-01T12:30:00+02:00",
+            "downloadUrl": "https://codedead.com/downloads/deadhash.exe?v=1.1.0&arch=x64",
+            "infoUrl": "https://codedead.com/deadhash"
+        }))
+        .to_request();
+    test::call_service(&app, req).await;
+    let (_, body) = fetch_feed!(&app, "/api/v1/applications/deadhash/feed.atom");
+
+    let entry = &body[body.find("<entry>").unwrap()..];
+    assert!(entry.contains("<title>deadhash Fish &amp; Chips (Windows x64)</title>"));
+    assert!(entry.contains("<published>2026-03-01T10:30:00Z</published>"));
+    assert!(entry.contains("<summary>Fixes &lt;script&gt; injection</summary>"));
+    assert!(entry.contains("<link rel=\"alternate\" href=\"https://codedead.com/deadhash\"/>"));
+    assert!(
+        entry.contains("href=\"https://codedead.com/downloads/deadhash.exe?v=1.1.0&amp;arch=x64\"")
+    );
+}
+
+#[actix_web::test]
+async fn global_feed_lists_the_releases_of_all_applications() {
+    let app = init_app!(seeded());
+
+    let (_, atom) = fetch_feed!(&app, "/api/v1/feed.atom");
+    let (_, rss) = fetch_feed!(&app, "/api/v1/feed.rss");
+    let req = test::TestRequest::get()
+        .uri("/api/v1/feed.atom")
+        .to_request();
+    let etag = test::call_service(&app, req)
+        .await
+        .headers()
+        .get(header::ETAG)
+        .unwrap()
+        .clone();
+    let req = test::TestRequest::get()
+        .uri("/api/v1/feed.atom")
+        .insert_header((header::IF_NONE_MATCH, etag))
+        .to_request();
+    let not_modified = test::call_service(&app, req).await;
+
+    assert_eq!(atom.matches("<entry>").count(), 5);
+    assert!(atom.contains("deadhash Release 1.0.0 (Windows x64)"));
+    assert_eq!(rss.matches("<item>").count(), 5);
+    assert_eq!(not_modified.status(), StatusCode::NOT_MODIFIED);
+}
+
+#[actix_web::test]
+async fn application_feed_of_unknown_application_is_not_found() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/unknown/feed.rss")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,366 @@
+use crate::tests::{WRITE_KEY, init_app, seeded};
+use actix_web::http::StatusCode;
+use actix_web::test;
+use serde_json::{Value, json};
+
+const CHECKSUM: &str = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+
+/// Add a Release to an architecture of the Windows platform of an Application
+macro_rules! add_release {
+    ($app:expr, $id:expr, $arch:expr, $release:expr) => {{
+        let req = test::TestRequest::post()
+            .uri(&format!(
+                "/api/v1/applications/{}/platforms/Windows/architectures/{}/releases",
+                $id, $arch
+            ))
+            .insert_header(("X-Api-Key", WRITE_KEY))
+            .set_json($release)
+            .to_request();
+        let resp = test::call_service($app, req).await;
+        assert!(resp.status().is_success());
+    }};
+}
+
+/// Create an Application with macOS and Windows releases of different versions per architecture
+macro_rules! create_application {
+    ($app:expr) => {{
+        let release = |semver: &str, url: &str| {
+            json!({
+                "semver": semver,
+                "portable": true,
+                "downloadUrl": url,
+                "checksum": CHECKSUM
+            })
+        };
+        let req = test::TestRequest::post()
+            .uri("/api/v1/applications/")
+            .insert_header(("X-Api-Key", WRITE_KEY))
+            .set_json(json!({
+                "id": "pk-finder",
+                "name": "PK Finder",
+                "description": "Recovers product keys.\nSupports Windows and macOS.",
+                "platforms": [
+                    {
+                        "platformName": "macOS",
+                        "architectures": [
+                            {
+                                "name": "arm64",
+                                "url": "https://codedead.com/pk-finder",
+                                "releases": [
+                                    release("1.0.0", "https://codedead.com/pk-finder-1.0.0-arm64.dmg"),
+                                    release("1.1.0", "https://codedead.com/pk-finder-1.1.0-arm64.dmg")
+                                ]
+                            },
+                            {
+                                "name": "x64",
+                                "url": "https://codedead.com/pk-finder",
+                                "releases": [release("1.0.0", "https://codedead.com/pk-finder-1.0.0-x64.dmg")]
+                            }
+                        ]
+                    },
+                    {
+                        "platformName": "Windows",
+                        "architectures": [
+                            {
+                                "name": "x64",
+                                "url": "https://codedead.com/pk-finder",
+                                "releases": [release("1.1.0", "https://codedead.com/pk-finder-1.1.0-x64.exe?src=api")]
+                            },
+                            {
+                                "name": "x86",
+                                "url": "https://codedead.com/pk-finder",
+                                "releases": [release("1.0.0", "https://codedead.com/pk-finder-1.0.0-x86.exe")]
+                            }
+                        ]
+                    }
+                ]
+            }))
+            .to_request();
+        let resp = test::call_service($app, req).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+    }};
+}
+
+/// Find the content of a manifest by its file name
+fn manifest<'a>(body: &'a Value, file_name: &str) -> &'a str {
+    body["manifests"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|m| m["fileName"] == file_name)
+        .unwrap()["content"]
+        .as_str()
+        .unwrap()
+}
+
+#[actix_web::test]
+async fn winget_renders_the_manifests_of_the_latest_windows_release() {
+    let app = init_app!(seeded());
+    add_release!(
+        &app,
+        "advanced-passgen",
+        "x64",
+        json!({
+            "semver": "1.2.0",
+            "description": "Fixes: a crash",
+            "releaseDate": "2026-02-01",
+            "downloadUrl": "https://codedead.com/downloads/passgen-1.2.0-x64.exe",
+            "infoUrl": "https://codedead.com/passgen/changelog",
+            "checksum": format!("sha256:{}", CHECKSUM)
+        })
+    );
+    add_release!(
+        &app,
+        "advanced-passgen",
+        "arm64",
+        json!({
+            "semver": "1.2.0",
+            "downloadUrl": "https://codedead.com/downloads/passgen-1.2.0-arm64.msi",
+            "checksum": CHECKSUM.to_uppercase()
+        })
+    );
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/advanced-passgen/winget?license=GPL-3.0")
+        .to_request();
+    let body: Value = test::call_and_read_body_json(&app, req).await;
+
+    assert_eq!(body["packageIdentifier"], "CodeDead.AdvancedPassgen");
+    assert_eq!(body["packageVersion"], "1.2.0");
+    assert_eq!(body["path"], "manifests/c/CodeDead/AdvancedPassgen/1.2.0");
+
+    let version = manifest(&body, "CodeDead.AdvancedPassgen.yaml");
+    assert!(version.starts_with(
+        "# yaml-language-server: $schema=https://aka.ms/winget-manifest.version.1.6.0.schema.json\n"
+    ));
+    assert!(version.contains("PackageIdentifier: CodeDead.AdvancedPassgen\n"));
+    assert!(
+        version.contains("DefaultLocale: en-US\nManifestType: version\nManifestVersion: 1.6.0\n")
+    );
+
+    let installer = manifest(&body, "CodeDead.AdvancedPassgen.installer.yaml");
+    assert!(installer.contains("ReleaseDate: 2026-02-01\n"));
+    assert!(installer.contains(&format!(
+        "- Architecture: x64\n  InstallerType: exe\n  InstallerUrl: https://codedead.com/downloads/passgen-1.2.0-x64.exe\n  InstallerSha256: {}\n",
+        CHECKSUM.to_uppercase()
+    )));
+    assert!(installer.contains("- Architecture: arm64\n  InstallerType: msi\n"));
+    assert!(installer.ends_with("ManifestType: installer\nManifestVersion: 1.6.0\n"));
+
+    let locale = manifest(&body, "CodeDead.AdvancedPassgen.locale.en-US.yaml");
+    assert!(
+        locale
+            .contains("PackageLocale: en-US\nPublisher: CodeDead\nPackageName: advanced-passgen\n")
+    );
+    assert!(
+        locale.contains("License: GPL-3.0\nShortDescription: The advanced-passgen application\n")
+    );
+    assert!(locale.contains("ReleaseNotes: 'Fixes: a crash'\n"));
+    assert!(locale.contains("ReleaseNotesUrl: https://codedead.com/passgen/changelog\n"));
+}
+
+#[actix_web::test]
+async fn winget_of_a_requested_version_uses_the_requested_identifier() {
+    let app = init_app!(seeded());
+    add_release!(
+        &app,
+        "deadhash",
+        "x64",
+        json!({
+            "semver": "2.0.0-beta.1",
+            "portable": true,
+            "downloadUrl": "https://codedead.com/downloads/deadhash.exe",
+            "checksum": CHECKSUM
+        })
+    );
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/deadhash/winget?version=2.0.0-beta.1&packageIdentifier=Example.Hash&publisher=Example")
+        .to_request();
+    let body: Value = test::call_and_read_body_json(&app, req).await;
+
+    assert_eq!(body["path"], "manifests/e/Example/Hash/2.0.0-beta.1");
+    let installer = manifest(&body, "Example.Hash.installer.yaml");
+    assert!(installer.contains("PackageVersion: 2.0.0-beta.1\n"));
+    assert!(installer.contains("  InstallerType: portable\n"));
+    assert!(!installer.contains("ReleaseDate"));
+}
+
+#[actix_web::test]
+async fn winget_without_installers_or_checksums_is_rejected() {
+    let app = init_app!(seeded());
+
+    for (uri, status) in [
+        ("/api/v1/applications/unknown/winget", StatusCode::NOT_FOUND),
+        ("/api/v1/applications/memplus/winget", StatusCode::NOT_FOUND),
+        (
+            "/api/v1/applications/deadhash/winget?version=9.9.9",
+            StatusCode::NOT_FOUND,
+        ),
+        (
+            "/api/v1/applications/deadhash/winget?version=latest",
+            StatusCode::BAD_REQUEST,
+        ),
+        (
+            "/api/v1/applications/deadhash/winget",
+            StatusCode::UNPROCESSABLE_ENTITY,
+        ),
+    ] {
+        let req = test::TestRequest::get().uri(uri).to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), status, "{}", uri);
+    }
+}
+
+#[actix_web::test]
+async fn homebrew_cask_uses_the_latest_release_of_each_architecture() {
+    let app = init_app!(seeded());
+    create_application!(&app);
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/pk-finder/homebrew.rb")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body = String::from_utf8(test::read_body(resp).await.to_vec()).unwrap();
+    assert_eq!(
+        body,
+        format!(
+            r#"cask "pk-finder" do
+  on_arm do
+    version "1.1.0"
+    sha256 "{checksum}"
+
+    url "https://codedead.com/pk-finder-1.1.0-arm64.dmg"
+  end
+
+  on_intel do
+    version "1.0.0"
+    sha256 "{checksum}"
+
+    url "https://codedead.com/pk-finder-1.0.0-x64.dmg"
+  end
+
+  name "PK Finder"
+  desc "Recovers product keys"
+  homepage "https://codedead.com/pk-finder"
+
+  app "PK Finder.app"
+end
+"#,
+            checksum = CHECKSUM
+        )
+    );
+}
+
+#[actix_web::test]
+async fn scoop_manifest_only_includes_architectures_of_the_latest_version() {
+    let app = init_app!(seeded());
+    create_application!(&app);
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/pk-finder/scoop.json?license=MIT")
+        .to_request();
+    let body: Value = test::call_and_read_body_json(&app, req).await;
+
+    assert_eq!(
+        body,
+        json!({
+            "version": "1.1.0",
+            "description": "Recovers product keys.",
+            "homepage": "https://codedead.com/pk-finder",
+            "license": "MIT",
+            "architecture": {
+                "64bit": {
+                    "url": "https://codedead.com/pk-finder-1.1.0-x64.exe?src=api",
+                    "hash": CHECKSUM,
+                    "bin": "pk-finder-1.1.0-x64.exe"
+                }
+            }
+        })
+    );
+}
+
+#[actix_web::test]
+async fn scoop_manifest_ignores_newer_releases_of_unsupported_architectures() {
+    let app = init_app!(seeded());
+    create_application!(&app);
+    let req = test::TestRequest::patch()
+        .uri("/api/v1/applications/pk-finder")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .set_json(json!({
+            "platforms": [{
+                "platformName": "Windows",
+                "architectures": [
+                    {
+                        "name": "x64",
+                        "url": "https://codedead.com/pk-finder",
+                        "releases": [{
+                            "semver": "1.1.0",
+                            "downloadUrl": "https://codedead.com/pk-finder-1.1.0-x64.exe",
+                            "checksum": CHECKSUM
+                        }]
+                    },
+                    {
+                        "name": "arm",
+                        "url": "https://codedead.com/pk-finder",
+                        "releases": [{
+                            "semver": "1.2.0",
+                            "downloadUrl": "https://codedead.com/pk-finder-1.2.0-arm.exe",
+                            "checksum": CHECKSUM
+                        }]
+                    }
+                ]
+            }]
+        }))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/applications/pk-finder/scoop.json?license=MIT")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["version"], "1.1.0");
+    assert_eq!(
+        body["architecture"]["64bit"]["url"],
+        "https://codedead.com/pk-finder-1.1.0-x64.exe"
+    );
+}
+
+#[actix_web::test]
+async fn homebrew_and_scoop_without_releases_or_checksums_are_rejected() {
+    let app = init_app!(seeded());
+
+    for (uri, status) in [
+        (
+            "/api/v1/applications/unknown/homebrew.rb",
+            StatusCode::NOT_FOUND,
+        ),
+        (
+            "/api/v1/applications/unknown/scoop.json",
+            StatusCode::NOT_FOUND,
+        ),
+        (
+            "/api/v1/applications/deadhash/homebrew.rb",
+            StatusCode::NOT_FOUND,
+        ),
+        (
+            "/api/v1/applications/memplus/scoop.json",
+            StatusCode::NOT_FOUND,
+        ),
+        (
+            "/api/v1/applications/deadhash/scoop.json",
+            StatusCode::UNPROCESSABLE_ENTITY,
+        ),
+    ] {
+        let req = test::TestRequest::get().uri(uri).to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), status, "{}", uri);
+    }
+}
@@ -0,0 +1,305 @@
+use crate::tests::{SERVER_CONTEXT, WRITE_KEY, failing, init_app, seeded};
+use actix_web::http::{StatusCode, header};
+use actix_web::test;
+use serde_json::{Value, json};
+
+/// Build the body of a create or update request
+fn version_body(semver: &str) -> Value {
+    json!({
+        "name": format!("Version {}", semver),
+        "semver": semver,
+        "platforms": [{
+            "name": "Windows",
+            "arch": "x64",
+            "downloadUrl": format!("https://codedead.com/downloads/{}.exe", semver)
+        }]
+    })
+}
+
+#[actix_web::test]
+async fn find_version_by_id_returns_the_version() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/version/v2")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["id"], "v2");
+    assert_eq!(body["semver"], "1.1.0");
+    assert_eq!(body["platforms"][0]["arch"], "x64");
+}
+
+#[actix_web::test]
+async fn find_version_by_id_answers_conditional_requests_with_not_modified() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/version/v1")
+        .to_request();
+    let etag = test::call_service(&app, req)
+        .await
+        .headers()
+        .get(header::ETAG)
+        .unwrap()
+        .clone();
+    let req = test::TestRequest::get()
+        .uri("/api/v1/version/v1")
+        .insert_header((header::IF_NONE_MATCH, etag))
+        .insert_header((header::IF_MODIFIED_SINCE, "Mon, 01 Jan 2001 00:00:00 GMT"))
+        .to_request();
+    let not_modified = test::call_service(&app, req).await;
+    let req = test::TestRequest::get()
+        .uri("/api/v1/version/v1")
+        .insert_header((header::IF_NONE_MATCH, "\"stale\""))
+        .to_request();
+    let stale = test::call_service(&app, req).await;
+    let req = test::TestRequest::get()
+        .uri("/api/v1/version/")
+        .insert_header((header::IF_NONE_MATCH, "*"))
+        .to_request();
+    let any = test::call_service(&app, req).await;
+
+    assert_eq!(not_modified.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(stale.status(), StatusCode::OK);
+    assert_eq!(any.status(), StatusCode::NOT_MODIFIED);
+}
+
+#[actix_web::test]
+async fn find_version_by_id_of_unknown_version_is_not_found() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/version/unknown")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn find_version_by_id_reports_database_errors() {
+    let app = init_app!(failing());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/version/v1")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["detail"], "Error fetching version with ID v1");
+}
+
+#[actix_web::test]
+async fn find_all_versions_paginates_by_id() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/version/?limit=5")
+        .to_request();
+    let first = test::call_service(&app, req).await;
+
+    assert_eq!(first.status(), StatusCode::OK);
+    assert_eq!(
+        first.headers().get(header::LINK).unwrap().to_str().unwrap(),
+        format!(
+            "<{0}/api/v1/version/?limit=2>; rel=first, <{0}/api/v1/version/?page=v2&limit=2>; rel=next",
+            SERVER_CONTEXT
+        )
+    );
+    let body: Value = test::read_body_json(first).await;
+    assert_eq!(body.as_array().unwrap().len(), 2);
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/version/?page=v2")
+        .to_request();
+    let last = test::call_service(&app, req).await;
+
+    assert_eq!(last.status(), StatusCode::OK);
+    assert_eq!(
+        last.headers().get(header::LINK).unwrap().to_str().unwrap(),
+        format!("<{}/api/v1/version/?limit=2>; rel=first", SERVER_CONTEXT)
+    );
+    let body: Value = test::read_body_json(last).await;
+    assert_eq!(body[0]["id"], "v3");
+}
+
+#[actix_web::test]
+async fn find_all_versions_past_the_last_page_is_not_found() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/version/?page=v3")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn find_all_versions_reports_database_errors() {
+    let app = init_app!(failing());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/version/")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[actix_web::test]
+async fn create_version_requires_credentials() {
+    let app = init_app!(seeded());
+
+    let mut body = version_body("3.0.0");
+    body["id"] = json!("v4");
+    let req = test::TestRequest::post()
+        .uri("/api/v1/version/")
+        .set_json(&body)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[actix_web::test]
+async fn create_version_stores_the_version() {
+    let app = init_app!(seeded());
+
+    let mut body = version_body("3.0.0");
+    body["id"] = json!("v4");
+    let req = test::TestRequest::post()
+        .uri("/api/v1/version/")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .set_json(&body)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    assert_eq!(
+        resp.headers()
+            .get(header::LOCATION)
+            .unwrap()
+            .to_str()
+            .unwrap(),
+        format!("{}/api/v1/version/v4", SERVER_CONTEXT)
+    );
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["semver"], "3.0.0");
+}
+
+#[actix_web::test]
+async fn create_version_rejects_duplicate_semantic_versions() {
+    let app = init_app!(seeded());
+
+    let mut body = version_body("1.0.0");
+    body["id"] = json!("v4");
+    let req = test::TestRequest::post()
+        .uri("/api/v1/version/")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .set_json(&body)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+}
+
+#[actix_web::test]
+async fn create_version_reports_database_errors() {
+    let app = init_app!(failing());
+
+    let mut body = version_body("3.0.0");
+    body["id"] = json!("v4");
+    let req = test::TestRequest::post()
+        .uri("/api/v1/version/")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .set_json(&body)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[actix_web::test]
+async fn update_version_replaces_the_version() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::put()
+        .uri("/api/v1/version/v3")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .set_json(version_body("2.0.1"))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["semver"], "2.0.1");
+    assert_eq!(body["createdAt"], "2026-01-01T00:00:00+00:00");
+}
+
+#[actix_web::test]
+async fn update_version_rejects_invalid_data_and_unknown_versions() {
+    let app = init_app!(seeded());
+
+    let mut invalid = version_body("2.0.1");
+    invalid["platforms"] = json!([]);
+    let req = test::TestRequest::put()
+        .uri("/api/v1/version/v3")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .set_json(&invalid)
+        .to_request();
+    let bad_request = test::call_service(&app, req).await;
+    let req = test::TestRequest::put()
+        .uri("/api/v1/version/unknown")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .set_json(version_body("9.0.0"))
+        .to_request();
+    let not_found = test::call_service(&app, req).await;
+
+    assert_eq!(bad_request.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(not_found.status(), StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn delete_version_invalidates_the_cached_version() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/version/v1")
+        .to_request();
+    let cached = test::call_service(&app, req).await;
+    let req = test::TestRequest::delete()
+        .uri("/api/v1/version/v1")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .to_request();
+    test::call_service(&app, req).await;
+    let req = test::TestRequest::get()
+        .uri("/api/v1/version/v1")
+        .to_request();
+    let missing = test::call_service(&app, req).await;
+
+    assert_eq!(cached.status(), StatusCode::OK);
+    assert_eq!(missing.status(), StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn delete_version_removes_the_version() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::delete()
+        .uri("/api/v1/version/v1")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .to_request();
+    let deleted = test::call_service(&app, req).await;
+    let req = test::TestRequest::get()
+        .uri("/api/v1/version/v1")
+        .to_request();
+    let missing = test::call_service(&app, req).await;
+
+    assert_eq!(deleted.status(), StatusCode::NO_CONTENT);
+    assert_eq!(missing.status(), StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,382 @@
+use crate::services::webhook::webhook_service::WebhookService;
+use crate::tests::{READ_ONLY_KEY, WEBHOOK_MAX_ATTEMPTS, WRITE_KEY, init_app, seeded};
+use actix_web::http::StatusCode;
+use actix_web::{App, HttpRequest, HttpResponse, HttpServer, test, web};
+use serde_json::{Value, json};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// A request that was received by the stand-in subscriber
+#[derive(Clone)]
+struct Received {
+    path: String,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl Received {
+    /// Look up a header by its case-insensitive name
+    fn header(&self, name: &str) -> String {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.clone())
+            .unwrap_or_default()
+    }
+}
+
+/// A local HTTP server that records every request and responds with queued status codes
+struct StandIn {
+    url: String,
+    received: Arc<Mutex<Vec<Received>>>,
+}
+
+impl StandIn {
+    /// Start a stand-in that responds with the provided status codes in order and 200 afterwards
+    fn start(statuses: &[u16]) -> StandIn {
+        let received = Arc::new(Mutex::new(vec![]));
+        let statuses = Arc::new(Mutex::new(
+            statuses.iter().copied().collect::<VecDeque<_>>(),
+        ));
+
+        let (log, queue) = (received.clone(), statuses.clone());
+        let server = HttpServer::new(move || {
+            let (log, queue) = (log.clone(), queue.clone());
+            App::new().default_service(web::to(move |req: HttpRequest, body: String| {
+                let (log, queue) = (log.clone(), queue.clone());
+                async move {
+                    log.lock().unwrap().push(Received {
+                        path: req.path().to_string(),
+                        headers: req
+                            .headers()
+                            .iter()
+                            .map(|(n, v)| (n.to_string(), v.to_str().unwrap().to_string()))
+                            .collect(),
+                        body,
+                    });
+                    let status = queue.lock().unwrap().pop_front().unwrap_or(200);
+                    HttpResponse::build(StatusCode::from_u16(status).unwrap()).finish()
+                }
+            }))
+        })
+        .workers(1)
+        .disable_signals()
+        .shutdown_timeout(0)
+        .bind(("127.0.0.1", 0))
+        .unwrap();
+
+        let url = format!("http://{}", server.addrs()[0]);
+        actix_web::rt::spawn(server.run());
+
+        StandIn { url, received }
+    }
+
+    fn received(&self) -> Vec<Received> {
+        self.received.lock().unwrap().clone()
+    }
+}
+
+/// Poll a condition until it holds, failing if it does not hold in time
+async fn eventually<F: AsyncFn() -> bool>(condition: F) {
+    for _ in 0..100 {
+        if condition().await {
+            return;
+        }
+        actix_web::rt::time::sleep(Duration::from_millis(20)).await;
+    }
+    panic!("the condition did not hold in time");
+}
+
+macro_rules! register {
+    ($app:expr, $body:expr) => {{
+        let req = test::TestRequest::post()
+            .uri("/api/v1/webhooks/")
+            .insert_header(("X-Api-Key", WRITE_KEY))
+            .set_json($body)
+            .to_request();
+        let resp = test::call_service($app, req).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let body: Value = test::read_body_json(resp).await;
+        body
+    }};
+}
+
+macro_rules! publish {
+    ($app:expr, $semver:expr) => {{
+        let req = test::TestRequest::post()
+            .uri("/api/v1/applications/advanced-passgen/platforms/windows/architectures/x64/releases")
+            .insert_header(("X-Api-Key", WRITE_KEY))
+            .set_json(json!({
+                "semver": $semver,
+                "downloadUrl": format!("https://codedead.com/downloads/{}.exe", $semver)
+            }))
+            .to_request();
+        assert_eq!(
+            test::call_service($app, req).await.status(),
+            StatusCode::CREATED
+        );
+    }};
+}
+
+macro_rules! deliveries {
+    ($app:expr, $id:expr) => {{
+        let req = test::TestRequest::get()
+            .uri(&format!("/api/v1/webhooks/{}/deliveries", $id))
+            .insert_header(("X-Api-Key", WRITE_KEY))
+            .to_request();
+        let body: Value = test::call_and_read_body_json($app, req).await;
+        body.as_array().unwrap().clone()
+    }};
+}
+
+#[actix_web::test]
+async fn webhooks_require_the_manage_scope() {
+    let app = init_app!(seeded());
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/webhooks/")
+        .to_request();
+    let anonymous = test::call_service(&app, req).await;
+    let req = test::TestRequest::post()
+        .uri("/api/v1/webhooks/")
+        .insert_header(("X-Api-Key", READ_ONLY_KEY))
+        .set_json(json!({ "url": "https://codedead.com/hook" }))
+        .to_request();
+    let forbidden = test::call_service(&app, req).await;
+
+    assert_eq!(anonymous.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(forbidden.status(), StatusCode::FORBIDDEN);
+}
+
+#[actix_web::test]
+async fn register_webhook_only_returns_the_secret_once() {
+    let app = init_app!(seeded());
+
+    let created = register!(
+        &app,
+        json!({ "url": "https://codedead.com/hook", "channel": "beta" })
+    );
+    let id = created["id"].as_str().unwrap();
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/v1/webhooks/{}", id))
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .to_request();
+    let found: Value = test::call_and_read_body_json(&app, req).await;
+    let req = test::TestRequest::get()
+        .uri("/api/v1/webhooks/")
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .to_request();
+    let all: Value = test::call_and_read_body_json(&app, req).await;
+
+    assert_eq!(created["secret"].as_str().unwrap().len(), 64);
+    assert_eq!(found["channel"], "beta");
+    assert!(found.get("secret").is_none());
+    assert_eq!(all[0]["id"], id);
+}
+
+#[actix_web::test]
+async fn register_webhook_rejects_relative_urls_and_short_secrets() {
+    let app = init_app!(seeded());
+
+    for body in [
+        json!({ "url": "/hook" }),
+        json!({ "url": "https://codedead.com/hook", "secret": "short" }),
+    ] {
+        let req = test::TestRequest::post()
+            .uri("/api/v1/webhooks/")
+            .insert_header(("X-Api-Key", WRITE_KEY))
+            .set_json(body)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+}
+
+#[actix_web::test]
+async fn delete_webhook_removes_the_webhook_and_its_log() {
+    let app = init_app!(seeded());
+
+    let created = register!(&app, json!({ "url": "https://codedead.com/hook" }));
+    let uri = format!("/api/v1/webhooks/{}", created["id"].as_str().unwrap());
+    let req = test::TestRequest::delete()
+        .uri(&uri)
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .to_request();
+    let deleted = test::call_service(&app, req).await;
+    let req = test::TestRequest::get()
+        .uri(&format!("{}/deliveries", uri))
+        .insert_header(("X-Api-Key", WRITE_KEY))
+        .to_request();
+    let missing = test::call_service(&app, req).await;
+
+    assert_eq!(deleted.status(), StatusCode::NO_CONTENT);
+    assert_eq!(missing.status(), StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn published_releases_are_delivered_signed_to_matching_webhooks() {
+    let app = init_app!(seeded());
+    let stand_in = StandIn::start(&[]);
+    let secret = "a-secret-of-sufficient-length";
+
+    let any = register!(
+        &app,
+        json!({
+            "url": format!("{}/any", stand_in.url),
+            "secret": secret,
+            "applicationId": "advanced-passgen"
+        })
+    );
+    let other = register!(
+        &app,
+        json!({
+            "url": format!("{}/other", stand_in.url),
+            "applicationId": "deadhash"
+        })
+    );
+    register!(
+        &app,
+        json!({
+            "url": format!("{}/stable", stand_in.url),
+            "channel": "stable"
+        })
+    );
+    publish!(&app, "1.2.0");
+    publish!(&app, "2.1.0-beta.1");
+    eventually(async || stand_in.received().len() == 3).await;
+    let any_id = any["id"].as_str().unwrap();
+    eventually(async || {
+        deliveries!(&app, any_id)
+            .iter()
+            .all(|d| d["status"] == "delivered")
+    })
+    .await;
+
+    let received = stand_in.received();
+    let mut paths: Vec<&str> = received.iter().map(|r| r.path.as_str()).collect();
+    paths.sort();
+    assert_eq!(paths, vec!["/any", "/any", "/stable"]);
+
+    let first = received
+        .iter()
+        .find(|r| r.path == "/any" && r.body.contains("1.2.0"))
+        .unwrap();
+    let payload: Value = serde_json::from_str(&first.body).unwrap();
+    assert_eq!(first.header("X-CodeDead-Event"), "release-published");
+    assert_eq!(
+        first.header("X-CodeDead-Signature"),
+        WebhookService::sign(secret, &first.header("X-CodeDead-Timestamp"), &first.body)
+    );
+    assert_eq!(payload["applicationId"], "advanced-passgen");
+    assert_eq!(payload["platform"], "Windows");
+    assert_eq!(payload["channel"], "stable");
+    assert_eq!(payload["release"]["semver"], "1.2.0");
+    assert_eq!(payload["release"]["channel"], "stable");
+
+    let log = deliveries!(&app, any_id);
+    assert_eq!(log.len(), 2);
+    assert_eq!(log[1]["id"], first.header("X-CodeDead-Delivery"));
+    assert_eq!(log[1]["attempts"], 1);
+    assert_eq!(log[1]["lastStatusCode"], 200);
+    assert!(log[1]["nextAttemptAt"].is_null());
+    assert!(deliveries!(&app, other["id"].as_str().unwrap()).is_empty());
+}
+
+#[actix_web::test]
+async fn failed_deliveries_are_retried_until_they_succeed_or_run_out_of_attempts() {
+    let app = init_app!(seeded());
+    let recovering = StandIn::start(&[500, 503]);
+    let down = StandIn::start(&[500; WEBHOOK_MAX_ATTEMPTS as usize]);
+
+    let recovers = register!(&app, json!({ "url": recovering.url }));
+    let fails = register!(&app, json!({ "url": down.url }));
+    publish!(&app, "1.2.0");
+    let (recovers, fails) = (
+        recovers["id"].as_str().unwrap(),
+        fails["id"].as_str().unwrap(),
+    );
+    eventually(async || deliveries!(&app, recovers)[0]["status"] == "delivered").await;
+    eventually(async || deliveries!(&app, fails)[0]["status"] == "failed").await;
+
+    let recovered = &deliveries!(&app, recovers)[0];
+    let failed = &deliveries!(&app, fails)[0];
+    let attempts = recovering.received();
+    assert_eq!(recovered["attempts"], 3);
+    assert_eq!(attempts.len(), 3);
+    assert!(
+        attempts
+            .iter()
+            .all(|r| r.header("X-CodeDead-Delivery") == recovered["id"])
+    );
+    assert_eq!(failed["attempts"], WEBHOOK_MAX_ATTEMPTS);
+    assert_eq!(failed["lastStatusCode"], 500);
+    assert_eq!(down.received().len(), WEBHOOK_MAX_ATTEMPTS as usize);
+}
+
+#[actix_web::test]
+async fn delivery_limits_below_one_return_a_single_delivery() {
+    let app = init_app!(seeded());
+    let stand_in = StandIn::start(&[]);
+
+    let webhook = register!(&app, json!({ "url": stand_in.url }));
+    publish!(&app, "1.2.0");
+    publish!(&app, "1.3.0");
+    let id = webhook["id"].as_str().unwrap();
+    eventually(async || deliveries!(&app, id).len() == 2).await;
+
+    for limit in ["0", "-5"] {
+        let req = test::TestRequest::get()
+            .uri(&format!(
+                "/api/v1/webhooks/{}/deliveries?limit={}",
+                id, limit
+            ))
+            .insert_header(("X-Api-Key", WRITE_KEY))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body.as_array().unwrap().len(), 1);
+    }
+}
+
+#[actix_web::test]
+async fn releases_added_by_an_application_update_are_delivered() {
+    let app = init_app!(seeded());
+    let stand_in = StandIn::start(&[]);
+    let webhook = register!(
+        &app,
+        json!({ "url": stand_in.url, "applicationId": "deadhash" })
+    );
+    let platforms = json!({
+        "platforms": [{
+            "platformName": "Windows",
+            "architectures": [{
+                "name": "x64",
+                "url": "https://codedead.com/deadhash",
+                "releases": [
+                    { "semver": "1.0.0", "downloadUrl": "https://codedead.com/downloads/1.0.0.exe" },
+                    { "semver": "1.1.0", "downloadUrl": "https://codedead.com/downloads/1.1.0.exe" }
+                ]
+            }]
+        }]
+    });
+
+    for _ in 0..2 {
+        let req = test::TestRequest::patch()
+            .uri("/api/v1/applications/deadhash")
+            .insert_header(("X-Api-Key", WRITE_KEY))
+            .set_json(&platforms)
+            .to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+    }
+    let id = webhook["id"].as_str().unwrap();
+    eventually(async || stand_in.received().len() == 1).await;
+
+    let body: Value = serde_json::from_str(&stand_in.received()[0].body).unwrap();
+    assert_eq!(body["platform"], "Windows");
+    assert_eq!(body["architecture"], "x64");
+    assert_eq!(body["release"]["semver"], "1.1.0");
+    assert_eq!(deliveries!(&app, id).len(), 1);
+}
@@ -1,2 +1,13 @@
+pub(crate) mod appcast;
+pub(crate) mod conditional;
 pub(crate) mod controller;
 pub(crate) mod dto;
+pub(crate) mod event_stream;
+pub(crate) mod feed;
+pub(crate) mod homebrew;
+pub(crate) mod middleware;
+pub(crate) mod package;
+pub(crate) mod scoop;
+pub(crate) mod winget;
+pub(crate) mod xml;
+pub(crate) mod yaml;
@@ -0,0 +1,207 @@
+use crate::repository::application::model::application::{
+    Application, ApplicationPlatform, Release,
+};
+use crate::repository::application::model::release_channel::ReleaseChannel;
+use crate::web::feed::{DOWNLOAD_TYPE, parse_time};
+use crate::web::xml::escape;
+use std::fmt::Write;
+
+/// The namespace of the Sparkle extensions to RSS, which WinSparkle shares
+const SPARKLE_NAMESPACE: &str = "http://www.andymatuschak.org/xml-namespaces/sparkle";
+
+/// A Release of a single architecture in an appcast
+struct AppcastItem<'a> {
+    release: &'a Release,
+    /// The value of the `sparkle:os` attribute, if the platform is known to Sparkle or WinSparkle
+    os: Option<String>,
+}
+
+/// A Sparkle or WinSparkle appcast of the Releases of an Application on one platform
+pub struct Appcast<'a> {
+    title: String,
+    description: String,
+    self_url: String,
+    items: Vec<AppcastItem<'a>>,
+}
+
+/// Map a platform and architecture onto the `sparkle:os` attribute of an enclosure
+///
+/// WinSparkle selects the enclosure of its own architecture by the `windows-x86`, `windows-x64`
+/// and `windows-arm64` values. Sparkle only distinguishes `macos`.
+///
+/// # Arguments
+///
+/// * `platform` - The name of the platform
+/// * `architecture` - The name of the architecture
+///
+/// # Returns
+///
+/// An Option containing the value of the attribute, or None for other platforms
+pub fn sparkle_os(platform: &str, architecture: &str) -> Option<String> {
+    match platform.to_ascii_lowercase().as_str() {
+        "windows" | "win" => Some(match architecture.to_ascii_lowercase().as_str() {
+            "x64" | "amd64" | "x86_64" => String::from("windows-x64"),
+            "x86" | "i386" | "i686" | "win32" => String::from("windows-x86"),
+            "arm64" | "aarch64" => String::from("windows-arm64"),
+            _ => String::from("windows"),
+        }),
+        "macos" | "mac" | "osx" | "darwin" => Some(String::from("macos")),
+        _ => None,
+    }
+}
+
+impl<'a> Appcast<'a> {
+    /// Build an appcast from the Releases of a platform of an Application, newest first
+    ///
+    /// # Arguments
+    ///
+    /// * `application` - The Application
+    /// * `platform` - The platform of the Application
+    /// * `architecture` - The name of the only architecture to include, or None for every architecture
+    /// * `channel` - The release channel to restrict the Releases to
+    /// * `self_url` - The URL of the appcast
+    ///
+    /// # Returns
+    ///
+    /// A new Appcast
+    pub fn new(
+        application: &'a Application,
+        platform: &'a ApplicationPlatform,
+        architecture: Option<&str>,
+        channel: &ReleaseChannel,
+        self_url: &str,
+    ) -> Self {
+        let mut items: Vec<AppcastItem> = platform
+            .architectures
+            .iter()
+            .flatten()
+            .filter(|a| architecture.is_none_or(|n| a.name.eq_ignore_ascii_case(n)))
+            .flat_map(|a| {
+                let os = sparkle_os(&platform.platform_name, &a.name);
+                a.releases
+                    .iter()
+                    .flatten()
+                    .filter(|r| channel.includes(&r.effective_channel()))
+                    .map(move |release| AppcastItem {
+                        release,
+                        os: os.clone(),
+                    })
+            })
+            .collect();
+        items.sort_by(|a, b| b.release.semver.cmp(&a.release.semver));
+
+        Appcast {
+            title: format!("{} for {}", application.name, platform.platform_name),
+            description: application
+                .description
+                .clone()
+                .unwrap_or_else(|| format!("The releases of {}", application.name)),
+            self_url: self_url.to_string(),
+            items,
+        }
+    }
+
+    /// Render the appcast as an RSS 2.0 document with Sparkle extensions
+    ///
+    /// # Returns
+    ///
+    /// The XML document
+    pub fn render(&self) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+        let _ = writeln!(
+            xml,
+            "<rss version=\"2.0\" xmlns:sparkle=\"{}\">",
+            SPARKLE_NAMESPACE
+        );
+        xml.push_str("  <channel>\n");
+        let _ = writeln!(xml, "    <title>{}</title>", escape(&self.title));
+        let _ = writeln!(xml, "    <link>{}</link>", escape(&self.self_url));
+        let _ = writeln!(
+            xml,
+            "    <description>{}</description>",
+            escape(&self.description)
+        );
+
+        for item in &self.items {
+            let release = item.release;
+            let version = release.semver.to_string();
+            let short_version = format!(
+                "{}.{}.{}",
+                release.semver.major, release.semver.minor, release.semver.patch
+            );
+
+            xml.push_str("    <item>\n");
+            let _ = writeln!(
+                xml,
+                "      <title>{}</title>",
+                escape(release.name.as_ref().unwrap_or(&version))
+            );
+            if let Some(published) = release.release_date.as_deref().and_then(parse_time) {
+                let _ = writeln!(xml, "      <pubDate>{}</pubDate>", published.to_rfc2822());
+            }
+            let _ = writeln!(
+                xml,
+                "      <sparkle:version>{}</sparkle:version>",
+                escape(&version)
+            );
+            let _ = writeln!(
+                xml,
+                "      <sparkle:shortVersionString>{}</sparkle:shortVersionString>",
+                short_version
+            );
+            if let Some(info_url) = &release.info_url {
+                let _ = writeln!(
+                    xml,
+                    "      <sparkle:releaseNotesLink>{}</sparkle:releaseNotesLink>",
+                    escape(info_url)
+                );
+            }
+            if let Some(description) = &release.description {
+                let _ = writeln!(
+                    xml,
+                    "      <description>{}</description>",
+                    escape(description)
+                );
+            }
+
+            // Older WinSparkle releases only read the versions from the enclosure
+            let _ = write!(
+                xml,
+                "      <enclosure url=\"{}\" sparkle:version=\"{}\" sparkle:shortVersionString=\"{}\" type=\"{}\"",
+                escape(&release.download_url),
+                escape(&version),
+                short_version,
+                DOWNLOAD_TYPE
+            );
+            if let Some(os) = &item.os {
+                let _ = write!(xml, " sparkle:os=\"{}\"", os);
+            }
+            if let Some(size) = release.size {
+                let _ = write!(xml, " length=\"{}\"", size);
+            }
+            if let Some(signature) = &release.signature {
+                let _ = write!(xml, " sparkle:edSignature=\"{}\"", escape(signature));
+            }
+            xml.push_str("/>\n");
+            xml.push_str("    </item>\n");
+        }
+
+        xml.push_str("  </channel>\n</rss>\n");
+        xml
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sparkle_os_maps_windows_architectures() {
+        assert_eq!(sparkle_os("Windows", "AMD64").unwrap(), "windows-x64");
+        assert_eq!(sparkle_os("windows", "i686").unwrap(), "windows-x86");
+        assert_eq!(sparkle_os("Windows", "aarch64").unwrap(), "windows-arm64");
+        assert_eq!(sparkle_os("Windows", "itanium").unwrap(), "windows");
+        assert_eq!(sparkle_os("macOS", "universal").unwrap(), "macos");
+        assert_eq!(sparkle_os("Linux", "x64"), None);
+    }
+}
@@ -0,0 +1,137 @@
+use actix_web::http::header::{
+    ETag, EntityTag, Header, HttpDate, IfModifiedSince, IfNoneMatch, LastModified,
+};
+use actix_web::{HttpRequest, HttpResponse, HttpResponseBuilder};
+use chrono::DateTime;
+use sha2::{Digest, Sha256};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The validators of a representation, used to answer conditional GET requests
+///
+/// The entity tag is derived from the ID and `updatedAt` timestamp of every entity in the
+/// representation together with the request URI and the API version, so it can be computed
+/// without serializing the body.
+pub struct Validators {
+    etag: EntityTag,
+    last_modified: Option<HttpDate>,
+}
+
+impl Validators {
+    /// Initialize the Validators of a representation
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - The HTTP request, whose URI is part of the entity tag
+    /// * `entities` - The ID and `updatedAt` timestamp of every entity in the representation
+    ///
+    /// # Returns
+    ///
+    /// The Validators with a strong entity tag and the latest modification date, if any timestamp could be parsed
+    pub fn new<'a>(
+        req: &HttpRequest,
+        entities: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Self {
+        let mut hasher = Sha256::new();
+        hasher.update(env!("CARGO_PKG_VERSION").as_bytes());
+        hasher.update([0]);
+        hasher.update(req.uri().to_string().as_bytes());
+
+        let mut last_modified: Option<SystemTime> = None;
+        for (id, updated_at) in entities {
+            hasher.update([0]);
+            hasher.update(id.as_bytes());
+            hasher.update([0]);
+            hasher.update(updated_at.as_bytes());
+
+            if let Ok(updated_at) = DateTime::parse_from_rfc3339(updated_at) {
+                // HTTP dates have a precision of one second
+                let updated_at =
+                    UNIX_EPOCH + Duration::from_secs(updated_at.timestamp().max(0) as u64);
+                last_modified = Some(last_modified.map_or(updated_at, |l| l.max(updated_at)));
+            }
+        }
+
+        let digest = hasher.finalize();
+        Validators {
+            etag: EntityTag::new_strong(hex::encode(&digest[..16])),
+            last_modified: last_modified.map(HttpDate::from),
+        }
+    }
+
+    /// Initialize the Validators of a collection of entities
+    ///
+    /// Deleting an entity, or an entity dropping off a page, does not move the latest `updatedAt`
+    /// timestamp forward, so collections are validated by their entity tag alone.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - The HTTP request, whose URI is part of the entity tag
+    /// * `entities` - The ID and `updatedAt` timestamp of every entity in the collection
+    ///
+    /// # Returns
+    ///
+    /// The Validators with a strong entity tag and without a modification date
+    pub fn for_collection<'a>(
+        req: &HttpRequest,
+        entities: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Self {
+        Validators {
+            last_modified: None,
+            ..Self::new(req, entities)
+        }
+    }
+
+    /// Check whether the client already has the current representation
+    ///
+    /// `If-None-Match` takes precedence over `If-Modified-Since`, as described by RFC 9110.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - The HTTP request
+    ///
+    /// # Returns
+    ///
+    /// True if the request can be answered with 304 Not Modified, otherwise false
+    pub fn is_not_modified(&self, req: &HttpRequest) -> bool {
+        if req.headers().contains_key(IfNoneMatch::name()) {
+            return match IfNoneMatch::parse(req) {
+                Ok(IfNoneMatch::Any) => true,
+                Ok(IfNoneMatch::Items(tags)) => tags.iter().any(|t| t.weak_eq(&self.etag)),
+                Err(_) => false,
+            };
+        }
+
+        match (IfModifiedSince::parse(req), self.last_modified) {
+            (Ok(IfModifiedSince(since)), Some(last_modified)) => {
+                SystemTime::from(last_modified) <= SystemTime::from(since)
+            }
+            _ => false,
+        }
+    }
+
+    /// Add the `ETag` and `Last-Modified` headers to a response
+    ///
+    /// # Arguments
+    ///
+    /// * `builder` - The builder of the response
+    ///
+    /// # Returns
+    ///
+    /// The builder with the validator headers
+    pub fn apply<'a>(&self, builder: &'a mut HttpResponseBuilder) -> &'a mut HttpResponseBuilder {
+        builder.insert_header(ETag(self.etag.clone()));
+        if let Some(last_modified) = self.last_modified {
+            builder.insert_header(LastModified(last_modified));
+        }
+        builder
+    }
+
+    /// Build the 304 Not Modified response for a client that has the current representation
+    ///
+    /// # Returns
+    ///
+    /// An HTTP 304 Not Modified response without a body
+    pub fn not_modified(&self) -> HttpResponse {
+        self.apply(&mut HttpResponse::NotModified()).finish()
+    }
+}
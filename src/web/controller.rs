@@ -1,7 +1,14 @@
 pub(crate) mod actuator_controller;
+pub(crate) mod appcast_controller;
 pub(crate) mod application_controller;
+pub(crate) mod event_controller;
+pub(crate) mod feed_controller;
+pub(crate) mod package_controller;
 pub(crate) mod version_controller;
+pub(crate) mod webhook_controller;
 
+use crate::web::middleware::{problem_details, request_metrics};
+use actix_web::middleware::from_fn;
 use actix_web::web;
 
 pub(crate) struct Controller {}
@@ -17,17 +24,64 @@ impl Controller {
     pub fn configure_routes(cfg: &mut web::ServiceConfig) {
         cfg.service(
             web::scope("/api/v1")
+                .wrap(from_fn(problem_details::problem_instance))
+                .wrap(from_fn(request_metrics::record_request))
+                .app_data(
+                    web::QueryConfig::default().error_handler(problem_details::query_error_handler),
+                )
+                .app_data(
+                    web::JsonConfig::default().error_handler(problem_details::json_error_handler),
+                )
                 .service(
                     web::scope("/applications")
+                        .service(application_controller::check_for_update)
+                        .service(application_controller::download)
+                        .service(application_controller::find_latest_release)
+                        .service(application_controller::find_latest_platform_release)
+                        .service(application_controller::find_latest_architecture_release)
+                        .service(event_controller::application_events)
+                        .service(feed_controller::application_atom_feed)
+                        .service(feed_controller::application_rss_feed)
+                        .service(appcast_controller::find_appcast)
+                        .service(package_controller::find_winget_manifests)
+                        .service(package_controller::find_homebrew_cask)
+                        .service(package_controller::find_scoop_manifest)
                         .service(application_controller::find_by_id)
-                        .service(application_controller::find_all),
+                        .service(application_controller::find_all)
+                        .service(application_controller::create_application)
+                        .service(application_controller::replace_application)
+                        .service(application_controller::patch_application)
+                        .service(application_controller::delete_application)
+                        .service(application_controller::create_release),
                 )
+                .service(event_controller::events)
+                .service(feed_controller::atom_feed)
+                .service(feed_controller::rss_feed)
                 .service(
                     web::scope("/version")
                         .service(version_controller::find_version_by_id)
-                        .service(version_controller::find_all_versions),
+                        .service(version_controller::find_all_versions)
+                        .service(version_controller::create_version)
+                        .service(version_controller::update_version)
+                        .service(version_controller::delete_version),
                 )
-                .service(web::scope("/actuators").service(actuator_controller::health)),
-        );
+                .service(
+                    web::scope("/webhooks")
+                        .service(webhook_controller::find_webhook_deliveries)
+                        .service(webhook_controller::find_webhook_by_id)
+                        .service(webhook_controller::find_all_webhooks)
+                        .service(webhook_controller::create_webhook)
+                        .service(webhook_controller::delete_webhook),
+                )
+                .service(
+                    web::scope("/actuators")
+                        .service(actuator_controller::health)
+                        .service(actuator_controller::liveness)
+                        .service(actuator_controller::readiness)
+                        .service(actuator_controller::metrics)
+                        .service(actuator_controller::info),
+                ),
+        )
+        .default_service(web::to(problem_details::not_found));
     }
 }
@@ -1,4 +1,9 @@
-use actix_web::{HttpResponse, get};
+use crate::config::server_config::ServerConfig;
+use crate::web::dto::health::health_dto::{HealthDto, HealthStatusDto};
+use crate::web::dto::info::info_dto::{BuildInfoDto, ConfigInfoDto, InfoDto, RuntimeInfoDto};
+use actix_web::http::header;
+use actix_web::{HttpResponse, get, web};
+use log::warn;
 
 /// # Summary
 ///
@@ -19,3 +24,116 @@ use actix_web::{HttpResponse, get};
 pub async fn health() -> HttpResponse {
     HttpResponse::Ok().finish()
 }
+
+/// # Summary
+///
+/// Check whether the process is alive. This does not depend on the database, so a database
+/// outage does not cause the process to be restarted.
+///
+/// # Returns
+///
+/// * `HttpResponse` - An HTTP response with status UP.
+#[utoipa::path(
+    get,
+    path = "/api/v1/actuators/health/liveness",
+    tag = "Actuators",
+    responses(
+            (status = 200, description = "HTTP OK", body = HealthDto),
+    ),
+)]
+#[get("/health/liveness")]
+pub async fn liveness() -> HttpResponse {
+    HttpResponse::Ok()
+        .append_header((header::CACHE_CONTROL, "no-store"))
+        .json(HealthDto::up())
+}
+
+/// # Summary
+///
+/// Check whether the application is ready to serve requests by pinging the database and
+/// verifying that the application and version collections exist.
+///
+/// # Returns
+///
+/// * `HttpResponse` - An HTTP response with the status and latency of every component.
+///
+/// # Errors
+///
+/// * Returns an HTTP 503 Service Unavailable if any component is down.
+#[utoipa::path(
+    get,
+    path = "/api/v1/actuators/health/readiness",
+    tag = "Actuators",
+    responses(
+            (status = 200, description = "HTTP OK", body = HealthDto),
+            (status = 503, description = "HTTP Service Unavailable", body = HealthDto),
+    ),
+)]
+#[get("/health/readiness")]
+pub async fn readiness(pool: web::Data<ServerConfig>) -> HttpResponse {
+    let dto = HealthDto::from(pool.services.health_service.readiness().await);
+
+    let mut response = if dto.status == HealthStatusDto::Up {
+        HttpResponse::Ok()
+    } else {
+        warn!("Readiness check failed");
+        HttpResponse::ServiceUnavailable()
+    };
+
+    response
+        .append_header((header::CACHE_CONTROL, "no-store"))
+        .json(dto)
+}
+
+/// # Summary
+///
+/// Expose the request, MongoDB and process metrics of the application.
+///
+/// # Returns
+///
+/// * `HttpResponse` - An HTTP response with the metrics in the Prometheus text format.
+#[utoipa::path(
+    get,
+    path = "/api/v1/actuators/metrics",
+    tag = "Actuators",
+    responses(
+            (status = 200, description = "HTTP OK", body = String, content_type = "text/plain; version=0.0.4"),
+    ),
+)]
+#[get("/metrics")]
+pub async fn metrics(pool: web::Data<ServerConfig>) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type(prometheus::TEXT_FORMAT)
+        .append_header((header::CACHE_CONTROL, "no-store"))
+        .body(pool.services.metrics_service.render())
+}
+
+/// # Summary
+///
+/// Describe the running build, its uptime and its effective configuration.
+///
+/// # Returns
+///
+/// * `HttpResponse` - An HTTP response with the build metadata, uptime and secret-free configuration.
+#[utoipa::path(
+    get,
+    path = "/api/v1/actuators/info",
+    tag = "Actuators",
+    responses(
+            (status = 200, description = "HTTP OK", body = InfoDto),
+    ),
+)]
+#[get("/info")]
+pub async fn info(pool: web::Data<ServerConfig>) -> HttpResponse {
+    let info_service = &pool.services.info_service;
+
+    let dto = InfoDto {
+        build: BuildInfoDto::from(info_service.build_info()),
+        runtime: RuntimeInfoDto::new(info_service.started_at(), info_service.uptime()),
+        config: ConfigInfoDto::from(pool.get_ref()),
+    };
+
+    HttpResponse::Ok()
+        .append_header((header::CACHE_CONTROL, "no-store"))
+        .json(dto)
+}
@@ -0,0 +1,113 @@
+use crate::config::server_config::ServerConfig;
+use crate::errors::api_error::{ApiError, ProblemDetails};
+use crate::repository::application::model::release_channel::ReleaseChannel;
+use crate::web::appcast::Appcast;
+use crate::web::conditional::Validators;
+use crate::web::dto::application::appcast_query::AppcastQuery;
+use crate::web::feed::FeedFormat;
+use actix_web::http::header;
+use actix_web::{HttpRequest, HttpResponse, get, web};
+use log::error;
+
+/// # Summary
+///
+/// Fetches the releases of a platform of an application as a Sparkle or WinSparkle appcast.
+///
+/// # Arguments
+///
+/// * `path` - The ID of the application and the name of the platform.
+/// * `query` - The optional architecture and release channel to restrict the releases to.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing the appcast or an error message.
+///
+/// # Errors
+///
+/// * Returns an HTTP 404 Not Found if the application, platform or architecture is not found.
+/// * Returns an HTTP 500 Internal Server Error if there is an error fetching the application.
+#[utoipa::path(
+    get,
+    path = "/api/v1/applications/{id}/platforms/{platform}/appcast.xml",
+    tag = "Appcasts",
+    params(
+        ("id" = String, Path, description = "The ID of the application", nullable = false),
+        ("platform" = String, Path, description = "The name of the platform", nullable = false),
+        ("arch" = Option<String>, Query, description = "The architecture to restrict the releases to", nullable = true),
+        ("channel" = Option<String>, Query, description = "The release channel to restrict the releases to, stable by default", nullable = true),
+        ("If-None-Match" = Option<String>, Header, description = "The entity tags of representations the client already has", nullable = true),
+        ("If-Modified-Since" = Option<String>, Header, description = "The date of the representation the client already has", nullable = true),
+    ),
+    responses(
+            (status = 200, description = "HTTP OK", body = String, content_type = "application/rss+xml"),
+            (status = 304, description = "HTTP Not Modified"),
+            (status = 404, description = "HTTP Not Found", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[get("/{id}/platforms/{platform}/appcast.xml")]
+pub async fn find_appcast(
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    query: web::Query<AppcastQuery>,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
+    let (id, platform) = path.into_inner();
+
+    let application = pool
+        .services
+        .application_service
+        .get_by_id(&id)
+        .await
+        .map_err(|e| {
+            error!("Error fetching application: {}", e);
+            ApiError::internal_server_error(&format!("Error fetching application with ID {}", id))
+        })?
+        .ok_or_else(|| ApiError::not_found(&format!("Application with ID {} was not found", id)))?;
+
+    let target = application
+        .platforms
+        .iter()
+        .flatten()
+        .find(|p| p.platform_name.eq_ignore_ascii_case(&platform))
+        .ok_or_else(|| {
+            ApiError::not_found(&format!(
+                "Platform {} of application with ID {} was not found",
+                platform, id
+            ))
+        })?;
+
+    if let Some(arch) = &query.arch
+        && !target
+            .architectures
+            .iter()
+            .flatten()
+            .any(|a| a.name.eq_ignore_ascii_case(arch))
+    {
+        return Err(ApiError::not_found(&format!(
+            "Architecture {} of {} was not found",
+            arch, target.platform_name
+        )));
+    }
+
+    let validators = Validators::new(
+        &req,
+        [(application.id.as_str(), application.updated_at.as_str())],
+    );
+    if validators.is_not_modified(&req) {
+        return Ok(validators.not_modified());
+    }
+
+    let appcast = Appcast::new(
+        &application,
+        target,
+        query.arch.as_deref(),
+        query.channel.as_ref().unwrap_or(&ReleaseChannel::Stable),
+        &format!("{}{}", &pool.server_context, req.uri()),
+    );
+
+    Ok(validators
+        .apply(&mut HttpResponse::Ok())
+        .insert_header((header::CONTENT_TYPE, FeedFormat::Rss.content_type()))
+        .body(appcast.render()))
+}
@@ -1,8 +1,22 @@
 use crate::config::server_config::ServerConfig;
-use crate::errors::internal_server_error::InternalServerError;
-use crate::web::dto::application::application_dto::ApplicationDto;
+use crate::errors::api_error::{ApiError, ProblemDetails};
+use crate::repository::application::model::application::Release;
+use crate::repository::application::model::release_channel::ReleaseChannel;
+use crate::repository::semver::SemVer;
+use crate::services::application::application_service::{ApplicationService, UpdateCheck};
+use crate::web::conditional::Validators;
+use crate::web::dto::application::application_dto::{ApplicationDto, ReleaseDto};
+use crate::web::dto::application::application_request_dto::{
+    CreateApplicationDto, PatchApplicationDto, UpdateApplicationDto,
+};
 use crate::web::dto::application::applications_query::ApplicationsQuery;
-use actix_web::{HttpResponse, get, web};
+use crate::web::dto::application::channel_query::ChannelQuery;
+use crate::web::dto::application::download_query::DownloadQuery;
+use crate::web::dto::application::latest_query::LatestQuery;
+use crate::web::dto::application::update_query::UpdateQuery;
+use crate::web::middleware::authentication::{APPLICATIONS_WRITE, RequireScope};
+use actix_web::http::header;
+use actix_web::{HttpRequest, HttpResponse, delete, get, patch, post, put, web};
 use log::error;
 
 /// # Summary
@@ -12,10 +26,11 @@ use log::error;
 /// # Arguments
 ///
 /// * `id` - The ID of the application to fetch.
+/// * `channel` - The optional release channel to restrict the releases to.
 ///
 /// # Returns
 ///
-/// * `HttpResponse` - The HTTP response containing the application data or an error message.
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing the application data or an error message.
 ///
 /// # Errors
 ///
@@ -27,39 +42,53 @@ use log::error;
     tag = "Applications",
     params(
         ("id" = String, Path, description = "The ID of the application to fetch", nullable = false),
+        ("channel" = Option<String>, Query, description = "The release channel to restrict the releases to", nullable = true),
+        ("If-None-Match" = Option<String>, Header, description = "The entity tags of representations the client already has", nullable = true),
+        ("If-Modified-Since" = Option<String>, Header, description = "The date of the representation the client already has", nullable = true),
     ),
     responses(
             (status = 200, description = "HTTP OK", body = ApplicationDto),
-            (status = 404, description = "HTTP Not Found"),
-            (status = 500, description = "HTTP Internal Server Error", body = InternalServerError),
+            (status = 304, description = "HTTP Not Modified"),
+            (status = 404, description = "HTTP Not Found", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
     ),
 )]
 #[get("/{id}")]
-pub async fn find_by_id(id: web::Path<String>, pool: web::Data<ServerConfig>) -> HttpResponse {
+pub async fn find_by_id(
+    req: HttpRequest,
+    id: web::Path<String>,
+    channel: web::Query<ChannelQuery>,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
     let id = id.into_inner();
 
-    let res = match pool
+    let res = pool
         .services
         .application_service
-        .get_by_id(&id, &pool.client.database(&pool.database_name))
+        .get_by_id(&id)
         .await
-    {
-        Ok(app) => app,
-        Err(e) => {
-            log::error!("Error fetching application: {}", e);
-            return HttpResponse::InternalServerError().json(InternalServerError::new(&format!(
-                "Error fetching application with ID {}",
-                id
-            )));
-        }
-    };
+        .map_err(|e| {
+            error!("Error fetching application: {}", e);
+            ApiError::internal_server_error(&format!("Error fetching application with ID {}", id))
+        })?;
+
+    let mut application = res
+        .ok_or_else(|| ApiError::not_found(&format!("Application with ID {} was not found", id)))?;
 
-    if res.is_none() {
-        return HttpResponse::NotFound().finish();
+    let validators = Validators::new(
+        &req,
+        [(application.id.as_str(), application.updated_at.as_str())],
+    );
+    if validators.is_not_modified(&req) {
+        return Ok(validators.not_modified());
     }
 
-    let dto = ApplicationDto::from(res.unwrap());
-    HttpResponse::Ok().json(dto)
+    if let Some(channel) = &channel.channel {
+        ApplicationService::retain_channel(&mut application, channel);
+    }
+
+    let dto = ApplicationDto::from(application);
+    Ok(validators.apply(&mut HttpResponse::Ok()).json(dto))
 }
 
 /// # Summary
@@ -69,10 +98,11 @@ pub async fn find_by_id(id: web::Path<String>, pool: web::Data<ServerConfig>) ->
 /// # Arguments
 ///
 /// * `query` - The query parameters for pagination.
+/// * `channel` - The optional release channel to restrict the releases to.
 ///
 /// # Returns
 ///
-/// * `HttpResponse` - The HTTP response containing a list of applications or an error message.
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing a list of applications or an error message.
 ///
 /// # Errors
 ///
@@ -85,18 +115,23 @@ pub async fn find_by_id(id: web::Path<String>, pool: web::Data<ServerConfig>) ->
     params(
         ("page" = Option<String>, Query, description = "The page", nullable = true),
         ("limit" = Option<i64>, Query, description = "The limit of the amount of entities to retrieve", nullable = true),
+        ("channel" = Option<String>, Query, description = "The release channel to restrict the releases to", nullable = true),
+        ("If-None-Match" = Option<String>, Header, description = "The entity tags of representations the client already has", nullable = true),
     ),
     responses(
             (status = 200, description = "HTTP OK", body = Vec<ApplicationDto>),
-            (status = 404, description = "HTTP Not Found"),
-            (status = 500, description = "HTTP Internal Server Error", body = InternalServerError),
+            (status = 304, description = "HTTP Not Modified"),
+            (status = 404, description = "HTTP Not Found", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
     ),
 )]
 #[get("/")]
 pub async fn find_all(
+    req: HttpRequest,
     query: web::Query<ApplicationsQuery>,
+    channel: web::Query<ChannelQuery>,
     pool: web::Data<ServerConfig>,
-) -> HttpResponse {
+) -> Result<HttpResponse, ApiError> {
     let limit = query.limit;
 
     let mut new_limit = limit.unwrap_or(pool.max_fetch_limit);
@@ -104,43 +139,33 @@ pub async fn find_all(
         new_limit = pool.max_fetch_limit;
     }
 
-    let res;
-    if query.page.is_none() {
-        res = match pool
-            .services
+    let res = if query.page.is_none() {
+        pool.services
             .application_service
-            .get_all_sorted_by_id(Some(new_limit), &pool.client.database(&pool.database_name))
+            .get_all_sorted_by_id(Some(new_limit))
             .await
-        {
-            Ok(r) => r,
-            Err(err) => {
-                error!("Error fetching applications: {}", err);
-                return HttpResponse::InternalServerError()
-                    .json(InternalServerError::new("Error fetching applications"));
-            }
-        };
     } else {
-        res = match pool
-            .services
+        pool.services
             .application_service
-            .get_all_with_id_greater_than(
-                &query.page.clone().unwrap(),
-                Some(new_limit),
-                &pool.client.database(&pool.database_name),
-            )
+            .get_all_with_id_greater_than(&query.page.clone().unwrap(), Some(new_limit))
             .await
-        {
-            Ok(r) => r,
-            Err(err) => {
-                error!("Error fetching applications: {}", err);
-                return HttpResponse::InternalServerError()
-                    .json(InternalServerError::new("Error fetching applications"));
-            }
-        };
     }
+    .map_err(|err| {
+        error!("Error fetching applications: {}", err);
+        ApiError::internal_server_error("Error fetching applications")
+    })?;
 
     if res.is_empty() {
-        return HttpResponse::NotFound().finish();
+        return Err(ApiError::not_found("No applications were found"));
+    }
+
+    let validators = Validators::for_collection(
+        &req,
+        res.iter()
+            .map(|app| (app.id.as_str(), app.updated_at.as_str())),
+    );
+    if validators.is_not_modified(&req) {
+        return Ok(validators.not_modified());
     }
 
     let last_id = res
@@ -149,22 +174,685 @@ pub async fn find_all(
         .unwrap_or(String::from(""));
 
     let mut dtos: Vec<ApplicationDto> = vec![];
-    for app in res {
+    for mut app in res {
+        if let Some(channel) = &channel.channel {
+            ApplicationService::retain_channel(&mut app, channel);
+        }
         dtos.push(ApplicationDto::from(app));
     }
 
-    let next;
-    if dtos.len() < usize::try_from(new_limit).unwrap() {
-        next = format!(
+    let next = if dtos.len() < usize::try_from(new_limit).unwrap() {
+        format!(
             "<{}/api/v1/applications/?limit={}>; rel=first",
             &pool.server_context, new_limit
-        );
+        )
     } else {
-        next = format!(
+        format!(
             "<{}/api/v1/applications/?limit={}>; rel=first, <{}/api/v1/applications/?page={}&limit={}>; rel=next",
             &pool.server_context, new_limit, &pool.server_context, last_id, new_limit
-        );
+        )
+    };
+
+    Ok(validators
+        .apply(&mut HttpResponse::Ok())
+        .append_header(("Link", next))
+        .json(dtos))
+}
+
+/// # Summary
+///
+/// Checks whether a newer release than the client's current version is available.
+///
+/// # Arguments
+///
+/// * `id` - The ID of the application.
+/// * `query` - The client's current version, platform, architecture and release channel.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing the newest release, or no content if the client is up to date.
+///
+/// # Errors
+///
+/// * Returns an HTTP 400 Bad Request if the current version is not a valid semantic version.
+/// * Returns an HTTP 404 Not Found if the application or a release for the platform and architecture is not found.
+/// * Returns an HTTP 500 Internal Server Error if there is an error fetching the application.
+#[utoipa::path(
+    get,
+    path = "/api/v1/applications/{id}/updates",
+    tag = "Applications",
+    params(
+        ("id" = String, Path, description = "The ID of the application", nullable = false),
+        ("current" = String, Query, description = "The semantic version the client is currently running", nullable = false),
+        ("platform" = String, Query, description = "The platform the client runs on", nullable = false),
+        ("arch" = String, Query, description = "The architecture the client runs on", nullable = false),
+        ("channel" = Option<String>, Query, description = "The release channel the client is subscribed to, defaults to stable", nullable = true),
+    ),
+    responses(
+            (status = 200, description = "HTTP OK", body = ReleaseDto),
+            (status = 204, description = "HTTP No Content"),
+            (status = 400, description = "HTTP Bad Request", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 404, description = "HTTP Not Found", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[get("/{id}/updates")]
+pub async fn check_for_update(
+    id: web::Path<String>,
+    query: web::Query<UpdateQuery>,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
+    let id = id.into_inner();
+
+    let current = query.current.parse::<SemVer>().map_err(|e| {
+        ApiError::bad_request(&format!(
+            "Invalid current version '{}': {}",
+            query.current, e
+        ))
+    })?;
+
+    let res = pool
+        .services
+        .application_service
+        .check_for_update(
+            &id,
+            &current,
+            &query.platform,
+            &query.arch,
+            query.channel.as_ref().unwrap_or(&ReleaseChannel::Stable),
+        )
+        .await
+        .map_err(|e| {
+            error!("Error checking for updates: {}", e);
+            ApiError::internal_server_error(&format!(
+                "Error checking for updates of application with ID {}",
+                id
+            ))
+        })?;
+
+    match res {
+        UpdateCheck::NotFound => Err(ApiError::not_found(&format!(
+            "No release of application with ID {} was found for {} {}",
+            id, query.platform, query.arch
+        ))),
+        UpdateCheck::UpToDate => Ok(HttpResponse::NoContent().finish()),
+        UpdateCheck::Available(release) => Ok(HttpResponse::Ok().json(ReleaseDto::from(*release))),
+    }
+}
+
+/// # Summary
+///
+/// Resolves the release channel to use from the query parameters.
+///
+/// # Arguments
+///
+/// * `channel` - The explicitly requested release channel.
+/// * `prerelease` - Whether pre-releases should be taken into account when no channel is requested.
+///
+/// # Returns
+///
+/// * `ReleaseChannel` - The requested channel, nightly when pre-releases are requested, or stable otherwise.
+fn resolve_channel(channel: &Option<ReleaseChannel>, prerelease: Option<bool>) -> ReleaseChannel {
+    match channel {
+        Some(channel) => channel.clone(),
+        None => {
+            if prerelease.unwrap_or(false) {
+                ReleaseChannel::Nightly
+            } else {
+                ReleaseChannel::Stable
+            }
+        }
     }
+}
+
+/// # Summary
+///
+/// Resolves the newest release of an application.
+///
+/// # Arguments
+///
+/// * `pool` - The server configuration.
+/// * `id` - The ID of the application.
+/// * `platform` - The optional platform to restrict the search to.
+/// * `architecture` - The optional architecture to restrict the search to.
+/// * `channel` - The release channel to take into account.
+///
+/// # Returns
+///
+/// * `Result<Release, ApiError>` - The newest release or an error.
+async fn latest_release(
+    pool: &ServerConfig,
+    id: &str,
+    platform: Option<&str>,
+    architecture: Option<&str>,
+    channel: &ReleaseChannel,
+) -> Result<Release, ApiError> {
+    pool.services
+        .application_service
+        .get_latest_release(id, platform, architecture, channel)
+        .await
+        .map_err(|e| {
+            error!("Error fetching latest release: {}", e);
+            ApiError::internal_server_error(&format!(
+                "Error fetching latest release of application with ID {}",
+                id
+            ))
+        })?
+        .ok_or_else(|| {
+            ApiError::not_found(&format!(
+                "No matching release of application with ID {} was found",
+                id
+            ))
+        })
+}
+
+/// # Summary
+///
+/// Fetches the newest release of an application across all platforms and architectures.
+///
+/// # Arguments
+///
+/// * `id` - The ID of the application.
+/// * `query` - The release channel or whether pre-releases should be taken into account.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing the newest release or an error message.
+///
+/// # Errors
+///
+/// * Returns an HTTP 404 Not Found if the application or a matching release is not found.
+/// * Returns an HTTP 500 Internal Server Error if there is an error fetching the application.
+#[utoipa::path(
+    get,
+    path = "/api/v1/applications/{id}/latest",
+    tag = "Applications",
+    params(
+        ("id" = String, Path, description = "The ID of the application", nullable = false),
+        ("prerelease" = Option<bool>, Query, description = "Whether pre-releases should be included when no channel is provided, defaults to false", nullable = true),
+        ("channel" = Option<String>, Query, description = "The release channel to take into account, defaults to stable", nullable = true),
+    ),
+    responses(
+            (status = 200, description = "HTTP OK", body = ReleaseDto),
+            (status = 404, description = "HTTP Not Found", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[get("/{id}/latest")]
+pub async fn find_latest_release(
+    id: web::Path<String>,
+    query: web::Query<LatestQuery>,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
+    let id = id.into_inner();
+
+    let release = latest_release(
+        &pool,
+        &id,
+        None,
+        None,
+        &resolve_channel(&query.channel, query.prerelease),
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(ReleaseDto::from(release)))
+}
+
+/// # Summary
+///
+/// Fetches the newest release of an application for a platform.
+///
+/// # Arguments
+///
+/// * `path` - The ID of the application and the name of the platform.
+/// * `query` - The release channel or whether pre-releases should be taken into account.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing the newest release or an error message.
+///
+/// # Errors
+///
+/// * Returns an HTTP 404 Not Found if the application or a matching release is not found.
+/// * Returns an HTTP 500 Internal Server Error if there is an error fetching the application.
+#[utoipa::path(
+    get,
+    path = "/api/v1/applications/{id}/platforms/{platform}/latest",
+    tag = "Applications",
+    params(
+        ("id" = String, Path, description = "The ID of the application", nullable = false),
+        ("platform" = String, Path, description = "The name of the platform", nullable = false),
+        ("prerelease" = Option<bool>, Query, description = "Whether pre-releases should be included when no channel is provided, defaults to false", nullable = true),
+        ("channel" = Option<String>, Query, description = "The release channel to take into account, defaults to stable", nullable = true),
+    ),
+    responses(
+            (status = 200, description = "HTTP OK", body = ReleaseDto),
+            (status = 404, description = "HTTP Not Found", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[get("/{id}/platforms/{platform}/latest")]
+pub async fn find_latest_platform_release(
+    path: web::Path<(String, String)>,
+    query: web::Query<LatestQuery>,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
+    let (id, platform) = path.into_inner();
+
+    let release = latest_release(
+        &pool,
+        &id,
+        Some(&platform),
+        None,
+        &resolve_channel(&query.channel, query.prerelease),
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(ReleaseDto::from(release)))
+}
+
+/// # Summary
+///
+/// Fetches the newest release of an application for a platform and architecture.
+///
+/// # Arguments
+///
+/// * `path` - The ID of the application and the names of the platform and architecture.
+/// * `query` - The release channel or whether pre-releases should be taken into account.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing the newest release or an error message.
+///
+/// # Errors
+///
+/// * Returns an HTTP 404 Not Found if the application or a matching release is not found.
+/// * Returns an HTTP 500 Internal Server Error if there is an error fetching the application.
+#[utoipa::path(
+    get,
+    path = "/api/v1/applications/{id}/platforms/{platform}/architectures/{arch}/latest",
+    tag = "Applications",
+    params(
+        ("id" = String, Path, description = "The ID of the application", nullable = false),
+        ("platform" = String, Path, description = "The name of the platform", nullable = false),
+        ("arch" = String, Path, description = "The name of the architecture", nullable = false),
+        ("prerelease" = Option<bool>, Query, description = "Whether pre-releases should be included when no channel is provided, defaults to false", nullable = true),
+        ("channel" = Option<String>, Query, description = "The release channel to take into account, defaults to stable", nullable = true),
+    ),
+    responses(
+            (status = 200, description = "HTTP OK", body = ReleaseDto),
+            (status = 404, description = "HTTP Not Found", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[get("/{id}/platforms/{platform}/architectures/{arch}/latest")]
+pub async fn find_latest_architecture_release(
+    path: web::Path<(String, String, String)>,
+    query: web::Query<LatestQuery>,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
+    let (id, platform, arch) = path.into_inner();
+
+    let release = latest_release(
+        &pool,
+        &id,
+        Some(&platform),
+        Some(&arch),
+        &resolve_channel(&query.channel, query.prerelease),
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(ReleaseDto::from(release)))
+}
+
+/// # Summary
+///
+/// Redirects to the download URL of the newest release of an application for a platform.
+///
+/// # Arguments
+///
+/// * `id` - The ID of the application.
+/// * `query` - The platform, optional architecture and release channel.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - An HTTP 302 Found response pointing to the download URL or an error message.
+///
+/// # Errors
+///
+/// * Returns an HTTP 404 Not Found if the application or a matching release is not found.
+/// * Returns an HTTP 500 Internal Server Error if there is an error fetching the application.
+#[utoipa::path(
+    get,
+    path = "/api/v1/applications/{id}/download",
+    tag = "Applications",
+    params(
+        ("id" = String, Path, description = "The ID of the application", nullable = false),
+        ("platform" = String, Query, description = "The name of the platform", nullable = false),
+        ("arch" = Option<String>, Query, description = "The name of the architecture", nullable = true),
+        ("prerelease" = Option<bool>, Query, description = "Whether pre-releases should be included when no channel is provided, defaults to false", nullable = true),
+        ("channel" = Option<String>, Query, description = "The release channel to take into account, defaults to stable", nullable = true),
+    ),
+    responses(
+            (status = 302, description = "HTTP Found"),
+            (status = 404, description = "HTTP Not Found", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[get("/{id}/download")]
+pub async fn download(
+    id: web::Path<String>,
+    query: web::Query<DownloadQuery>,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
+    let id = id.into_inner();
+
+    let release = latest_release(
+        &pool,
+        &id,
+        Some(&query.platform),
+        query.arch.as_deref(),
+        &resolve_channel(&query.channel, query.prerelease),
+    )
+    .await?;
+
+    Ok(HttpResponse::Found()
+        .append_header((header::LOCATION, release.download_url))
+        .append_header((header::CACHE_CONTROL, "no-cache"))
+        .finish())
+}
+
+/// # Summary
+///
+/// Creates a new application.
+///
+/// # Arguments
+///
+/// * `body` - The application to create.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing the created application or an error message.
+///
+/// # Errors
+///
+/// * Returns an HTTP 400 Bad Request if the application is invalid.
+/// * Returns an HTTP 409 Conflict if an application with the same ID already exists.
+/// * Returns an HTTP 500 Internal Server Error if there is an error creating the application.
+#[utoipa::path(
+    post,
+    path = "/api/v1/applications/",
+    tag = "Applications",
+    security(
+        ("api_key" = ["applications:write"]),
+        ("bearer" = ["applications:write"]),
+    ),
+    request_body = CreateApplicationDto,
+    responses(
+            (status = 201, description = "HTTP Created", body = ApplicationDto),
+            (status = 400, description = "HTTP Bad Request", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 422, description = "HTTP Unprocessable Content", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 409, description = "HTTP Conflict", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 401, description = "HTTP Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 403, description = "HTTP Forbidden", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[post("/", wrap = "RequireScope::new(APPLICATIONS_WRITE)")]
+pub async fn create_application(
+    body: web::Json<CreateApplicationDto>,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
+    let application = pool
+        .services
+        .application_service
+        .create(body.into_inner().into())
+        .await
+        .map_err(|e| e.into_api_error("Error creating application"))?;
+
+    Ok(HttpResponse::Created()
+        .append_header((
+            header::LOCATION,
+            format!(
+                "{}/api/v1/applications/{}",
+                &pool.server_context, application.id
+            ),
+        ))
+        .json(ApplicationDto::from(application)))
+}
+
+/// # Summary
+///
+/// Replaces the name, description and platforms of an application.
+///
+/// # Arguments
+///
+/// * `id` - The ID of the application to replace.
+/// * `body` - The new state of the application.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing the updated application or an error message.
+///
+/// # Errors
+///
+/// * Returns an HTTP 400 Bad Request if the application is invalid.
+/// * Returns an HTTP 404 Not Found if the application is not found.
+/// * Returns an HTTP 500 Internal Server Error if there is an error updating the application.
+#[utoipa::path(
+    put,
+    path = "/api/v1/applications/{id}",
+    tag = "Applications",
+    security(
+        ("api_key" = ["applications:write"]),
+        ("bearer" = ["applications:write"]),
+    ),
+    params(
+        ("id" = String, Path, description = "The ID of the application to replace", nullable = false),
+    ),
+    request_body = UpdateApplicationDto,
+    responses(
+            (status = 200, description = "HTTP OK", body = ApplicationDto),
+            (status = 400, description = "HTTP Bad Request", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 422, description = "HTTP Unprocessable Content", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 404, description = "HTTP Not Found", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 401, description = "HTTP Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 403, description = "HTTP Forbidden", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[put("/{id}", wrap = "RequireScope::new(APPLICATIONS_WRITE)")]
+pub async fn replace_application(
+    id: web::Path<String>,
+    body: web::Json<UpdateApplicationDto>,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
+    let application = pool
+        .services
+        .application_service
+        .update(&id, body.into_inner().into())
+        .await
+        .map_err(|e| e.into_api_error(&format!("Error updating application with ID {}", id)))?;
+
+    Ok(HttpResponse::Ok().json(ApplicationDto::from(application)))
+}
+
+/// # Summary
+///
+/// Partially updates an application. Fields that are omitted are left untouched.
+///
+/// # Arguments
+///
+/// * `id` - The ID of the application to update.
+/// * `body` - The fields to update.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing the updated application or an error message.
+///
+/// # Errors
+///
+/// * Returns an HTTP 400 Bad Request if the changes are invalid.
+/// * Returns an HTTP 404 Not Found if the application is not found.
+/// * Returns an HTTP 500 Internal Server Error if there is an error updating the application.
+#[utoipa::path(
+    patch,
+    path = "/api/v1/applications/{id}",
+    tag = "Applications",
+    security(
+        ("api_key" = ["applications:write"]),
+        ("bearer" = ["applications:write"]),
+    ),
+    params(
+        ("id" = String, Path, description = "The ID of the application to update", nullable = false),
+    ),
+    request_body = PatchApplicationDto,
+    responses(
+            (status = 200, description = "HTTP OK", body = ApplicationDto),
+            (status = 400, description = "HTTP Bad Request", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 422, description = "HTTP Unprocessable Content", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 404, description = "HTTP Not Found", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 401, description = "HTTP Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 403, description = "HTTP Forbidden", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[patch("/{id}", wrap = "RequireScope::new(APPLICATIONS_WRITE)")]
+pub async fn patch_application(
+    id: web::Path<String>,
+    body: web::Json<PatchApplicationDto>,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
+    let application = pool
+        .services
+        .application_service
+        .update(&id, body.into_inner().into())
+        .await
+        .map_err(|e| e.into_api_error(&format!("Error updating application with ID {}", id)))?;
+
+    Ok(HttpResponse::Ok().json(ApplicationDto::from(application)))
+}
+
+/// # Summary
+///
+/// Deletes an application.
+///
+/// # Arguments
+///
+/// * `id` - The ID of the application to delete.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - An empty HTTP response or an error message.
+///
+/// # Errors
+///
+/// * Returns an HTTP 404 Not Found if the application is not found.
+/// * Returns an HTTP 500 Internal Server Error if there is an error deleting the application.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/applications/{id}",
+    tag = "Applications",
+    security(
+        ("api_key" = ["applications:write"]),
+        ("bearer" = ["applications:write"]),
+    ),
+    params(
+        ("id" = String, Path, description = "The ID of the application to delete", nullable = false),
+    ),
+    responses(
+            (status = 204, description = "HTTP No Content"),
+            (status = 404, description = "HTTP Not Found", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 401, description = "HTTP Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 403, description = "HTTP Forbidden", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[delete("/{id}", wrap = "RequireScope::new(APPLICATIONS_WRITE)")]
+pub async fn delete_application(
+    id: web::Path<String>,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
+    pool.services
+        .application_service
+        .delete(&id)
+        .await
+        .map_err(|e| e.into_api_error(&format!("Error deleting application with ID {}", id)))?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// # Summary
+///
+/// Publishes a new release into an existing architecture of an application.
+///
+/// # Arguments
+///
+/// * `path` - The ID of the application and the names of the platform and architecture.
+/// * `body` - The release to publish.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing the published release or an error message.
+///
+/// # Errors
+///
+/// * Returns an HTTP 400 Bad Request if the release is invalid.
+/// * Returns an HTTP 404 Not Found if the application, platform or architecture is not found.
+/// * Returns an HTTP 409 Conflict if a release with the same semantic version already exists.
+/// * Returns an HTTP 500 Internal Server Error if there is an error publishing the release.
+#[utoipa::path(
+    post,
+    path = "/api/v1/applications/{id}/platforms/{platform}/architectures/{arch}/releases",
+    tag = "Applications",
+    security(
+        ("api_key" = ["applications:write"]),
+        ("bearer" = ["applications:write"]),
+    ),
+    params(
+        ("id" = String, Path, description = "The ID of the application", nullable = false),
+        ("platform" = String, Path, description = "The name of the platform", nullable = false),
+        ("arch" = String, Path, description = "The name of the architecture", nullable = false),
+    ),
+    request_body = ReleaseDto,
+    responses(
+            (status = 201, description = "HTTP Created", body = ReleaseDto),
+            (status = 400, description = "HTTP Bad Request", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 422, description = "HTTP Unprocessable Content", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 404, description = "HTTP Not Found", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 409, description = "HTTP Conflict", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 401, description = "HTTP Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 403, description = "HTTP Forbidden", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[post(
+    "/{id}/platforms/{platform}/architectures/{arch}/releases",
+    wrap = "RequireScope::new(APPLICATIONS_WRITE)"
+)]
+pub async fn create_release(
+    path: web::Path<(String, String, String)>,
+    body: web::Json<ReleaseDto>,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
+    let (id, platform, arch) = path.into_inner();
+
+    let release = pool
+        .services
+        .application_service
+        .add_release(&id, &platform, &arch, body.into_inner().into())
+        .await
+        .map_err(|e| {
+            e.into_api_error(&format!(
+                "Error publishing release of application with ID {}",
+                id
+            ))
+        })?;
 
-    HttpResponse::Ok().append_header(("Link", next)).json(dtos)
+    Ok(HttpResponse::Created()
+        .append_header((
+            header::LOCATION,
+            format!("{}/api/v1/applications/{}", &pool.server_context, id),
+        ))
+        .json(ReleaseDto::from(release)))
 }
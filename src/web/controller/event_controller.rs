@@ -0,0 +1,91 @@
+use crate::config::server_config::ServerConfig;
+use crate::errors::api_error::{ApiError, ProblemDetails};
+use crate::web::event_stream::event_stream;
+use actix_web::{HttpRequest, HttpResponse, get, web};
+use log::error;
+
+/// # Summary
+///
+/// Streams the changes to an application as Server-Sent Events.
+///
+/// # Arguments
+///
+/// * `id` - The ID of the application to follow.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - The HTTP response with an event for every change to the application, its architectures and releases.
+///
+/// # Errors
+///
+/// * Returns an HTTP 404 Not Found if the application is not found.
+/// * Returns an HTTP 429 Too Many Requests if the maximum number of event streams is open.
+/// * Returns an HTTP 500 Internal Server Error if there is an error fetching the application.
+#[utoipa::path(
+    get,
+    path = "/api/v1/applications/{id}/events",
+    tag = "Events",
+    params(
+        ("id" = String, Path, description = "The ID of the application to follow", nullable = false),
+        ("Last-Event-ID" = Option<String>, Header, description = "The ID of the last event the client received, to resume the stream", nullable = true),
+    ),
+    responses(
+            (status = 200, description = "HTTP OK", body = String, content_type = "text/event-stream"),
+            (status = 404, description = "HTTP Not Found", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 429, description = "HTTP Too Many Requests", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[get("/{id}/events")]
+pub async fn application_events(
+    req: HttpRequest,
+    id: web::Path<String>,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
+    let id = id.into_inner();
+
+    pool.services
+        .application_service
+        .get_by_id(&id)
+        .await
+        .map_err(|e| {
+            error!("Error fetching application: {}", e);
+            ApiError::internal_server_error(&format!("Error fetching application with ID {}", id))
+        })?
+        .ok_or_else(|| ApiError::not_found(&format!("Application with ID {} was not found", id)))?;
+
+    event_stream(&req, &pool.services.event_service, move |event| {
+        event.application_id == id
+    })
+}
+
+/// # Summary
+///
+/// Streams the changes to all applications as Server-Sent Events.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - The HTTP response with an event for every change to an application, its architectures and releases.
+///
+/// # Errors
+///
+/// * Returns an HTTP 429 Too Many Requests if the maximum number of event streams is open.
+#[utoipa::path(
+    get,
+    path = "/api/v1/events",
+    tag = "Events",
+    params(
+        ("Last-Event-ID" = Option<String>, Header, description = "The ID of the last event the client received, to resume the stream", nullable = true),
+    ),
+    responses(
+            (status = 200, description = "HTTP OK", body = String, content_type = "text/event-stream"),
+            (status = 429, description = "HTTP Too Many Requests", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[get("/events")]
+pub async fn events(
+    req: HttpRequest,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
+    event_stream(&req, &pool.services.event_service, |_| true)
+}
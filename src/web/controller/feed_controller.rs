@@ -0,0 +1,301 @@
+use crate::config::server_config::ServerConfig;
+use crate::errors::api_error::{ApiError, ProblemDetails};
+use crate::repository::application::model::application::Application;
+use crate::web::conditional::Validators;
+use crate::web::dto::application::channel_query::ChannelQuery;
+use crate::web::feed::{Feed, FeedFormat};
+use actix_web::http::header;
+use actix_web::{HttpRequest, HttpResponse, get, web};
+use log::error;
+
+/// Render the feed of a single application
+///
+/// # Arguments
+///
+/// * `req` - The HTTP request
+/// * `id` - The ID of the application
+/// * `channel` - The optional release channel to restrict the releases to
+/// * `pool` - The server configuration
+/// * `format` - The syndication format to render
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing the feed or an error message.
+async fn application_feed(
+    req: HttpRequest,
+    id: String,
+    channel: ChannelQuery,
+    pool: web::Data<ServerConfig>,
+    format: FeedFormat,
+) -> Result<HttpResponse, ApiError> {
+    let application = pool
+        .services
+        .application_service
+        .get_by_id(&id)
+        .await
+        .map_err(|e| {
+            error!("Error fetching application: {}", e);
+            ApiError::internal_server_error(&format!("Error fetching application with ID {}", id))
+        })?
+        .ok_or_else(|| ApiError::not_found(&format!("Application with ID {} was not found", id)))?;
+
+    let applications = [application];
+    let feed = Feed::new(
+        &format!("{} releases", applications[0].name),
+        applications[0]
+            .description
+            .as_deref()
+            .unwrap_or(&format!("The releases of {}", applications[0].name)),
+        &pool.server_context,
+        req.path(),
+        &format!("/api/v1/applications/{}", id),
+        &applications,
+        channel.channel.as_ref(),
+    );
+
+    Ok(feed_response(&req, &feed, &applications, format))
+}
+
+/// Render the feed of all applications
+///
+/// # Arguments
+///
+/// * `req` - The HTTP request
+/// * `channel` - The optional release channel to restrict the releases to
+/// * `pool` - The server configuration
+/// * `format` - The syndication format to render
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing the feed or an error message.
+async fn global_feed(
+    req: HttpRequest,
+    channel: ChannelQuery,
+    pool: web::Data<ServerConfig>,
+    format: FeedFormat,
+) -> Result<HttpResponse, ApiError> {
+    let applications = pool
+        .services
+        .application_service
+        .get_all_sorted_by_id(None)
+        .await
+        .map_err(|e| {
+            error!("Error fetching applications: {}", e);
+            ApiError::internal_server_error("Error fetching applications")
+        })?;
+
+    let feed = Feed::new(
+        "CodeDead releases",
+        "The releases of all CodeDead applications",
+        &pool.server_context,
+        req.path(),
+        "/api/v1/applications/",
+        &applications,
+        channel.channel.as_ref(),
+    );
+
+    Ok(feed_response(&req, &feed, &applications, format))
+}
+
+/// Answer a feed request, or a conditional request with 304 Not Modified
+fn feed_response(
+    req: &HttpRequest,
+    feed: &Feed,
+    applications: &[Application],
+    format: FeedFormat,
+) -> HttpResponse {
+    let validators = Validators::new(
+        req,
+        applications
+            .iter()
+            .map(|a| (a.id.as_str(), a.updated_at.as_str())),
+    );
+    if validators.is_not_modified(req) {
+        return validators.not_modified();
+    }
+
+    validators
+        .apply(&mut HttpResponse::Ok())
+        .insert_header((header::CONTENT_TYPE, format.content_type()))
+        .body(feed.render(format))
+}
+
+/// # Summary
+///
+/// Fetches the releases of an application as an Atom feed.
+///
+/// # Arguments
+///
+/// * `id` - The ID of the application.
+/// * `channel` - The optional release channel to restrict the releases to.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing the Atom feed or an error message.
+///
+/// # Errors
+///
+/// * Returns an HTTP 404 Not Found if the application is not found.
+/// * Returns an HTTP 500 Internal Server Error if there is an error fetching the application.
+#[utoipa::path(
+    get,
+    path = "/api/v1/applications/{id}/feed.atom",
+    tag = "Feeds",
+    params(
+        ("id" = String, Path, description = "The ID of the application", nullable = false),
+        ("channel" = Option<String>, Query, description = "The release channel to restrict the releases to", nullable = true),
+        ("If-None-Match" = Option<String>, Header, description = "The entity tags of representations the client already has", nullable = true),
+        ("If-Modified-Since" = Option<String>, Header, description = "The date of the representation the client already has", nullable = true),
+    ),
+    responses(
+            (status = 200, description = "HTTP OK", body = String, content_type = "application/atom+xml"),
+            (status = 304, description = "HTTP Not Modified"),
+            (status = 404, description = "HTTP Not Found", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[get("/{id}/feed.atom")]
+pub async fn application_atom_feed(
+    req: HttpRequest,
+    id: web::Path<String>,
+    channel: web::Query<ChannelQuery>,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
+    application_feed(
+        req,
+        id.into_inner(),
+        channel.into_inner(),
+        pool,
+        FeedFormat::Atom,
+    )
+    .await
+}
+
+/// # Summary
+///
+/// Fetches the releases of an application as an RSS feed.
+///
+/// # Arguments
+///
+/// * `id` - The ID of the application.
+/// * `channel` - The optional release channel to restrict the releases to.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing the RSS feed or an error message.
+///
+/// # Errors
+///
+/// * Returns an HTTP 404 Not Found if the application is not found.
+/// * Returns an HTTP 500 Internal Server Error if there is an error fetching the application.
+#[utoipa::path(
+    get,
+    path = "/api/v1/applications/{id}/feed.rss",
+    tag = "Feeds",
+    params(
+        ("id" = String, Path, description = "The ID of the application", nullable = false),
+        ("channel" = Option<String>, Query, description = "The release channel to restrict the releases to", nullable = true),
+        ("If-None-Match" = Option<String>, Header, description = "The entity tags of representations the client already has", nullable = true),
+        ("If-Modified-Since" = Option<String>, Header, description = "The date of the representation the client already has", nullable = true),
+    ),
+    responses(
+            (status = 200, description = "HTTP OK", body = String, content_type = "application/rss+xml"),
+            (status = 304, description = "HTTP Not Modified"),
+            (status = 404, description = "HTTP Not Found", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[get("/{id}/feed.rss")]
+pub async fn application_rss_feed(
+    req: HttpRequest,
+    id: web::Path<String>,
+    channel: web::Query<ChannelQuery>,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
+    application_feed(
+        req,
+        id.into_inner(),
+        channel.into_inner(),
+        pool,
+        FeedFormat::Rss,
+    )
+    .await
+}
+
+/// # Summary
+///
+/// Fetches the releases of all applications as an Atom feed.
+///
+/// # Arguments
+///
+/// * `channel` - The optional release channel to restrict the releases to.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing the Atom feed or an error message.
+///
+/// # Errors
+///
+/// * Returns an HTTP 500 Internal Server Error if there is an error fetching the applications.
+#[utoipa::path(
+    get,
+    path = "/api/v1/feed.atom",
+    tag = "Feeds",
+    params(
+        ("channel" = Option<String>, Query, description = "The release channel to restrict the releases to", nullable = true),
+        ("If-None-Match" = Option<String>, Header, description = "The entity tags of representations the client already has", nullable = true),
+        ("If-Modified-Since" = Option<String>, Header, description = "The date of the representation the client already has", nullable = true),
+    ),
+    responses(
+            (status = 200, description = "HTTP OK", body = String, content_type = "application/atom+xml"),
+            (status = 304, description = "HTTP Not Modified"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[get("/feed.atom")]
+pub async fn atom_feed(
+    req: HttpRequest,
+    channel: web::Query<ChannelQuery>,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
+    global_feed(req, channel.into_inner(), pool, FeedFormat::Atom).await
+}
+
+/// # Summary
+///
+/// Fetches the releases of all applications as an RSS feed.
+///
+/// # Arguments
+///
+/// * `channel` - The optional release channel to restrict the releases to.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing the RSS feed or an error message.
+///
+/// # Errors
+///
+/// * Returns an HTTP 500 Internal Server Error if there is an error fetching the applications.
+#[utoipa::path(
+    get,
+    path = "/api/v1/feed.rss",
+    tag = "Feeds",
+    params(
+        ("channel" = Option<String>, Query, description = "The release channel to restrict the releases to", nullable = true),
+        ("If-None-Match" = Option<String>, Header, description = "The entity tags of representations the client already has", nullable = true),
+        ("If-Modified-Since" = Option<String>, Header, description = "The date of the representation the client already has", nullable = true),
+    ),
+    responses(
+            (status = 200, description = "HTTP OK", body = String, content_type = "application/rss+xml"),
+            (status = 304, description = "HTTP Not Modified"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[get("/feed.rss")]
+pub async fn rss_feed(
+    req: HttpRequest,
+    channel: web::Query<ChannelQuery>,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
+    global_feed(req, channel.into_inner(), pool, FeedFormat::Rss).await
+}
@@ -0,0 +1,322 @@
+use crate::config::server_config::ServerConfig;
+use crate::errors::api_error::{ApiError, ProblemDetails};
+use crate::repository::application::model::application::Application;
+use crate::repository::application::model::release_channel::ReleaseChannel;
+use crate::repository::semver::SemVer;
+use crate::services::application::application_service::ApplicationService;
+use crate::web::conditional::Validators;
+use crate::web::dto::package::homebrew_query::HomebrewQuery;
+use crate::web::dto::package::scoop_manifest_dto::ScoopManifestDto;
+use crate::web::dto::package::scoop_query::ScoopQuery;
+use crate::web::dto::package::winget_dto::WingetPackageDto;
+use crate::web::dto::package::winget_query::WingetQuery;
+use crate::web::homebrew::{HomebrewCask, default_token};
+use crate::web::package::{PackageError, is_windows};
+use crate::web::scoop::scoop_manifest;
+use crate::web::winget::{WingetPackage, default_package_identifier};
+use actix_web::http::header;
+use actix_web::{HttpRequest, HttpResponse, get, web};
+use log::error;
+
+/// The publisher of packages when none is requested
+const DEFAULT_PUBLISHER: &str = "CodeDead";
+
+/// The license of packages when none is requested
+const DEFAULT_LICENSE: &str = "Proprietary";
+
+/// Fetch an Application or fail with the appropriate ApiError
+///
+/// # Arguments
+///
+/// * `pool` - The server configuration
+/// * `id` - The ID of the Application
+///
+/// # Returns
+///
+/// * `Result<Application, ApiError>` - The Application or an error message.
+async fn fetch_application(pool: &ServerConfig, id: &str) -> Result<Application, ApiError> {
+    pool.services
+        .application_service
+        .get_by_id(id)
+        .await
+        .map_err(|e| {
+            error!("Error fetching application: {}", e);
+            ApiError::internal_server_error(&format!("Error fetching application with ID {}", id))
+        })?
+        .ok_or_else(|| ApiError::not_found(&format!("Application with ID {} was not found", id)))
+}
+
+/// Convert a PackageError into the appropriate ApiError
+///
+/// # Arguments
+///
+/// * `e` - The PackageError
+/// * `id` - The ID of the Application
+///
+/// # Returns
+///
+/// * `ApiError` - HTTP 404 Not Found if nothing can be installed, HTTP 422 Unprocessable Content if a checksum is missing.
+fn package_error(e: PackageError, id: &str) -> ApiError {
+    match e {
+        PackageError::NoInstallers => ApiError::not_found(&format!(
+            "No installable releases of application with ID {} were found",
+            id
+        )),
+        PackageError::MissingChecksum(_) => ApiError::unprocessable_content(&e.to_string()),
+    }
+}
+
+/// # Summary
+///
+/// Renders the winget version, installer and default locale manifests of the Windows releases of an application.
+///
+/// # Arguments
+///
+/// * `path` - The ID of the application.
+/// * `query` - The optional version, package identifier, publisher, license and release channel.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing the manifests or an error message.
+///
+/// # Errors
+///
+/// * Returns an HTTP 400 Bad Request if the version is not a valid semantic version.
+/// * Returns an HTTP 404 Not Found if the application, a Windows platform or an installer of the version is not found.
+/// * Returns an HTTP 422 Unprocessable Content if an installer has no SHA-256 checksum.
+/// * Returns an HTTP 500 Internal Server Error if there is an error fetching the application.
+#[utoipa::path(
+    get,
+    path = "/api/v1/applications/{id}/winget",
+    tag = "Packages",
+    params(
+        ("id" = String, Path, description = "The ID of the application", nullable = false),
+        ("version" = Option<String>, Query, description = "The version to render, the latest release of the channel by default", nullable = true),
+        ("packageIdentifier" = Option<String>, Query, description = "The identifier of the package, derived from the publisher and the name of the application by default", nullable = true),
+        ("publisher" = Option<String>, Query, description = "The publisher of the package, CodeDead by default", nullable = true),
+        ("license" = Option<String>, Query, description = "The license of the package, Proprietary by default", nullable = true),
+        ("channel" = Option<String>, Query, description = "The release channel to find the latest release in, stable by default", nullable = true),
+        ("If-None-Match" = Option<String>, Header, description = "The entity tags of representations the client already has", nullable = true),
+        ("If-Modified-Since" = Option<String>, Header, description = "The date of the representation the client already has", nullable = true),
+    ),
+    responses(
+            (status = 200, description = "HTTP OK", body = WingetPackageDto),
+            (status = 304, description = "HTTP Not Modified"),
+            (status = 400, description = "HTTP Bad Request", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 404, description = "HTTP Not Found", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 422, description = "HTTP Unprocessable Content", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[get("/{id}/winget")]
+pub async fn find_winget_manifests(
+    req: HttpRequest,
+    id: web::Path<String>,
+    query: web::Query<WingetQuery>,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
+    let id = id.into_inner();
+    let application = fetch_application(&pool, &id).await?;
+
+    let platform = application
+        .platforms
+        .iter()
+        .flatten()
+        .find(|p| is_windows(&p.platform_name))
+        .ok_or_else(|| {
+            ApiError::not_found(&format!(
+                "Application with ID {} has no Windows platform",
+                id
+            ))
+        })?;
+
+    let version = match &query.version {
+        Some(version) => version
+            .parse::<SemVer>()
+            .map_err(|e| ApiError::bad_request(&format!("Invalid version '{}': {}", version, e)))?,
+        None => ApplicationService::find_latest_release(
+            &application,
+            Some(&platform.platform_name),
+            None,
+            query.channel.as_ref().unwrap_or(&ReleaseChannel::Stable),
+        )
+        .map(|r| r.semver.clone())
+        .ok_or_else(|| {
+            ApiError::not_found(&format!(
+                "No Windows release of application with ID {} was found",
+                id
+            ))
+        })?,
+    };
+
+    let validators = Validators::new(
+        &req,
+        [(application.id.as_str(), application.updated_at.as_str())],
+    );
+    if validators.is_not_modified(&req) {
+        return Ok(validators.not_modified());
+    }
+
+    let publisher = query.publisher.as_deref().unwrap_or(DEFAULT_PUBLISHER);
+    let package_identifier = query
+        .package_identifier
+        .clone()
+        .unwrap_or_else(|| default_package_identifier(publisher, &application));
+
+    let package = WingetPackage::new(
+        &application,
+        &version,
+        &package_identifier,
+        publisher,
+        query.license.as_deref().unwrap_or(DEFAULT_LICENSE),
+    )
+    .map_err(|e| package_error(e, &id))?;
+
+    Ok(validators
+        .apply(&mut HttpResponse::Ok())
+        .json(WingetPackageDto::from(package)))
+}
+
+/// # Summary
+///
+/// Renders a Homebrew cask of the latest macOS release of every architecture of an application.
+///
+/// # Arguments
+///
+/// * `id` - The ID of the application.
+/// * `query` - The optional cask token, app bundle name and release channel.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing the cask or an error message.
+///
+/// # Errors
+///
+/// * Returns an HTTP 404 Not Found if the application or a macOS release is not found.
+/// * Returns an HTTP 422 Unprocessable Content if a release has no SHA-256 checksum.
+/// * Returns an HTTP 500 Internal Server Error if there is an error fetching the application.
+#[utoipa::path(
+    get,
+    path = "/api/v1/applications/{id}/homebrew.rb",
+    tag = "Packages",
+    params(
+        ("id" = String, Path, description = "The ID of the application", nullable = false),
+        ("token" = Option<String>, Query, description = "The token of the cask, derived from the name of the application by default", nullable = true),
+        ("app" = Option<String>, Query, description = "The name of the app bundle in the download, the name of the application followed by .app by default", nullable = true),
+        ("channel" = Option<String>, Query, description = "The release channel to find the latest releases in, stable by default", nullable = true),
+        ("If-None-Match" = Option<String>, Header, description = "The entity tags of representations the client already has", nullable = true),
+        ("If-Modified-Since" = Option<String>, Header, description = "The date of the representation the client already has", nullable = true),
+    ),
+    responses(
+            (status = 200, description = "HTTP OK", body = String, content_type = "text/plain"),
+            (status = 304, description = "HTTP Not Modified"),
+            (status = 404, description = "HTTP Not Found", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 422, description = "HTTP Unprocessable Content", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[get("/{id}/homebrew.rb")]
+pub async fn find_homebrew_cask(
+    req: HttpRequest,
+    id: web::Path<String>,
+    query: web::Query<HomebrewQuery>,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
+    let id = id.into_inner();
+    let application = fetch_application(&pool, &id).await?;
+
+    let validators = Validators::new(
+        &req,
+        [(application.id.as_str(), application.updated_at.as_str())],
+    );
+    if validators.is_not_modified(&req) {
+        return Ok(validators.not_modified());
+    }
+
+    let token = query
+        .token
+        .clone()
+        .unwrap_or_else(|| default_token(&application));
+    let app = query
+        .app
+        .clone()
+        .unwrap_or_else(|| format!("{}.app", application.name));
+
+    let cask = HomebrewCask::new(
+        &application,
+        &token,
+        &app,
+        query.channel.as_ref().unwrap_or(&ReleaseChannel::Stable),
+    )
+    .map_err(|e| package_error(e, &id))?;
+
+    Ok(validators
+        .apply(&mut HttpResponse::Ok())
+        .insert_header((header::CONTENT_TYPE, "text/plain; charset=utf-8"))
+        .body(cask.render()))
+}
+
+/// # Summary
+///
+/// Renders a Scoop manifest of the latest Windows release of an application.
+///
+/// # Arguments
+///
+/// * `id` - The ID of the application.
+/// * `query` - The optional license and release channel.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing the manifest or an error message.
+///
+/// # Errors
+///
+/// * Returns an HTTP 404 Not Found if the application or a Windows release is not found.
+/// * Returns an HTTP 422 Unprocessable Content if a release has no SHA-256 checksum.
+/// * Returns an HTTP 500 Internal Server Error if there is an error fetching the application.
+#[utoipa::path(
+    get,
+    path = "/api/v1/applications/{id}/scoop.json",
+    tag = "Packages",
+    params(
+        ("id" = String, Path, description = "The ID of the application", nullable = false),
+        ("license" = Option<String>, Query, description = "The license of the application, Proprietary by default", nullable = true),
+        ("channel" = Option<String>, Query, description = "The release channel to find the latest release in, stable by default", nullable = true),
+        ("If-None-Match" = Option<String>, Header, description = "The entity tags of representations the client already has", nullable = true),
+        ("If-Modified-Since" = Option<String>, Header, description = "The date of the representation the client already has", nullable = true),
+    ),
+    responses(
+            (status = 200, description = "HTTP OK", body = ScoopManifestDto),
+            (status = 304, description = "HTTP Not Modified"),
+            (status = 404, description = "HTTP Not Found", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 422, description = "HTTP Unprocessable Content", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[get("/{id}/scoop.json")]
+pub async fn find_scoop_manifest(
+    req: HttpRequest,
+    id: web::Path<String>,
+    query: web::Query<ScoopQuery>,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
+    let id = id.into_inner();
+    let application = fetch_application(&pool, &id).await?;
+
+    let validators = Validators::new(
+        &req,
+        [(application.id.as_str(), application.updated_at.as_str())],
+    );
+    if validators.is_not_modified(&req) {
+        return Ok(validators.not_modified());
+    }
+
+    let manifest = scoop_manifest(
+        &application,
+        query.license.as_deref().unwrap_or(DEFAULT_LICENSE),
+        query.channel.as_ref().unwrap_or(&ReleaseChannel::Stable),
+    )
+    .map_err(|e| package_error(e, &id))?;
+
+    Ok(validators.apply(&mut HttpResponse::Ok()).json(manifest))
+}
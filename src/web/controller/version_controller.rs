@@ -1,8 +1,12 @@
 use crate::config::server_config::ServerConfig;
-use crate::errors::internal_server_error::InternalServerError;
+use crate::errors::api_error::{ApiError, ProblemDetails};
+use crate::web::conditional::Validators;
 use crate::web::dto::application::applications_query::ApplicationsQuery;
 use crate::web::dto::version::version_dto::VersionDto;
-use actix_web::{HttpResponse, get, web};
+use crate::web::dto::version::version_request_dto::{CreateVersionDto, UpdateVersionDto};
+use crate::web::middleware::authentication::{RequireScope, VERSIONS_WRITE};
+use actix_web::http::header;
+use actix_web::{HttpRequest, HttpResponse, delete, get, post, put, web};
 use log::error;
 
 /// Fetches a Version by its ID.
@@ -13,7 +17,7 @@ use log::error;
 ///
 /// # Returns
 ///
-/// * `HttpResponse` - The HTTP response containing the Version data or an error message.
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing the Version data or an error message.
 ///
 /// # Errors
 ///
@@ -25,42 +29,44 @@ use log::error;
     tag = "Version",
     params(
         ("id" = String, Path, description = "The ID of the Version to fetch", nullable = false),
+        ("If-None-Match" = Option<String>, Header, description = "The entity tags of representations the client already has", nullable = true),
+        ("If-Modified-Since" = Option<String>, Header, description = "The date of the representation the client already has", nullable = true),
     ),
     responses(
             (status = 200, description = "HTTP OK", body = VersionDto),
-            (status = 404, description = "HTTP Not Found"),
-            (status = 500, description = "HTTP Internal Server Error", body = InternalServerError),
+            (status = 304, description = "HTTP Not Modified"),
+            (status = 404, description = "HTTP Not Found", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
     ),
 )]
 #[get("/{id}")]
 pub async fn find_version_by_id(
+    req: HttpRequest,
     id: web::Path<String>,
     pool: web::Data<ServerConfig>,
-) -> HttpResponse {
+) -> Result<HttpResponse, ApiError> {
     let id = id.into_inner();
 
-    let res = match pool
+    let res = pool
         .services
         .version_service
-        .find_by_id(&id, &pool.client.database(&pool.database_name))
+        .find_by_id(&id)
         .await
-    {
-        Ok(app) => app,
-        Err(e) => {
-            log::error!("Error fetching version: {}", e);
-            return HttpResponse::InternalServerError().json(InternalServerError::new(&format!(
-                "Error fetching version with ID {}",
-                id
-            )));
-        }
-    };
+        .map_err(|e| {
+            error!("Error fetching version: {}", e);
+            ApiError::internal_server_error(&format!("Error fetching version with ID {}", id))
+        })?;
+
+    let version =
+        res.ok_or_else(|| ApiError::not_found(&format!("Version with ID {} was not found", id)))?;
 
-    if res.is_none() {
-        return HttpResponse::NotFound().finish();
+    let validators = Validators::new(&req, [(version.id.as_str(), version.updated_at.as_str())]);
+    if validators.is_not_modified(&req) {
+        return Ok(validators.not_modified());
     }
 
-    let dto = VersionDto::from(res.unwrap());
-    HttpResponse::Ok().json(dto)
+    let dto = VersionDto::from(version);
+    Ok(validators.apply(&mut HttpResponse::Ok()).json(dto))
 }
 
 /// Fetches all version with pagination support.
@@ -71,7 +77,7 @@ pub async fn find_version_by_id(
 ///
 /// # Returns
 ///
-/// * `HttpResponse` - The HTTP response containing a list of version or an error message.
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing a list of version or an error message.
 ///
 /// # Errors
 ///
@@ -84,18 +90,21 @@ pub async fn find_version_by_id(
     params(
         ("page" = Option<String>, Query, description = "The page", nullable = true),
         ("limit" = Option<i64>, Query, description = "The limit of the amount of entities to retrieve", nullable = true),
+        ("If-None-Match" = Option<String>, Header, description = "The entity tags of representations the client already has", nullable = true),
     ),
     responses(
             (status = 200, description = "HTTP OK", body = Vec<VersionDto>),
-            (status = 404, description = "HTTP Not Found"),
-            (status = 500, description = "HTTP Internal Server Error", body = InternalServerError),
+            (status = 304, description = "HTTP Not Modified"),
+            (status = 404, description = "HTTP Not Found", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
     ),
 )]
 #[get("/")]
 pub async fn find_all_versions(
+    req: HttpRequest,
     query: web::Query<ApplicationsQuery>,
     pool: web::Data<ServerConfig>,
-) -> HttpResponse {
+) -> Result<HttpResponse, ApiError> {
     let limit = query.limit;
 
     let mut new_limit = limit.unwrap_or(pool.max_fetch_limit);
@@ -103,43 +112,33 @@ pub async fn find_all_versions(
         new_limit = pool.max_fetch_limit;
     }
 
-    let res;
-    if query.page.is_none() {
-        res = match pool
-            .services
+    let res = if query.page.is_none() {
+        pool.services
             .version_service
-            .get_all_sorted_by_id(Some(new_limit), &pool.client.database(&pool.database_name))
+            .get_all_sorted_by_id(Some(new_limit))
             .await
-        {
-            Ok(r) => r,
-            Err(err) => {
-                error!("Error fetching versions: {}", err);
-                return HttpResponse::InternalServerError()
-                    .json(InternalServerError::new("Error fetching versions"));
-            }
-        };
     } else {
-        res = match pool
-            .services
+        pool.services
             .version_service
-            .get_all_with_id_greater_than(
-                &query.page.clone().unwrap(),
-                Some(new_limit),
-                &pool.client.database(&pool.database_name),
-            )
+            .get_all_with_id_greater_than(&query.page.clone().unwrap(), Some(new_limit))
             .await
-        {
-            Ok(r) => r,
-            Err(err) => {
-                error!("Error fetching versions: {}", err);
-                return HttpResponse::InternalServerError()
-                    .json(InternalServerError::new("Error fetching versions"));
-            }
-        };
     }
+    .map_err(|err| {
+        error!("Error fetching versions: {}", err);
+        ApiError::internal_server_error("Error fetching versions")
+    })?;
 
     if res.is_empty() {
-        return HttpResponse::NotFound().finish();
+        return Err(ApiError::not_found("No versions were found"));
+    }
+
+    let validators = Validators::for_collection(
+        &req,
+        res.iter()
+            .map(|version| (version.id.as_str(), version.updated_at.as_str())),
+    );
+    if validators.is_not_modified(&req) {
+        return Ok(validators.not_modified());
     }
 
     let last_id = res
@@ -152,18 +151,179 @@ pub async fn find_all_versions(
         dtos.push(VersionDto::from(app));
     }
 
-    let next;
-    if dtos.len() < usize::try_from(new_limit).unwrap() {
-        next = format!(
+    let next = if dtos.len() < usize::try_from(new_limit).unwrap() {
+        format!(
             "<{}/api/v1/version/?limit={}>; rel=first",
             &pool.server_context, new_limit
-        );
+        )
     } else {
-        next = format!(
+        format!(
             "<{}/api/v1/version/?limit={}>; rel=first, <{}/api/v1/version/?page={}&limit={}>; rel=next",
             &pool.server_context, new_limit, &pool.server_context, last_id, new_limit
-        );
-    }
+        )
+    };
+
+    Ok(validators
+        .apply(&mut HttpResponse::Ok())
+        .append_header(("Link", next))
+        .json(dtos))
+}
+
+/// Creates a new Version.
+///
+/// # Arguments
+///
+/// * `body` - The Version to create.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing the created Version or an error message.
+///
+/// # Errors
+///
+/// * Returns an HTTP 400 Bad Request if the Version is invalid.
+/// * Returns an HTTP 409 Conflict if a Version with the same ID or semver already exists.
+/// * Returns an HTTP 500 Internal Server Error if there is an error creating the Version.
+#[utoipa::path(
+    post,
+    path = "/api/v1/version/",
+    tag = "Version",
+    security(
+        ("api_key" = ["versions:write"]),
+        ("bearer" = ["versions:write"]),
+    ),
+    request_body = CreateVersionDto,
+    responses(
+            (status = 201, description = "HTTP Created", body = VersionDto),
+            (status = 400, description = "HTTP Bad Request", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 422, description = "HTTP Unprocessable Content", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 409, description = "HTTP Conflict", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 401, description = "HTTP Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 403, description = "HTTP Forbidden", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[post("/", wrap = "RequireScope::new(VERSIONS_WRITE)")]
+pub async fn create_version(
+    body: web::Json<CreateVersionDto>,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
+    let version = pool
+        .services
+        .version_service
+        .create(body.into_inner().into())
+        .await
+        .map_err(|e| e.into_api_error("Error creating version"))?;
+
+    Ok(HttpResponse::Created()
+        .append_header((
+            header::LOCATION,
+            format!("{}/api/v1/version/{}", &pool.server_context, version.id),
+        ))
+        .json(VersionDto::from(version)))
+}
+
+/// Replaces an existing Version.
+///
+/// # Arguments
+///
+/// * `id` - The ID of the Version to replace.
+/// * `body` - The new state of the Version.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing the updated Version or an error message.
+///
+/// # Errors
+///
+/// * Returns an HTTP 400 Bad Request if the Version is invalid.
+/// * Returns an HTTP 404 Not Found if the Version is not found.
+/// * Returns an HTTP 409 Conflict if another Version with the same semver already exists.
+/// * Returns an HTTP 500 Internal Server Error if there is an error updating the Version.
+#[utoipa::path(
+    put,
+    path = "/api/v1/version/{id}",
+    tag = "Version",
+    security(
+        ("api_key" = ["versions:write"]),
+        ("bearer" = ["versions:write"]),
+    ),
+    params(
+        ("id" = String, Path, description = "The ID of the Version to replace", nullable = false),
+    ),
+    request_body = UpdateVersionDto,
+    responses(
+            (status = 200, description = "HTTP OK", body = VersionDto),
+            (status = 400, description = "HTTP Bad Request", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 422, description = "HTTP Unprocessable Content", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 404, description = "HTTP Not Found", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 409, description = "HTTP Conflict", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 401, description = "HTTP Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 403, description = "HTTP Forbidden", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[put("/{id}", wrap = "RequireScope::new(VERSIONS_WRITE)")]
+pub async fn update_version(
+    id: web::Path<String>,
+    body: web::Json<UpdateVersionDto>,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
+    let id = id.into_inner();
+
+    let version = pool
+        .services
+        .version_service
+        .update(body.into_inner().into_version(id.clone()))
+        .await
+        .map_err(|e| e.into_api_error(&format!("Error updating version with ID {}", id)))?;
+
+    Ok(HttpResponse::Ok().json(VersionDto::from(version)))
+}
+
+/// Deletes a Version.
+///
+/// # Arguments
+///
+/// * `id` - The ID of the Version to delete.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - An empty HTTP response or an error message.
+///
+/// # Errors
+///
+/// * Returns an HTTP 404 Not Found if the Version is not found.
+/// * Returns an HTTP 500 Internal Server Error if there is an error deleting the Version.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/version/{id}",
+    tag = "Version",
+    security(
+        ("api_key" = ["versions:write"]),
+        ("bearer" = ["versions:write"]),
+    ),
+    params(
+        ("id" = String, Path, description = "The ID of the Version to delete", nullable = false),
+    ),
+    responses(
+            (status = 204, description = "HTTP No Content"),
+            (status = 404, description = "HTTP Not Found", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 401, description = "HTTP Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 403, description = "HTTP Forbidden", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[delete("/{id}", wrap = "RequireScope::new(VERSIONS_WRITE)")]
+pub async fn delete_version(
+    id: web::Path<String>,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
+    pool.services
+        .version_service
+        .delete(&id)
+        .await
+        .map_err(|e| e.into_api_error(&format!("Error deleting version with ID {}", id)))?;
 
-    HttpResponse::Ok().append_header(("Link", next)).json(dtos)
+    Ok(HttpResponse::NoContent().finish())
 }
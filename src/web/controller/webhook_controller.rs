@@ -0,0 +1,271 @@
+use crate::config::server_config::ServerConfig;
+use crate::errors::api_error::{ApiError, ProblemDetails};
+use crate::web::dto::webhook::deliveries_query::DeliveriesQuery;
+use crate::web::dto::webhook::webhook_delivery_dto::WebhookDeliveryDto;
+use crate::web::dto::webhook::webhook_dto::{CreateWebhookDto, WebhookDto};
+use crate::web::middleware::authentication::{RequireScope, WEBHOOKS_MANAGE};
+use actix_web::http::header;
+use actix_web::{HttpResponse, delete, get, post, web};
+use log::error;
+
+/// # Summary
+///
+/// Fetches all registered webhooks.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing a list of webhooks or an error message.
+///
+/// # Errors
+///
+/// * Returns an HTTP 500 Internal Server Error if there is an error fetching the webhooks.
+#[utoipa::path(
+    get,
+    path = "/api/v1/webhooks/",
+    tag = "Webhooks",
+    security(
+        ("api_key" = ["webhooks:manage"]),
+        ("bearer" = ["webhooks:manage"]),
+    ),
+    responses(
+            (status = 200, description = "HTTP OK", body = Vec<WebhookDto>),
+            (status = 401, description = "HTTP Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 403, description = "HTTP Forbidden", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[get("/", wrap = "RequireScope::new(WEBHOOKS_MANAGE)")]
+pub async fn find_all_webhooks(pool: web::Data<ServerConfig>) -> Result<HttpResponse, ApiError> {
+    let webhooks = pool.services.webhook_service.get_all().await.map_err(|e| {
+        error!("Error fetching webhooks: {}", e);
+        ApiError::internal_server_error("Error fetching webhooks")
+    })?;
+
+    let dtos: Vec<WebhookDto> = webhooks.into_iter().map(WebhookDto::from).collect();
+    Ok(HttpResponse::Ok().json(dtos))
+}
+
+/// # Summary
+///
+/// Fetches a webhook by its ID.
+///
+/// # Arguments
+///
+/// * `id` - The ID of the webhook to fetch.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing the webhook or an error message.
+///
+/// # Errors
+///
+/// * Returns an HTTP 404 Not Found if the webhook is not found.
+/// * Returns an HTTP 500 Internal Server Error if there is an error fetching the webhook.
+#[utoipa::path(
+    get,
+    path = "/api/v1/webhooks/{id}",
+    tag = "Webhooks",
+    security(
+        ("api_key" = ["webhooks:manage"]),
+        ("bearer" = ["webhooks:manage"]),
+    ),
+    params(
+        ("id" = String, Path, description = "The ID of the webhook to fetch", nullable = false),
+    ),
+    responses(
+            (status = 200, description = "HTTP OK", body = WebhookDto),
+            (status = 404, description = "HTTP Not Found", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 401, description = "HTTP Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 403, description = "HTTP Forbidden", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[get("/{id}", wrap = "RequireScope::new(WEBHOOKS_MANAGE)")]
+pub async fn find_webhook_by_id(
+    id: web::Path<String>,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
+    let webhook = pool
+        .services
+        .webhook_service
+        .get_by_id(&id)
+        .await
+        .map_err(|e| {
+            error!("Error fetching webhook: {}", e);
+            ApiError::internal_server_error(&format!("Error fetching webhook with ID {}", id))
+        })?
+        .ok_or_else(|| ApiError::not_found(&format!("Webhook with ID {} was not found", id)))?;
+
+    Ok(HttpResponse::Ok().json(WebhookDto::from(webhook)))
+}
+
+/// # Summary
+///
+/// Fetches the most recent deliveries of a webhook, newest first.
+///
+/// # Arguments
+///
+/// * `id` - The ID of the webhook.
+/// * `query` - The maximum number of deliveries to return.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing a list of deliveries or an error message.
+///
+/// # Errors
+///
+/// * Returns an HTTP 404 Not Found if the webhook is not found.
+/// * Returns an HTTP 500 Internal Server Error if there is an error fetching the deliveries.
+#[utoipa::path(
+    get,
+    path = "/api/v1/webhooks/{id}/deliveries",
+    tag = "Webhooks",
+    security(
+        ("api_key" = ["webhooks:manage"]),
+        ("bearer" = ["webhooks:manage"]),
+    ),
+    params(
+        ("id" = String, Path, description = "The ID of the webhook", nullable = false),
+        ("limit" = Option<i64>, Query, description = "The limit of the amount of deliveries to retrieve, at least 1", nullable = true),
+    ),
+    responses(
+            (status = 200, description = "HTTP OK", body = Vec<WebhookDeliveryDto>),
+            (status = 404, description = "HTTP Not Found", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 401, description = "HTTP Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 403, description = "HTTP Forbidden", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[get("/{id}/deliveries", wrap = "RequireScope::new(WEBHOOKS_MANAGE)")]
+pub async fn find_webhook_deliveries(
+    id: web::Path<String>,
+    query: web::Query<DeliveriesQuery>,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
+    let limit = query
+        .limit
+        .unwrap_or(pool.max_fetch_limit)
+        .min(pool.max_fetch_limit)
+        .max(1);
+
+    let deliveries = pool
+        .services
+        .webhook_service
+        .deliveries(&id, Some(limit))
+        .await
+        .map_err(|e| {
+            e.into_api_error(&format!(
+                "Error fetching deliveries of webhook with ID {}",
+                id
+            ))
+        })?;
+
+    let dtos: Vec<WebhookDeliveryDto> = deliveries
+        .into_iter()
+        .map(WebhookDeliveryDto::from)
+        .collect();
+    Ok(HttpResponse::Ok().json(dtos))
+}
+
+/// # Summary
+///
+/// Registers a new webhook that is notified when a release is published.
+///
+/// # Arguments
+///
+/// * `body` - The webhook to register.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - The HTTP response containing the registered webhook, including its secret, or an error message.
+///
+/// # Errors
+///
+/// * Returns an HTTP 400 Bad Request if the webhook is invalid.
+/// * Returns an HTTP 500 Internal Server Error if there is an error registering the webhook.
+#[utoipa::path(
+    post,
+    path = "/api/v1/webhooks/",
+    tag = "Webhooks",
+    security(
+        ("api_key" = ["webhooks:manage"]),
+        ("bearer" = ["webhooks:manage"]),
+    ),
+    request_body = CreateWebhookDto,
+    responses(
+            (status = 201, description = "HTTP Created", body = WebhookDto),
+            (status = 400, description = "HTTP Bad Request", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 422, description = "HTTP Unprocessable Content", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 401, description = "HTTP Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 403, description = "HTTP Forbidden", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[post("/", wrap = "RequireScope::new(WEBHOOKS_MANAGE)")]
+pub async fn create_webhook(
+    body: web::Json<CreateWebhookDto>,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
+    let webhook = pool
+        .services
+        .webhook_service
+        .register(body.into_inner().into())
+        .await
+        .map_err(|e| e.into_api_error("Error registering webhook"))?;
+
+    Ok(HttpResponse::Created()
+        .append_header((
+            header::LOCATION,
+            format!("{}/api/v1/webhooks/{}", &pool.server_context, webhook.id),
+        ))
+        .json(WebhookDto::with_secret(webhook)))
+}
+
+/// # Summary
+///
+/// Deletes a webhook. Its pending deliveries are not sent anymore.
+///
+/// # Arguments
+///
+/// * `id` - The ID of the webhook to delete.
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - An empty HTTP response or an error message.
+///
+/// # Errors
+///
+/// * Returns an HTTP 404 Not Found if the webhook is not found.
+/// * Returns an HTTP 500 Internal Server Error if there is an error deleting the webhook.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/webhooks/{id}",
+    tag = "Webhooks",
+    security(
+        ("api_key" = ["webhooks:manage"]),
+        ("bearer" = ["webhooks:manage"]),
+    ),
+    params(
+        ("id" = String, Path, description = "The ID of the webhook to delete", nullable = false),
+    ),
+    responses(
+            (status = 204, description = "HTTP No Content"),
+            (status = 404, description = "HTTP Not Found", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 401, description = "HTTP Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 403, description = "HTTP Forbidden", body = ProblemDetails, content_type = "application/problem+json"),
+            (status = 500, description = "HTTP Internal Server Error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+)]
+#[delete("/{id}", wrap = "RequireScope::new(WEBHOOKS_MANAGE)")]
+pub async fn delete_webhook(
+    id: web::Path<String>,
+    pool: web::Data<ServerConfig>,
+) -> Result<HttpResponse, ApiError> {
+    pool.services
+        .webhook_service
+        .delete(&id)
+        .await
+        .map_err(|e| e.into_api_error(&format!("Error deleting webhook with ID {}", id)))?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
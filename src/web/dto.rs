@@ -1,2 +1,7 @@
 pub(crate) mod application;
+pub(crate) mod event;
+pub(crate) mod health;
+pub(crate) mod info;
+pub(crate) mod package;
 pub(crate) mod version;
+pub(crate) mod webhook;
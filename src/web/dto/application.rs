@@ -1,2 +1,8 @@
+pub(crate) mod appcast_query;
 pub(crate) mod application_dto;
+pub(crate) mod application_request_dto;
 pub(crate) mod applications_query;
+pub(crate) mod channel_query;
+pub(crate) mod download_query;
+pub(crate) mod latest_query;
+pub(crate) mod update_query;
@@ -1,12 +1,18 @@
 use crate::repository::application::model::application::{
     Application, ApplicationPlatform, Architecture, Release, ReleaseType,
 };
+use crate::repository::application::model::release_channel::ReleaseChannel;
+use crate::repository::semver::SemVer;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 #[derive(Serialize, Deserialize, Clone, ToSchema)]
 pub struct ApplicationDto {
     pub id: String,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: String,
     pub name: String,
     pub description: Option<String>,
     pub platforms: Option<Vec<ApplicationPlatformDto>>,
@@ -35,12 +41,19 @@ pub struct ReleaseDto {
     pub release_date: Option<String>,
     #[serde(rename = "releaseType")]
     pub release_type: Option<ReleaseTypeDto>,
-    pub semver: String,
+    #[schema(value_type = Option<String>, example = "stable")]
+    pub channel: Option<ReleaseChannel>,
+    #[schema(value_type = String, example = "1.2.3")]
+    pub semver: SemVer,
     #[serde(rename = "downloadUrl")]
     pub download_url: String,
     #[serde(rename = "infoUrl")]
     pub info_url: Option<String>,
     pub checksum: Option<String>,
+    /// The size of the download in bytes
+    pub size: Option<u64>,
+    /// The base64-encoded Ed25519 signature of the download
+    pub signature: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, ToSchema)]
@@ -56,6 +69,8 @@ impl From<Application> for ApplicationDto {
     fn from(application: Application) -> Self {
         ApplicationDto {
             id: application.id,
+            created_at: application.created_at,
+            updated_at: application.updated_at,
             name: application.name,
             description: application.description,
             platforms: application.platforms.map(|platforms| {
@@ -96,6 +111,7 @@ impl From<Architecture> for ArchitectureDto {
 
 impl From<Release> for ReleaseDto {
     fn from(release: Release) -> Self {
+        let channel = Some(release.effective_channel());
         let release_type = release.release_type.map(ReleaseTypeDto::from);
 
         ReleaseDto {
@@ -104,10 +120,13 @@ impl From<Release> for ReleaseDto {
             portable: release.portable,
             release_date: release.release_date,
             release_type,
+            channel,
             semver: release.semver,
             download_url: release.download_url,
             info_url: release.info_url,
             checksum: release.checksum,
+            size: release.size,
+            signature: release.signature,
         }
     }
 }
@@ -123,3 +142,57 @@ impl From<ReleaseType> for ReleaseTypeDto {
         }
     }
 }
+
+impl From<ApplicationPlatformDto> for ApplicationPlatform {
+    fn from(platform: ApplicationPlatformDto) -> Self {
+        ApplicationPlatform {
+            platform_name: platform.platform_name,
+            architectures: platform
+                .architectures
+                .map(|architectures| architectures.into_iter().map(Architecture::from).collect()),
+        }
+    }
+}
+
+impl From<ArchitectureDto> for Architecture {
+    fn from(architecture: ArchitectureDto) -> Self {
+        Architecture {
+            name: architecture.name,
+            url: architecture.url,
+            releases: architecture
+                .releases
+                .map(|releases| releases.into_iter().map(Release::from).collect()),
+        }
+    }
+}
+
+impl From<ReleaseDto> for Release {
+    fn from(release: ReleaseDto) -> Self {
+        Release {
+            name: release.name,
+            description: release.description,
+            portable: release.portable,
+            release_date: release.release_date,
+            release_type: release.release_type.map(ReleaseType::from),
+            channel: release.channel,
+            semver: release.semver,
+            download_url: release.download_url,
+            info_url: release.info_url,
+            checksum: release.checksum,
+            size: release.size,
+            signature: release.signature,
+        }
+    }
+}
+
+impl From<ReleaseTypeDto> for ReleaseType {
+    fn from(release_type: ReleaseTypeDto) -> Self {
+        match release_type {
+            ReleaseTypeDto::Major => ReleaseType::Major,
+            ReleaseTypeDto::Minor => ReleaseType::Minor,
+            ReleaseTypeDto::Patch => ReleaseType::Patch,
+            ReleaseTypeDto::PreRelease => ReleaseType::PreRelease,
+            ReleaseTypeDto::Other => ReleaseType::Other,
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::repository::application::model::application::{Application, ApplicationPlatform};
+use crate::repository::application::model::application_changes::ApplicationChanges;
+use crate::web::dto::application::application_dto::ApplicationPlatformDto;
+use serde::{Deserialize, Deserializer};
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct CreateApplicationDto {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub platforms: Option<Vec<ApplicationPlatformDto>>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct UpdateApplicationDto {
+    pub name: String,
+    pub description: Option<String>,
+    pub platforms: Option<Vec<ApplicationPlatformDto>>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct PatchApplicationDto {
+    pub name: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_present")]
+    #[schema(value_type = Option<String>)]
+    pub description: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_present")]
+    #[schema(value_type = Option<Vec<ApplicationPlatformDto>>)]
+    pub platforms: Option<Option<Vec<ApplicationPlatformDto>>>,
+}
+
+/// Deserialize a field that is present in the payload, so that an explicit `null` can be told
+/// apart from a missing field
+fn deserialize_present<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+/// Convert a list of platform DTOs into their model counterparts
+fn to_platforms(platforms: Vec<ApplicationPlatformDto>) -> Vec<ApplicationPlatform> {
+    platforms
+        .into_iter()
+        .map(ApplicationPlatform::from)
+        .collect()
+}
+
+impl From<CreateApplicationDto> for Application {
+    fn from(dto: CreateApplicationDto) -> Self {
+        Application {
+            id: dto.id,
+            created_at: String::new(),
+            updated_at: String::new(),
+            name: dto.name,
+            description: dto.description,
+            platforms: dto.platforms.map(to_platforms),
+        }
+    }
+}
+
+impl From<UpdateApplicationDto> for ApplicationChanges {
+    fn from(dto: UpdateApplicationDto) -> Self {
+        ApplicationChanges {
+            name: Some(dto.name),
+            description: Some(dto.description),
+            platforms: Some(dto.platforms.map(to_platforms)),
+        }
+    }
+}
+
+impl From<PatchApplicationDto> for ApplicationChanges {
+    fn from(dto: PatchApplicationDto) -> Self {
+        ApplicationChanges {
+            name: dto.name,
+            description: dto.description,
+            platforms: dto.platforms.map(|p| p.map(to_platforms)),
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use crate::repository::application::model::release_channel::ReleaseChannel;
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct DownloadQuery {
+    pub platform: String,
+    pub arch: Option<String>,
+    pub prerelease: Option<bool>,
+    #[schema(value_type = Option<String>)]
+    pub channel: Option<ReleaseChannel>,
+}
@@ -0,0 +1,10 @@
+use crate::repository::application::model::release_channel::ReleaseChannel;
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct LatestQuery {
+    pub prerelease: Option<bool>,
+    #[schema(value_type = Option<String>)]
+    pub channel: Option<ReleaseChannel>,
+}
@@ -0,0 +1,12 @@
+use crate::repository::application::model::release_channel::ReleaseChannel;
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct UpdateQuery {
+    pub current: String,
+    pub platform: String,
+    pub arch: String,
+    #[schema(value_type = Option<String>)]
+    pub channel: Option<ReleaseChannel>,
+}
@@ -0,0 +1,37 @@
+use crate::services::event::event_service::ApplicationEvent;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// The data of a Server-Sent Event that describes a change to an Application
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct ApplicationEventDto {
+    #[schema(example = "1760779800000-7")]
+    pub id: String,
+    #[serde(rename = "type")]
+    #[schema(example = "release-published")]
+    pub event_type: String,
+    #[serde(rename = "applicationId")]
+    pub application_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub platform: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub architecture: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub semver: Option<String>,
+    #[serde(rename = "occurredAt")]
+    pub occurred_at: String,
+}
+
+impl From<&ApplicationEvent> for ApplicationEventDto {
+    fn from(event: &ApplicationEvent) -> Self {
+        ApplicationEventDto {
+            id: event.id.to_string(),
+            event_type: event.kind.to_string(),
+            application_id: event.application_id.clone(),
+            platform: event.release.as_ref().map(|r| r.platform.clone()),
+            architecture: event.release.as_ref().map(|r| r.architecture.clone()),
+            semver: event.release.as_ref().map(|r| r.semver.clone()),
+            occurred_at: event.occurred_at.clone(),
+        }
+    }
+}
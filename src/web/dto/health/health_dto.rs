@@ -0,0 +1,85 @@
+use crate::services::health::health_service::{ComponentHealth, HealthStatus, Readiness};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum HealthStatusDto {
+    Up,
+    Down,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct HealthDto {
+    pub status: HealthStatusDto,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<BTreeMap<String, ComponentHealthDto>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct ComponentHealthDto {
+    pub status: HealthStatusDto,
+    #[serde(rename = "latencyMs")]
+    #[schema(example = 1.25)]
+    pub latency_ms: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+}
+
+impl HealthDto {
+    /// Build the HealthDto of a process that is alive
+    ///
+    /// # Returns
+    ///
+    /// A HealthDto with status UP and no components
+    pub fn up() -> Self {
+        HealthDto {
+            status: HealthStatusDto::Up,
+            components: None,
+        }
+    }
+}
+
+impl From<HealthStatus> for HealthStatusDto {
+    fn from(status: HealthStatus) -> Self {
+        match status {
+            HealthStatus::Up => HealthStatusDto::Up,
+            HealthStatus::Down => HealthStatusDto::Down,
+        }
+    }
+}
+
+impl From<ComponentHealth> for ComponentHealthDto {
+    fn from(component: ComponentHealth) -> Self {
+        ComponentHealthDto {
+            status: component.status.into(),
+            latency_ms: component.latency.as_secs_f64() * 1000.0,
+            details: component.details,
+        }
+    }
+}
+
+impl From<Readiness> for HealthDto {
+    /// Converts the Readiness of the API to a HealthDto for API responses.
+    ///
+    /// # Arguments
+    ///
+    /// * `readiness` - The outcome of the readiness checks.
+    ///
+    /// # Returns
+    ///
+    /// A HealthDto with the overall status and the status of every component.
+    fn from(readiness: Readiness) -> Self {
+        HealthDto {
+            status: readiness.status().into(),
+            components: Some(
+                readiness
+                    .components
+                    .into_iter()
+                    .map(|c| (c.name.clone(), ComponentHealthDto::from(c)))
+                    .collect(),
+            ),
+        }
+    }
+}
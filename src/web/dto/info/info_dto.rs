@@ -0,0 +1,107 @@
+use crate::config::server_config::ServerConfig;
+use crate::services::info::info_service::BuildInfo;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use utoipa::ToSchema;
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct InfoDto {
+    pub build: BuildInfoDto,
+    pub runtime: RuntimeInfoDto,
+    pub config: ConfigInfoDto,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct BuildInfoDto {
+    #[schema(example = "0.1.0")]
+    pub version: String,
+    #[serde(rename = "gitCommit")]
+    pub git_commit: String,
+    #[serde(rename = "buildTimestamp")]
+    pub build_timestamp: Option<String>,
+    #[serde(rename = "rustcVersion")]
+    #[schema(example = "rustc 1.97.1 (59807616e 2026-09-10)")]
+    pub rustc_version: String,
+    pub features: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct RuntimeInfoDto {
+    #[serde(rename = "startedAt")]
+    pub started_at: String,
+    #[serde(rename = "uptimeSeconds")]
+    pub uptime_seconds: u64,
+}
+
+/// The effective configuration of the server, which never includes secrets
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct ConfigInfoDto {
+    pub host: String,
+    pub port: u16,
+    #[serde(rename = "maxFetchLimit")]
+    pub max_fetch_limit: i64,
+    pub workers: i64,
+    #[serde(rename = "serverContext")]
+    pub server_context: String,
+    #[serde(rename = "corsAllowedOrigins")]
+    pub cors_allowed_origins: Vec<String>,
+    #[serde(rename = "jwtEnabled")]
+    pub jwt_enabled: bool,
+}
+
+impl From<BuildInfo> for BuildInfoDto {
+    fn from(build: BuildInfo) -> Self {
+        BuildInfoDto {
+            version: build.version.to_string(),
+            git_commit: build.git_commit.to_string(),
+            build_timestamp: build.build_timestamp.map(|t| t.to_rfc3339()),
+            rustc_version: build.rustc_version.to_string(),
+            features: build.features.into_iter().map(String::from).collect(),
+        }
+    }
+}
+
+impl RuntimeInfoDto {
+    /// Build the RuntimeInfoDto of the running process
+    ///
+    /// # Arguments
+    ///
+    /// * `started_at` - The moment the process was started
+    /// * `uptime` - The time that passed since the process was started
+    ///
+    /// # Returns
+    ///
+    /// A RuntimeInfoDto with the start time and the uptime in whole seconds
+    pub fn new(started_at: DateTime<Utc>, uptime: Duration) -> Self {
+        RuntimeInfoDto {
+            started_at: started_at.to_rfc3339(),
+            uptime_seconds: uptime.as_secs(),
+        }
+    }
+}
+
+impl From<&ServerConfig> for ConfigInfoDto {
+    /// Converts the ServerConfig to a ConfigInfoDto for API responses.
+    ///
+    /// Only settings that are safe to disclose are copied; credentials and verification keys are left out.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The effective ServerConfig.
+    ///
+    /// # Returns
+    ///
+    /// A ConfigInfoDto instance containing the disclosable settings.
+    fn from(config: &ServerConfig) -> Self {
+        ConfigInfoDto {
+            host: config.host.clone(),
+            port: config.port,
+            max_fetch_limit: config.max_fetch_limit,
+            workers: config.workers,
+            server_context: config.server_context.clone(),
+            cors_allowed_origins: config.cors_allowed_origins.clone(),
+            jwt_enabled: config.services.jwt_service.is_enabled(),
+        }
+    }
+}
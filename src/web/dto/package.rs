@@ -0,0 +1,5 @@
+pub(crate) mod homebrew_query;
+pub(crate) mod scoop_manifest_dto;
+pub(crate) mod scoop_query;
+pub(crate) mod winget_dto;
+pub(crate) mod winget_query;
@@ -0,0 +1,11 @@
+use crate::repository::application::model::release_channel::ReleaseChannel;
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct HomebrewQuery {
+    pub token: Option<String>,
+    pub app: Option<String>,
+    #[schema(value_type = Option<String>)]
+    pub channel: Option<ReleaseChannel>,
+}
@@ -0,0 +1,29 @@
+use serde::Serialize;
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+pub struct ScoopManifestDto {
+    pub version: String,
+    pub description: String,
+    pub homepage: String,
+    pub license: String,
+    /// The download of an architecture-neutral release
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bin: Option<String>,
+    /// The downloads per Scoop architecture, which are `64bit`, `32bit` and `arm64`
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub architecture: BTreeMap<String, ScoopArchitectureDto>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ScoopArchitectureDto {
+    pub url: String,
+    pub hash: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bin: Option<String>,
+}
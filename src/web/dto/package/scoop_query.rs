@@ -0,0 +1,10 @@
+use crate::repository::application::model::release_channel::ReleaseChannel;
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct ScoopQuery {
+    pub license: Option<String>,
+    #[schema(value_type = Option<String>)]
+    pub channel: Option<ReleaseChannel>,
+}
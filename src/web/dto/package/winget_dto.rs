@@ -0,0 +1,41 @@
+use crate::web::winget::{WingetManifest, WingetPackage};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+pub struct WingetPackageDto {
+    #[serde(rename = "packageIdentifier")]
+    pub package_identifier: String,
+    #[serde(rename = "packageVersion")]
+    pub package_version: String,
+    /// The directory of the manifests in the winget-pkgs repository
+    pub path: String,
+    pub manifests: Vec<WingetManifestDto>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct WingetManifestDto {
+    #[serde(rename = "fileName")]
+    pub file_name: String,
+    pub content: String,
+}
+
+impl From<WingetManifest> for WingetManifestDto {
+    fn from(manifest: WingetManifest) -> Self {
+        WingetManifestDto {
+            file_name: manifest.file_name,
+            content: manifest.content,
+        }
+    }
+}
+
+impl From<WingetPackage<'_>> for WingetPackageDto {
+    fn from(package: WingetPackage<'_>) -> Self {
+        WingetPackageDto {
+            package_identifier: package.package_identifier().to_string(),
+            package_version: package.package_version(),
+            path: package.path(),
+            manifests: package.render().into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use crate::repository::application::model::release_channel::ReleaseChannel;
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct WingetQuery {
+    pub version: Option<String>,
+    #[serde(rename = "packageIdentifier")]
+    pub package_identifier: Option<String>,
+    pub publisher: Option<String>,
+    pub license: Option<String>,
+    #[schema(value_type = Option<String>)]
+    pub channel: Option<ReleaseChannel>,
+}
@@ -1 +1,2 @@
 pub(crate) mod version_dto;
+pub(crate) mod version_request_dto;
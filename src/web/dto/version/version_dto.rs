@@ -1,3 +1,4 @@
+use crate::repository::semver::SemVer;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -10,7 +11,8 @@ pub struct VersionDto {
     pub updated_at: String,
     pub name: Option<String>,
     pub description: Option<String>,
-    pub semver: String,
+    #[schema(value_type = String, example = "1.2.3")]
+    pub semver: SemVer,
     pub platforms: Vec<PlatformDto>,
 }
 
@@ -57,3 +59,24 @@ impl From<crate::repository::v2::model::version::Version> for VersionDto {
         }
     }
 }
+
+impl From<PlatformDto> for crate::repository::v2::model::version::Platform {
+    /// Converts a PlatformDto from an API request to a Platform of the repository model.
+    ///
+    /// # Arguments
+    ///
+    /// * `platform` - The PlatformDto instance to convert.
+    ///
+    /// # Returns
+    ///
+    /// A Platform instance containing the data from the provided PlatformDto.
+    fn from(platform: PlatformDto) -> Self {
+        crate::repository::v2::model::version::Platform {
+            name: platform.name,
+            arch: platform.arch,
+            portable: platform.portable,
+            download_url: platform.download_url,
+            info_url: platform.info_url,
+        }
+    }
+}
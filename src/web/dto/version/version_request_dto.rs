@@ -0,0 +1,61 @@
+use crate::repository::semver::SemVer;
+use crate::repository::v2::model::version::{Platform, Version};
+use crate::web::dto::version::version_dto::PlatformDto;
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct CreateVersionDto {
+    pub id: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    #[schema(value_type = String, example = "1.2.3")]
+    pub semver: SemVer,
+    pub platforms: Vec<PlatformDto>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct UpdateVersionDto {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    #[schema(value_type = String, example = "1.2.3")]
+    pub semver: SemVer,
+    pub platforms: Vec<PlatformDto>,
+}
+
+impl From<CreateVersionDto> for Version {
+    fn from(dto: CreateVersionDto) -> Self {
+        Version {
+            id: dto.id,
+            created_at: String::new(),
+            updated_at: String::new(),
+            name: dto.name,
+            description: dto.description,
+            semver: dto.semver,
+            platforms: dto.platforms.into_iter().map(Platform::from).collect(),
+        }
+    }
+}
+
+impl UpdateVersionDto {
+    /// Converts the UpdateVersionDto into a Version with the provided ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the Version that is being updated.
+    ///
+    /// # Returns
+    ///
+    /// A Version instance containing the data from the UpdateVersionDto.
+    pub fn into_version(self, id: String) -> Version {
+        Version {
+            id,
+            created_at: String::new(),
+            updated_at: String::new(),
+            name: self.name,
+            description: self.description,
+            semver: self.semver,
+            platforms: self.platforms.into_iter().map(Platform::from).collect(),
+        }
+    }
+}
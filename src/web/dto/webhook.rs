@@ -0,0 +1,3 @@
+pub(crate) mod deliveries_query;
+pub(crate) mod webhook_delivery_dto;
+pub(crate) mod webhook_dto;
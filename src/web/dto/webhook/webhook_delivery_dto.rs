@@ -0,0 +1,68 @@
+use crate::repository::webhook::model::webhook_delivery::{DeliveryStatus, WebhookDelivery};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DeliveryStatusDto {
+    Pending,
+    Delivered,
+    Failed,
+}
+
+#[derive(Serialize, Deserialize, Clone, ToSchema)]
+pub struct WebhookDeliveryDto {
+    pub id: String,
+    #[serde(rename = "webhookId")]
+    pub webhook_id: String,
+    #[schema(example = "release-published")]
+    pub event: String,
+    /// The JSON body that is signed and sent
+    pub payload: String,
+    pub status: DeliveryStatusDto,
+    pub attempts: u32,
+    /// The time of the next attempt, which only applies to pending deliveries
+    #[serde(rename = "nextAttemptAt")]
+    pub next_attempt_at: Option<String>,
+    #[serde(rename = "lastStatusCode")]
+    pub last_status_code: Option<u16>,
+    #[serde(rename = "lastError")]
+    pub last_error: Option<String>,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: String,
+}
+
+impl From<DeliveryStatus> for DeliveryStatusDto {
+    fn from(status: DeliveryStatus) -> Self {
+        match status {
+            DeliveryStatus::Pending => DeliveryStatusDto::Pending,
+            DeliveryStatus::Delivered => DeliveryStatusDto::Delivered,
+            DeliveryStatus::Failed => DeliveryStatusDto::Failed,
+        }
+    }
+}
+
+impl From<WebhookDelivery> for WebhookDeliveryDto {
+    fn from(delivery: WebhookDelivery) -> Self {
+        let next_attempt_at = match delivery.status {
+            DeliveryStatus::Pending => Some(delivery.next_attempt_at),
+            _ => None,
+        };
+
+        WebhookDeliveryDto {
+            id: delivery.id,
+            webhook_id: delivery.webhook_id,
+            event: delivery.event,
+            payload: delivery.payload,
+            status: delivery.status.into(),
+            attempts: delivery.attempts,
+            next_attempt_at,
+            last_status_code: delivery.last_status_code,
+            last_error: delivery.last_error,
+            created_at: delivery.created_at,
+            updated_at: delivery.updated_at,
+        }
+    }
+}
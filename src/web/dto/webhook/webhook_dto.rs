@@ -0,0 +1,77 @@
+use crate::repository::application::model::release_channel::ReleaseChannel;
+use crate::repository::webhook::model::webhook::Webhook;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Serialize, Deserialize, Clone, ToSchema)]
+pub struct WebhookDto {
+    pub id: String,
+    pub url: String,
+    /// The secret with which payloads are signed, which is only returned on registration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    #[serde(rename = "applicationId")]
+    pub application_id: Option<String>,
+    #[schema(value_type = Option<String>, example = "stable")]
+    pub channel: Option<ReleaseChannel>,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct CreateWebhookDto {
+    pub url: String,
+    /// The secret with which payloads are signed, which is generated if it is omitted
+    pub secret: Option<String>,
+    /// The ID of the only application to be notified about
+    #[serde(rename = "applicationId")]
+    pub application_id: Option<String>,
+    /// The release channel whose visible releases to be notified about
+    #[schema(value_type = Option<String>, example = "stable")]
+    pub channel: Option<ReleaseChannel>,
+}
+
+impl WebhookDto {
+    /// Convert a Webhook into a WebhookDto that includes its secret
+    ///
+    /// # Arguments
+    ///
+    /// * `webhook` - The Webhook that was registered
+    ///
+    /// # Returns
+    ///
+    /// A WebhookDto with the secret of the Webhook
+    pub fn with_secret(webhook: Webhook) -> Self {
+        let secret = webhook.secret.clone();
+        WebhookDto {
+            secret: Some(secret),
+            ..WebhookDto::from(webhook)
+        }
+    }
+}
+
+impl From<Webhook> for WebhookDto {
+    fn from(webhook: Webhook) -> Self {
+        WebhookDto {
+            id: webhook.id,
+            url: webhook.url,
+            secret: None,
+            application_id: webhook.application_id,
+            channel: webhook.channel,
+            created_at: webhook.created_at,
+        }
+    }
+}
+
+impl From<CreateWebhookDto> for Webhook {
+    fn from(dto: CreateWebhookDto) -> Self {
+        Webhook {
+            id: String::new(),
+            url: dto.url,
+            secret: dto.secret.unwrap_or_default(),
+            application_id: dto.application_id,
+            channel: dto.channel,
+            created_at: String::new(),
+        }
+    }
+}
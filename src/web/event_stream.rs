@@ -0,0 +1,96 @@
+use crate::errors::api_error::ApiError;
+use crate::services::event::event_service::{ApplicationEvent, EventId, EventService};
+use crate::web::dto::event::event_dto::ApplicationEventDto;
+use actix_web::http::header;
+use actix_web::rt::time::{Instant, interval_at};
+use actix_web::web::Bytes;
+use actix_web::{HttpRequest, HttpResponse};
+use futures::{StreamExt, future, stream};
+use std::convert::Infallible;
+
+/// The name of the header with which clients resume an event stream
+const LAST_EVENT_ID: &str = "Last-Event-ID";
+/// The number of seconds after which clients may reconnect when too many streams are open
+const RETRY_AFTER_SECONDS: u64 = 30;
+
+/// Encode an ApplicationEvent as a Server-Sent Event
+///
+/// # Arguments
+///
+/// * `event` - The ApplicationEvent to encode
+///
+/// # Returns
+///
+/// The `id`, `event` and `data` fields of the event, terminated by a blank line
+fn encode(event: &ApplicationEvent) -> Bytes {
+    let data = serde_json::to_string(&ApplicationEventDto::from(event))
+        .expect("ApplicationEventDto can always be serialized");
+
+    Bytes::from(format!(
+        "id: {}\nevent: {}\ndata: {}\n\n",
+        event.id, event.kind, data
+    ))
+}
+
+/// Respond with a stream of Server-Sent Events
+///
+/// Clients that send a `Last-Event-ID` header first receive the retained events they missed.
+/// A heartbeat comment is sent at the interval of the EventService to keep idle connections
+/// open. The stream ends when the client falls too far behind, after which it can resume.
+///
+/// # Arguments
+///
+/// * `req` - The HTTP request
+/// * `event_service` - The `EventService` to subscribe to
+/// * `filter` - The function that selects the events to send
+///
+/// # Returns
+///
+/// * `Result<HttpResponse, ApiError>` - An HTTP 200 OK response with the `text/event-stream` content type.
+///
+/// # Errors
+///
+/// * Returns an HTTP 429 Too Many Requests if the maximum number of event streams is open.
+pub fn event_stream(
+    req: &HttpRequest,
+    event_service: &EventService,
+    filter: impl Fn(&ApplicationEvent) -> bool + 'static,
+) -> Result<HttpResponse, ApiError> {
+    // Malformed identifiers are ignored rather than rejected, because browsers resend whatever
+    // identifier they received last. Identifiers of a previous run of the server are accepted,
+    // but do not replay any events, because their sequence numbers do not match this run.
+    let last_event_id = req
+        .headers()
+        .get(LAST_EVENT_ID)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<EventId>().ok());
+
+    let subscription = event_service.subscribe(last_event_id).ok_or_else(|| {
+        ApiError::too_many_requests("The maximum number of event streams is open")
+            .with_retry_after(RETRY_AFTER_SECONDS)
+    })?;
+    let events = stream::iter(subscription.missed)
+        .chain(subscription.receiver)
+        .filter(move |e| future::ready(filter(e)))
+        .map(|e| Some(encode(&e)))
+        .chain(stream::once(future::ready(None)));
+
+    let period = event_service.heartbeat_interval();
+    let heartbeats = stream::unfold(
+        interval_at(Instant::now() + period, period),
+        |mut i| async {
+            i.tick().await;
+            Some((Some(Bytes::from_static(b": heartbeat\n\n")), i))
+        },
+    );
+
+    let body = stream::select(events, heartbeats)
+        .take_while(|frame| future::ready(frame.is_some()))
+        .map(|frame| Ok::<_, Infallible>(frame.unwrap_or_default()));
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header((header::CACHE_CONTROL, "no-cache"))
+        .insert_header(("X-Accel-Buffering", "no"))
+        .streaming(body))
+}
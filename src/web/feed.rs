@@ -0,0 +1,337 @@
+use crate::repository::application::model::application::{Application, Release};
+use crate::repository::application::model::release_channel::ReleaseChannel;
+use crate::web::xml::escape;
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
+use std::fmt::Write;
+
+/// The maximum number of entries in a feed
+const FEED_SIZE: usize = 50;
+/// The media type that is announced for downloads, which can be any kind of installer or archive
+pub const DOWNLOAD_TYPE: &str = "application/octet-stream";
+
+/// The syndication format of a feed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedFormat {
+    Atom,
+    Rss,
+}
+
+impl FeedFormat {
+    /// Get the media type of the format
+    ///
+    /// # Returns
+    ///
+    /// The value of the `Content-Type` header
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            FeedFormat::Atom => "application/atom+xml; charset=utf-8",
+            FeedFormat::Rss => "application/rss+xml; charset=utf-8",
+        }
+    }
+}
+
+/// A Release of an Application on a single platform and architecture
+struct FeedEntry<'a> {
+    application: &'a Application,
+    platform: &'a str,
+    architecture: &'a str,
+    release: &'a Release,
+    published: DateTime<Utc>,
+}
+
+/// A feed of the most recent Releases of one or more Applications
+pub struct Feed<'a> {
+    title: String,
+    description: String,
+    /// The URL of the feed itself, which doubles as its ID
+    self_url: String,
+    /// The URL of the Applications the feed describes
+    link: String,
+    /// The base URL context, used to build the IDs of the entries
+    server_context: String,
+    updated: DateTime<Utc>,
+    entries: Vec<FeedEntry<'a>>,
+}
+
+/// Parse a timestamp of an Application or Release
+///
+/// Release dates may be plain dates, which are taken to be midnight UTC.
+///
+/// # Arguments
+///
+/// * `value` - An RFC 3339 timestamp or a date in the `YYYY-MM-DD` format
+///
+/// # Returns
+///
+/// An Option containing the point in time, or None if the value could not be parsed
+pub fn parse_time(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|t| t.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|d| d.and_hms_opt(0, 0, 0))
+                .map(|t| t.and_utc())
+        })
+}
+
+impl<'a> Feed<'a> {
+    /// Build a feed from the Releases of Applications
+    ///
+    /// Every Release of every architecture becomes an entry, newest first. Releases without a
+    /// release date are dated at the last modification of their Application.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The title of the feed
+    /// * `description` - The description of the feed
+    /// * `server_context` - The base URL context where the server runs
+    /// * `self_path` - The path of the feed, relative to the server context
+    /// * `link_path` - The path of the Applications the feed describes, relative to the server context
+    /// * `applications` - The Applications whose Releases to include
+    /// * `channel` - The release channel to restrict the Releases to, or None for every Release
+    ///
+    /// # Returns
+    ///
+    /// A new Feed with at most 50 entries
+    pub fn new(
+        title: &str,
+        description: &str,
+        server_context: &str,
+        self_path: &str,
+        link_path: &str,
+        applications: &'a [Application],
+        channel: Option<&ReleaseChannel>,
+    ) -> Self {
+        let mut entries: Vec<FeedEntry> = vec![];
+        for application in applications {
+            let modified = parse_time(&application.updated_at).unwrap_or(DateTime::UNIX_EPOCH);
+            for platform in application.platforms.iter().flatten() {
+                for architecture in platform.architectures.iter().flatten() {
+                    for release in architecture.releases.iter().flatten() {
+                        if channel.is_some_and(|c| !c.includes(&release.effective_channel())) {
+                            continue;
+                        }
+
+                        entries.push(FeedEntry {
+                            application,
+                            platform: &platform.platform_name,
+                            architecture: &architecture.name,
+                            release,
+                            published: release
+                                .release_date
+                                .as_deref()
+                                .and_then(parse_time)
+                                .unwrap_or(modified),
+                        });
+                    }
+                }
+            }
+        }
+
+        entries.sort_by(|a, b| {
+            b.published
+                .cmp(&a.published)
+                .then_with(|| b.release.semver.cmp(&a.release.semver))
+        });
+        entries.truncate(FEED_SIZE);
+
+        // Renaming an Application changes the titles of its entries, so that counts as an update
+        let updated = applications
+            .iter()
+            .filter_map(|a| parse_time(&a.updated_at))
+            .chain(entries.iter().map(|e| e.published))
+            .max()
+            .unwrap_or(DateTime::UNIX_EPOCH);
+
+        Feed {
+            title: title.to_string(),
+            description: description.to_string(),
+            self_url: format!("{}{}", server_context, self_path),
+            link: format!("{}{}", server_context, link_path),
+            server_context: server_context.to_string(),
+            updated,
+            entries,
+        }
+    }
+
+    /// Render the feed in a syndication format
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The format to render
+    ///
+    /// # Returns
+    ///
+    /// The XML document
+    pub fn render(&self, format: FeedFormat) -> String {
+        match format {
+            FeedFormat::Atom => self.to_atom(),
+            FeedFormat::Rss => self.to_rss(),
+        }
+    }
+
+    /// Render the feed as an Atom 1.0 document
+    fn to_atom(&self) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+        xml.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+        let _ = writeln!(xml, "  <id>{}</id>", escape(&self.self_url));
+        let _ = writeln!(xml, "  <title>{}</title>", escape(&self.title));
+        let _ = writeln!(xml, "  <subtitle>{}</subtitle>", escape(&self.description));
+        let _ = writeln!(xml, "  <updated>{}</updated>", atom_time(&self.updated));
+        let _ = writeln!(
+            xml,
+            "  <link rel=\"self\" type=\"application/atom+xml\" href=\"{}\"/>",
+            escape(&self.self_url)
+        );
+        let _ = writeln!(
+            xml,
+            "  <link rel=\"alternate\" href=\"{}\"/>",
+            escape(&self.link)
+        );
+        xml.push_str("  <author><name>CodeDead</name></author>\n");
+        xml.push_str("  <generator>CodeDead API</generator>\n");
+
+        for entry in &self.entries {
+            let release = entry.release;
+            xml.push_str("  <entry>\n");
+            let _ = writeln!(xml, "    <id>{}</id>", escape(&self.entry_id(entry)));
+            let _ = writeln!(xml, "    <title>{}</title>", escape(&entry_title(entry)));
+            let _ = writeln!(
+                xml,
+                "    <published>{}</published>",
+                atom_time(&entry.published)
+            );
+            let _ = writeln!(
+                xml,
+                "    <updated>{}</updated>",
+                atom_time(&entry.published)
+            );
+            let _ = writeln!(
+                xml,
+                "    <link rel=\"alternate\" href=\"{}\"/>",
+                escape(release.info_url.as_ref().unwrap_or(&release.download_url))
+            );
+            let length = release
+                .size
+                .map(|s| format!(" length=\"{}\"", s))
+                .unwrap_or_default();
+            let _ = writeln!(
+                xml,
+                "    <link rel=\"enclosure\" type=\"{}\" href=\"{}\"{}/>",
+                DOWNLOAD_TYPE,
+                escape(&release.download_url),
+                length
+            );
+            for term in [entry.platform, entry.architecture] {
+                let _ = writeln!(xml, "    <category term=\"{}\"/>", escape(term));
+            }
+            if let Some(description) = &release.description {
+                let _ = writeln!(xml, "    <summary>{}</summary>", escape(description));
+            }
+            xml.push_str("  </entry>\n");
+        }
+
+        xml.push_str("</feed>\n");
+        xml
+    }
+
+    /// Render the feed as an RSS 2.0 document
+    fn to_rss(&self) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+        xml.push_str("<rss version=\"2.0\" xmlns:atom=\"http://www.w3.org/2005/Atom\">\n");
+        xml.push_str("  <channel>\n");
+        let _ = writeln!(xml, "    <title>{}</title>", escape(&self.title));
+        let _ = writeln!(xml, "    <link>{}</link>", escape(&self.link));
+        let _ = writeln!(
+            xml,
+            "    <description>{}</description>",
+            escape(&self.description)
+        );
+        let _ = writeln!(
+            xml,
+            "    <atom:link rel=\"self\" type=\"application/rss+xml\" href=\"{}\"/>",
+            escape(&self.self_url)
+        );
+        let _ = writeln!(
+            xml,
+            "    <lastBuildDate>{}</lastBuildDate>",
+            self.updated.to_rfc2822()
+        );
+        xml.push_str("    <generator>CodeDead API</generator>\n");
+
+        for entry in &self.entries {
+            let release = entry.release;
+            xml.push_str("    <item>\n");
+            let _ = writeln!(xml, "      <title>{}</title>", escape(&entry_title(entry)));
+            let _ = writeln!(
+                xml,
+                "      <link>{}</link>",
+                escape(release.info_url.as_ref().unwrap_or(&release.download_url))
+            );
+            let _ = writeln!(
+                xml,
+                "      <guid isPermaLink=\"false\">{}</guid>",
+                escape(&self.entry_id(entry))
+            );
+            let _ = writeln!(
+                xml,
+                "      <pubDate>{}</pubDate>",
+                entry.published.to_rfc2822()
+            );
+            for category in [entry.platform, entry.architecture] {
+                let _ = writeln!(xml, "      <category>{}</category>", escape(category));
+            }
+            if let Some(description) = &release.description {
+                let _ = writeln!(
+                    xml,
+                    "      <description>{}</description>",
+                    escape(description)
+                );
+            }
+            // RSS requires a length, which is announced as 0 when the size of a download is unknown
+            let _ = writeln!(
+                xml,
+                "      <enclosure url=\"{}\" length=\"{}\" type=\"{}\"/>",
+                escape(&release.download_url),
+                release.size.unwrap_or(0),
+                DOWNLOAD_TYPE
+            );
+            xml.push_str("    </item>\n");
+        }
+
+        xml.push_str("  </channel>\n</rss>\n");
+        xml
+    }
+
+    /// Build the permanent ID of an entry, which does not change when the feed is regenerated
+    fn entry_id(&self, entry: &FeedEntry) -> String {
+        format!(
+            "{}/api/v1/applications/{}/platforms/{}/architectures/{}/releases/{}",
+            self.server_context,
+            entry.application.id,
+            entry.platform,
+            entry.architecture,
+            entry.release.semver
+        )
+    }
+}
+
+/// Build the title of an entry from the names of its Application and Release
+fn entry_title(entry: &FeedEntry) -> String {
+    let release = entry
+        .release
+        .name
+        .clone()
+        .unwrap_or_else(|| entry.release.semver.to_string());
+    format!(
+        "{} {} ({} {})",
+        entry.application.name, release, entry.platform, entry.architecture
+    )
+}
+
+/// Format a point in time as an Atom date construct
+fn atom_time(time: &DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
@@ -0,0 +1,265 @@
+use crate::repository::application::model::application::{Application, Release};
+use crate::repository::application::model::release_channel::ReleaseChannel;
+use crate::services::application::application_service::ApplicationService;
+use crate::web::package::{PackageError, is_macos, short_description};
+use std::fmt::Write;
+
+/// The CPU families Homebrew distinguishes between in a cask
+#[derive(Clone, Copy, PartialEq)]
+enum CaskArch {
+    Arm,
+    Intel,
+    Universal,
+}
+
+impl CaskArch {
+    /// Map the name of an architecture onto a Homebrew CPU family
+    ///
+    /// # Arguments
+    ///
+    /// * `architecture` - The name of the architecture
+    ///
+    /// # Returns
+    ///
+    /// An Option containing the CPU family, or None if Homebrew does not know the architecture
+    fn from_name(architecture: &str) -> Option<Self> {
+        match architecture.to_ascii_lowercase().as_str() {
+            "arm64" | "aarch64" | "arm" | "apple-silicon" => Some(CaskArch::Arm),
+            "x64" | "amd64" | "x86_64" | "intel" => Some(CaskArch::Intel),
+            "universal" | "neutral" | "any" => Some(CaskArch::Universal),
+            _ => None,
+        }
+    }
+}
+
+/// The latest Release of a single CPU family in a cask
+struct CaskArtifact<'a> {
+    arch: CaskArch,
+    release: &'a Release,
+    sha256: String,
+}
+
+/// A Homebrew cask of the latest macOS Releases of an Application
+pub struct HomebrewCask<'a> {
+    token: String,
+    app: String,
+    application: &'a Application,
+    homepage: String,
+    artifacts: Vec<CaskArtifact<'a>>,
+}
+
+/// Quote a string as a Ruby string literal
+///
+/// # Arguments
+///
+/// * `value` - The string to quote
+///
+/// # Returns
+///
+/// The double-quoted literal, without interpolation
+fn ruby_string(value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace("#{", "\\#{")
+        .replace('\n', "\\n");
+    format!("\"{}\"", escaped)
+}
+
+/// Derive the default cask token of an Application
+///
+/// # Arguments
+///
+/// * `application` - The Application
+///
+/// # Returns
+///
+/// The lowercase name of the Application with its words joined by hyphens
+pub fn default_token(application: &Application) -> String {
+    application
+        .name
+        .to_ascii_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<&str>>()
+        .join("-")
+}
+
+impl<'a> HomebrewCask<'a> {
+    /// Build a cask from the latest macOS Release of every architecture of an Application
+    ///
+    /// Architectures that Homebrew does not know are left out. A universal Release is only used when
+    /// there is no Release for Apple silicon or Intel.
+    ///
+    /// # Arguments
+    ///
+    /// * `application` - The Application
+    /// * `token` - The token of the cask
+    /// * `app` - The name of the app bundle the download contains
+    /// * `channel` - The release channel to find the latest Releases in
+    ///
+    /// # Returns
+    ///
+    /// A new HomebrewCask
+    ///
+    /// # Errors
+    ///
+    /// Returns a PackageError if no Release was found or a Release has no SHA-256 checksum
+    pub fn new(
+        application: &'a Application,
+        token: &str,
+        app: &str,
+        channel: &ReleaseChannel,
+    ) -> Result<Self, PackageError> {
+        let mut homepage = None;
+        let mut artifacts: Vec<CaskArtifact> = vec![];
+        for platform in application
+            .platforms
+            .iter()
+            .flatten()
+            .filter(|p| is_macos(&p.platform_name))
+        {
+            for architecture in platform.architectures.iter().flatten() {
+                let Some(arch) = CaskArch::from_name(&architecture.name) else {
+                    continue;
+                };
+                let Some(release) = ApplicationService::find_latest_release(
+                    application,
+                    Some(&platform.platform_name),
+                    Some(&architecture.name),
+                    channel,
+                ) else {
+                    continue;
+                };
+                if artifacts
+                    .iter()
+                    .any(|a| a.arch == arch && a.release.semver >= release.semver)
+                {
+                    continue;
+                }
+                let sha256 = release
+                    .sha256()
+                    .ok_or_else(|| PackageError::MissingChecksum(architecture.name.clone()))?;
+
+                artifacts.retain(|a| a.arch != arch);
+                artifacts.push(CaskArtifact {
+                    arch,
+                    release,
+                    sha256,
+                });
+                homepage.get_or_insert(architecture.url.clone());
+            }
+        }
+
+        if artifacts.iter().any(|a| a.arch != CaskArch::Universal) {
+            artifacts.retain(|a| a.arch != CaskArch::Universal);
+        }
+        if artifacts.is_empty() {
+            return Err(PackageError::NoInstallers);
+        }
+        artifacts.sort_by_key(|a| a.arch != CaskArch::Arm);
+
+        Ok(HomebrewCask {
+            token: token.to_string(),
+            app: app.to_string(),
+            application,
+            homepage: homepage.unwrap_or_default(),
+            artifacts,
+        })
+    }
+
+    /// Write the version, checksum and URL of a Release
+    ///
+    /// # Arguments
+    ///
+    /// * `ruby` - The cask to write to
+    /// * `artifact` - The Release to write
+    /// * `indent` - The indentation of the stanzas
+    fn write_artifact(ruby: &mut String, artifact: &CaskArtifact, indent: usize) {
+        let _ = writeln!(
+            ruby,
+            "{:indent$}version {}",
+            "",
+            ruby_string(&artifact.release.semver.to_string())
+        );
+        let _ = writeln!(
+            ruby,
+            "{:indent$}sha256 {}",
+            "",
+            ruby_string(&artifact.sha256)
+        );
+        ruby.push('\n');
+        let _ = writeln!(
+            ruby,
+            "{:indent$}url {}",
+            "",
+            ruby_string(&artifact.release.download_url)
+        );
+    }
+
+    /// Render the cask as a Ruby file
+    ///
+    /// Releases for Apple silicon and Intel are placed in `on_arm` and `on_intel` blocks, so each
+    /// can have its own version. A cask with only one of them depends on that architecture.
+    ///
+    /// # Returns
+    ///
+    /// The Ruby source of the cask
+    pub fn render(&self) -> String {
+        let mut ruby = String::new();
+        let _ = writeln!(ruby, "cask {} do", ruby_string(&self.token));
+
+        if let [artifact] = self.artifacts.as_slice() {
+            Self::write_artifact(&mut ruby, artifact, 2);
+        } else {
+            for (i, artifact) in self.artifacts.iter().enumerate() {
+                if i > 0 {
+                    ruby.push('\n');
+                }
+                let block = match artifact.arch {
+                    CaskArch::Arm => "on_arm",
+                    _ => "on_intel",
+                };
+                let _ = writeln!(ruby, "  {} do", block);
+                Self::write_artifact(&mut ruby, artifact, 4);
+                ruby.push_str("  end\n");
+            }
+            ruby.push('\n');
+        }
+
+        let description = short_description(self.application)
+            .trim_end_matches('.')
+            .to_string();
+        let _ = writeln!(ruby, "  name {}", ruby_string(&self.application.name));
+        let _ = writeln!(ruby, "  desc {}", ruby_string(&description));
+        let _ = writeln!(ruby, "  homepage {}", ruby_string(&self.homepage));
+
+        if let [artifact] = self.artifacts.as_slice() {
+            let arch = match artifact.arch {
+                CaskArch::Arm => Some(":arm64"),
+                CaskArch::Intel => Some(":x86_64"),
+                CaskArch::Universal => None,
+            };
+            if let Some(arch) = arch {
+                ruby.push('\n');
+                let _ = writeln!(ruby, "  depends_on arch: {}", arch);
+            }
+        }
+
+        ruby.push('\n');
+        let _ = writeln!(ruby, "  app {}", ruby_string(&self.app));
+        ruby.push_str("end\n");
+        ruby
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ruby_string_does_not_interpolate() {
+        assert_eq!(ruby_string("Dead \"Hash\""), "\"Dead \\\"Hash\\\"\"");
+        assert_eq!(ruby_string("#{version}\\"), "\"\\#{version}\\\\\"");
+    }
+}
@@ -0,0 +1,3 @@
+pub(crate) mod authentication;
+pub(crate) mod problem_details;
+pub(crate) mod request_metrics;
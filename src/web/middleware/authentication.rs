@@ -0,0 +1,273 @@
+use crate::config::server_config::ServerConfig;
+use crate::errors::api_error::ApiError;
+use crate::services::jwt::jwt_service::JwtService;
+use actix_web::body::EitherBody;
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform, forward_ready};
+use actix_web::http::header;
+use actix_web::{Error, HttpMessage, HttpRequest, web};
+use futures::future::{LocalBoxFuture, Ready, ready};
+use log::{error, info, warn};
+use std::rc::Rc;
+
+/// The scope that is required to create, modify or delete applications
+pub const APPLICATIONS_WRITE: &str = "applications:write";
+/// The scope that is required to create, modify or delete versions
+pub const VERSIONS_WRITE: &str = "versions:write";
+/// The scope that is required to register, inspect or delete webhooks
+pub const WEBHOOKS_MANAGE: &str = "webhooks:manage";
+
+/// The name of the header that can carry an API key
+const API_KEY_HEADER: &str = "X-Api-Key";
+/// The realm that is announced in `WWW-Authenticate` challenges
+const REALM: &str = "codedead_api";
+
+/// The identity of an authenticated client, available in the request extensions
+#[derive(Clone, Debug)]
+pub struct Principal {
+    pub id: String,
+    pub scopes: Vec<String>,
+}
+
+/// The credentials that were presented with a request
+enum Credentials {
+    /// A token from the `Authorization: Bearer` header, either a JWT or an API key
+    Bearer(String),
+    /// An API key from the `X-Api-Key` header
+    ApiKey(String),
+}
+
+/// Middleware that only lets requests through that carry credentials with a specific scope
+///
+/// Credentials are read from the `Authorization: Bearer` header or the `X-Api-Key` header.
+/// Bearer tokens that have the shape of a JWT are verified as such when JWT verification keys
+/// are configured; all other credentials are looked up as API keys.
+pub struct RequireScope {
+    scope: &'static str,
+}
+
+impl RequireScope {
+    /// Initialize a new RequireScope middleware
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - The scope that the credentials of a request must carry
+    ///
+    /// # Returns
+    ///
+    /// A new instance of RequireScope
+    pub fn new(scope: &'static str) -> Self {
+        RequireScope { scope }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for RequireScope
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Transform = RequireScopeMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RequireScopeMiddleware {
+            service: Rc::new(service),
+            scope: self.scope,
+        }))
+    }
+}
+
+pub struct RequireScopeMiddleware<S> {
+    service: Rc<S>,
+    scope: &'static str,
+}
+
+impl<S, B> Service<ServiceRequest> for RequireScopeMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let service = Rc::clone(&self.service);
+        let scope = self.scope;
+
+        Box::pin(async move {
+            let principal = match authenticate(req.request()).await {
+                Ok(principal) => principal,
+                Err(e) => return Ok(req.error_response(e).map_into_right_body()),
+            };
+
+            if !principal.scopes.iter().any(|s| s == scope) {
+                warn!("Rejected {} because it lacks scope {}", principal.id, scope);
+                return Ok(req
+                    .error_response(insufficient_scope(scope))
+                    .map_into_right_body());
+            }
+
+            info!("Authorized {} for scope {}", principal.id, scope);
+            req.extensions_mut().insert(principal);
+            service.call(req).await.map(|res| res.map_into_left_body())
+        })
+    }
+}
+
+/// Read the credentials of a request from the `Authorization` or `X-Api-Key` header
+///
+/// # Arguments
+///
+/// * `req` - The HTTP request
+///
+/// # Returns
+///
+/// A Result containing an Option with the Credentials, or None if no credentials were provided
+///
+/// # Errors
+///
+/// Returns an error if the `Authorization` header is present but malformed
+fn extract_credentials(req: &HttpRequest) -> Result<Option<Credentials>, ()> {
+    if let Some(value) = req.headers().get(header::AUTHORIZATION) {
+        let value = value.to_str().map_err(|_| ())?;
+        return match value.split_once(' ') {
+            Some((scheme, token))
+                if scheme.eq_ignore_ascii_case("Bearer") && !token.trim().is_empty() =>
+            {
+                Ok(Some(Credentials::Bearer(token.trim().to_string())))
+            }
+            _ => Err(()),
+        };
+    }
+
+    Ok(req
+        .headers()
+        .get(API_KEY_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .map(Credentials::ApiKey))
+}
+
+/// Build a `WWW-Authenticate` challenge as described by RFC 6750
+///
+/// # Arguments
+///
+/// * `parameters` - The additional authentication parameters
+///
+/// # Returns
+///
+/// The value of the `WWW-Authenticate` header
+fn challenge(parameters: &[(&str, &str)]) -> String {
+    let mut value = format!("Bearer realm=\"{}\"", REALM);
+    for (name, parameter) in parameters {
+        value.push_str(&format!(
+            ", {}=\"{}\"",
+            name,
+            parameter.replace('\\', "\\\\").replace('"', "\\\"")
+        ));
+    }
+    value
+}
+
+/// Build the error for requests without credentials
+fn missing_credentials() -> ApiError {
+    ApiError::unauthorized("Missing credentials").with_challenge(challenge(&[]))
+}
+
+/// Build the error for requests with a malformed `Authorization` header
+fn invalid_request() -> ApiError {
+    ApiError::bad_request("Malformed Authorization header").with_challenge(challenge(&[
+        ("error", "invalid_request"),
+        ("error_description", "Malformed Authorization header"),
+    ]))
+}
+
+/// Build the error for requests with invalid, expired or revoked credentials
+fn invalid_token(description: &str) -> ApiError {
+    ApiError::unauthorized(description).with_challenge(challenge(&[
+        ("error", "invalid_token"),
+        ("error_description", description),
+    ]))
+}
+
+/// Build the error for requests with credentials that lack the required scope
+fn insufficient_scope(scope: &str) -> ApiError {
+    ApiError::forbidden(&format!(
+        "The provided credentials lack the required scope {}",
+        scope
+    ))
+    .with_challenge(challenge(&[
+        ("error", "insufficient_scope"),
+        ("scope", scope),
+    ]))
+}
+
+/// Authenticate a request using its bearer token or API key
+///
+/// # Arguments
+///
+/// * `req` - The HTTP request
+///
+/// # Returns
+///
+/// A Result containing the Principal of the request
+///
+/// # Errors
+///
+/// Returns the ApiError to send when the request could not be authenticated
+async fn authenticate(req: &HttpRequest) -> Result<Principal, ApiError> {
+    let credentials = match extract_credentials(req) {
+        Ok(Some(credentials)) => credentials,
+        Ok(None) => return Err(missing_credentials()),
+        Err(()) => return Err(invalid_request()),
+    };
+
+    let pool = match req.app_data::<web::Data<ServerConfig>>() {
+        Some(pool) => pool,
+        None => {
+            error!("Server configuration is not available for authentication");
+            return Err(ApiError::internal_server_error(
+                "Error authenticating request",
+            ));
+        }
+    };
+
+    let api_key = match credentials {
+        Credentials::Bearer(token)
+            if pool.services.jwt_service.is_enabled() && JwtService::is_jwt(&token) =>
+        {
+            return match pool.services.jwt_service.validate(&token) {
+                Ok(claims) => Ok(Principal {
+                    scopes: claims.scopes(),
+                    id: claims.sub,
+                }),
+                Err(e) => {
+                    warn!("Rejected bearer token: {}", e);
+                    Err(invalid_token("The bearer token is invalid or has expired"))
+                }
+            };
+        }
+        Credentials::Bearer(token) => token,
+        Credentials::ApiKey(api_key) => api_key,
+    };
+
+    match pool.services.api_key_service.authenticate(&api_key).await {
+        Ok(Some(api_key)) => Ok(Principal {
+            id: api_key.id,
+            scopes: api_key.scopes,
+        }),
+        Ok(None) => Err(invalid_token("The API key is invalid, revoked or expired")),
+        Err(e) => {
+            error!("Error authenticating API key: {}", e);
+            Err(ApiError::internal_server_error(
+                "Error authenticating request",
+            ))
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use crate::errors::api_error::ApiError;
+use actix_web::body::{EitherBody, MessageBody};
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::error::{JsonPayloadError, QueryPayloadError};
+use actix_web::middleware::Next;
+use actix_web::{Error, HttpRequest, HttpResponse, ResponseError};
+
+/// Middleware that fills in the `instance` of problem details documents with the request path
+///
+/// # Arguments
+///
+/// * `req` - The service request
+/// * `next` - The next service in the chain
+///
+/// # Returns
+///
+/// The response of the next service, rebuilt when it carries an ApiError without an instance
+pub async fn problem_instance<B: MessageBody + 'static>(
+    req: ServiceRequest,
+    next: Next<B>,
+) -> Result<ServiceResponse<EitherBody<B>>, Error> {
+    let path = req.path().to_string();
+    let res = next.call(req).await?;
+
+    let problem = res
+        .response()
+        .error()
+        .and_then(|e| e.as_error::<ApiError>())
+        .filter(|e| !e.has_instance())
+        .cloned();
+
+    match problem {
+        Some(problem) => {
+            let (req, _) = res.into_parts();
+            let response = HttpResponse::from_error(problem.with_instance(&path));
+            Ok(ServiceResponse::new(req, response).map_into_right_body())
+        }
+        None => Ok(res.map_into_left_body()),
+    }
+}
+
+/// Convert errors of the `web::Query` extractor into problem details
+///
+/// # Arguments
+///
+/// * `err` - The error that occurred while deserializing the query string
+/// * `req` - The HTTP request
+///
+/// # Returns
+///
+/// An HTTP 400 Bad Request ApiError
+pub fn query_error_handler(err: QueryPayloadError, req: &HttpRequest) -> Error {
+    ApiError::bad_request(&format!("Invalid query string: {}", err))
+        .with_instance(req.path())
+        .into()
+}
+
+/// Convert errors of the `web::Json` extractor into problem details
+///
+/// # Arguments
+///
+/// * `err` - The error that occurred while reading or deserializing the request body
+/// * `req` - The HTTP request
+///
+/// # Returns
+///
+/// An HTTP 422 Unprocessable Content ApiError when the body is well-formed JSON of the wrong
+/// shape, otherwise an ApiError with the status code of the underlying error
+pub fn json_error_handler(err: JsonPayloadError, req: &HttpRequest) -> Error {
+    let problem = match &err {
+        JsonPayloadError::Deserialize(e) if e.is_data() => {
+            ApiError::unprocessable_content(&format!("Invalid request body: {}", e))
+        }
+        _ => ApiError::new(err.status_code(), &format!("Invalid request body: {}", err)),
+    };
+
+    problem.with_instance(req.path()).into()
+}
+
+/// Respond to requests that do not match any route with problem details
+///
+/// # Arguments
+///
+/// * `req` - The HTTP request
+///
+/// # Returns
+///
+/// An HTTP 404 Not Found ApiError
+pub async fn not_found(req: HttpRequest) -> Result<HttpResponse, ApiError> {
+    Err(ApiError::not_found("The requested resource does not exist").with_instance(req.path()))
+}
@@ -0,0 +1,50 @@
+use crate::config::server_config::ServerConfig;
+use crate::services::metrics::metrics_service::UNMATCHED_ROUTE;
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::middleware::Next;
+use actix_web::{Error, web};
+use std::time::Instant;
+
+/// Middleware that records the count and latency of requests per method, route and status
+///
+/// The route label is the matched route pattern, e.g. `/api/v1/applications/{id}`, so that the
+/// number of time series does not grow with the number of distinct IDs that are requested.
+///
+/// # Arguments
+///
+/// * `req` - The service request
+/// * `next` - The next service in the chain
+///
+/// # Returns
+///
+/// The response of the next service
+pub async fn record_request<B: MessageBody + 'static>(
+    req: ServiceRequest,
+    next: Next<B>,
+) -> Result<ServiceResponse<B>, Error> {
+    let start = Instant::now();
+    let method = req.method().to_string();
+    let route = req
+        .match_pattern()
+        .unwrap_or_else(|| String::from(UNMATCHED_ROUTE));
+    let pool = req.app_data::<web::Data<ServerConfig>>().cloned();
+
+    let res = next.call(req).await;
+
+    if let Some(pool) = pool {
+        let status = match &res {
+            Ok(res) => res.status(),
+            Err(e) => e.as_response_error().status_code(),
+        };
+
+        pool.services.metrics_service.observe_request(
+            &method,
+            &route,
+            status.as_u16(),
+            start.elapsed(),
+        );
+    }
+
+    res
+}
@@ -0,0 +1,71 @@
+use crate::repository::application::model::application::Application;
+use std::fmt;
+
+/// The reasons a package manifest cannot be rendered
+#[derive(Debug, PartialEq)]
+pub enum PackageError {
+    /// None of the architectures has a release the package manager can install
+    NoInstallers,
+    /// The Release of an architecture has no SHA-256 checksum
+    MissingChecksum(String),
+}
+
+impl fmt::Display for PackageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PackageError::NoInstallers => write!(f, "No installable releases were found"),
+            PackageError::MissingChecksum(arch) => write!(
+                f,
+                "The release for architecture {} has no SHA-256 checksum",
+                arch
+            ),
+        }
+    }
+}
+
+/// Check whether a platform is Windows
+///
+/// # Arguments
+///
+/// * `platform` - The name of the platform
+///
+/// # Returns
+///
+/// True if the platform is Windows, false otherwise
+pub fn is_windows(platform: &str) -> bool {
+    matches!(platform.to_ascii_lowercase().as_str(), "windows" | "win")
+}
+
+/// Check whether a platform is macOS
+///
+/// # Arguments
+///
+/// * `platform` - The name of the platform
+///
+/// # Returns
+///
+/// True if the platform is macOS, false otherwise
+pub fn is_macos(platform: &str) -> bool {
+    matches!(
+        platform.to_ascii_lowercase().as_str(),
+        "macos" | "mac" | "osx" | "darwin"
+    )
+}
+
+/// Get the one-line description of an Application
+///
+/// # Arguments
+///
+/// * `application` - The Application
+///
+/// # Returns
+///
+/// The first line of the description of the Application, or its name if it has no description
+pub fn short_description(application: &Application) -> &str {
+    application
+        .description
+        .as_deref()
+        .and_then(|d| d.trim().lines().next())
+        .filter(|d| !d.is_empty())
+        .unwrap_or(&application.name)
+}
@@ -0,0 +1,134 @@
+use crate::repository::application::model::application::{Application, Architecture, Release};
+use crate::repository::application::model::release_channel::ReleaseChannel;
+use crate::services::application::application_service::ApplicationService;
+use crate::web::dto::package::scoop_manifest_dto::{ScoopArchitectureDto, ScoopManifestDto};
+use crate::web::package::{PackageError, is_windows, short_description};
+use crate::web::winget::winget_architecture;
+use std::collections::BTreeMap;
+
+/// Determine the executable Scoop should shim for a Release
+///
+/// # Arguments
+///
+/// * `release` - The Release
+///
+/// # Returns
+///
+/// An Option containing the file name of the download if it is a portable executable, or None otherwise
+fn bin(release: &Release) -> Option<String> {
+    let path = release.download_url.split(['?', '#']).next()?;
+    let file_name = path.rsplit('/').next()?;
+
+    (release.portable == Some(true) && file_name.to_ascii_lowercase().ends_with(".exe"))
+        .then(|| file_name.to_string())
+}
+
+/// Determine the Scoop architecture of an architecture name
+///
+/// # Arguments
+///
+/// * `architecture` - The name of the architecture
+///
+/// # Returns
+///
+/// An Option containing the Scoop architecture, an empty string for architecture-neutral
+/// Releases, or None if Scoop does not support the architecture
+fn scoop_architecture(architecture: &str) -> Option<&'static str> {
+    match winget_architecture(architecture)? {
+        "x64" => Some("64bit"),
+        "x86" => Some("32bit"),
+        "arm64" => Some("arm64"),
+        "neutral" => Some(""),
+        _ => None,
+    }
+}
+
+/// Build a Scoop manifest from the latest Windows Release of an Application
+///
+/// Scoop manifests describe a single version, so only the architectures whose latest Release has
+/// the highest version are included. Architectures Scoop does not support are ignored, also when
+/// determining the highest version. Architecture-neutral Releases become the top-level download.
+///
+/// # Arguments
+///
+/// * `application` - The Application
+/// * `license` - The license of the Application
+/// * `channel` - The release channel to find the latest Releases in
+///
+/// # Returns
+///
+/// A new ScoopManifestDto
+///
+/// # Errors
+///
+/// Returns a PackageError if no Release was found or a Release has no SHA-256 checksum
+pub fn scoop_manifest(
+    application: &Application,
+    license: &str,
+    channel: &ReleaseChannel,
+) -> Result<ScoopManifestDto, PackageError> {
+    let candidates: Vec<(&str, &Architecture, &Release)> = application
+        .platforms
+        .iter()
+        .flatten()
+        .filter(|p| is_windows(&p.platform_name))
+        .flat_map(|p| p.architectures.iter().flatten().map(move |a| (p, a)))
+        .filter_map(|(platform, architecture)| {
+            let key = scoop_architecture(&architecture.name)?;
+            let release = ApplicationService::find_latest_release(
+                application,
+                Some(&platform.platform_name),
+                Some(&architecture.name),
+                channel,
+            )?;
+            Some((key, architecture, release))
+        })
+        .collect();
+
+    let latest = candidates
+        .iter()
+        .map(|(_, _, release)| &release.semver)
+        .max()
+        .ok_or(PackageError::NoInstallers)?;
+
+    let mut manifest = ScoopManifestDto {
+        version: latest.to_string(),
+        description: short_description(application).to_string(),
+        homepage: String::new(),
+        license: license.to_string(),
+        url: None,
+        hash: None,
+        bin: None,
+        architecture: BTreeMap::new(),
+    };
+
+    for (key, architecture, release) in candidates.iter().filter(|(_, _, r)| &r.semver == latest) {
+        let hash = release
+            .sha256()
+            .ok_or_else(|| PackageError::MissingChecksum(architecture.name.clone()))?;
+
+        if manifest.homepage.is_empty() {
+            manifest.homepage = architecture.url.clone();
+        }
+        if key.is_empty() {
+            manifest.url = Some(release.download_url.clone());
+            manifest.hash = Some(hash);
+            manifest.bin = bin(release);
+        } else {
+            manifest.architecture.insert(
+                key.to_string(),
+                ScoopArchitectureDto {
+                    url: release.download_url.clone(),
+                    hash,
+                    bin: bin(release),
+                },
+            );
+        }
+    }
+
+    if manifest.url.is_none() && manifest.architecture.is_empty() {
+        return Err(PackageError::NoInstallers);
+    }
+
+    Ok(manifest)
+}
@@ -0,0 +1,409 @@
+use crate::repository::application::model::application::{Application, Release};
+use crate::repository::semver::SemVer;
+use crate::web::feed::parse_time;
+use crate::web::package::{PackageError, is_windows, short_description};
+use crate::web::yaml::scalar;
+use std::fmt::Write;
+
+/// The version of the winget manifest schema that is rendered
+pub const MANIFEST_VERSION: &str = "1.6.0";
+
+/// The locale of the default locale manifest
+const DEFAULT_LOCALE: &str = "en-US";
+
+/// The maximum length of the ShortDescription of a winget package
+const SHORT_DESCRIPTION_LENGTH: usize = 256;
+
+/// An installer of a single architecture in a winget installer manifest
+struct WingetInstaller<'a> {
+    architecture: &'static str,
+    installer_type: &'static str,
+    release: &'a Release,
+    sha256: String,
+}
+
+/// A file of a multi-file winget manifest
+pub struct WingetManifest {
+    pub file_name: String,
+    pub content: String,
+}
+
+/// The version, installer and default locale manifests of one version of a winget package
+pub struct WingetPackage<'a> {
+    package_identifier: String,
+    publisher: String,
+    license: String,
+    application: &'a Application,
+    version: SemVer,
+    installers: Vec<WingetInstaller<'a>>,
+}
+
+/// Map the name of an architecture onto a winget architecture
+///
+/// # Arguments
+///
+/// * `architecture` - The name of the architecture
+///
+/// # Returns
+///
+/// An Option containing the winget architecture, or None if winget does not know the architecture
+pub fn winget_architecture(architecture: &str) -> Option<&'static str> {
+    match architecture.to_ascii_lowercase().as_str() {
+        "x64" | "amd64" | "x86_64" => Some("x64"),
+        "x86" | "i386" | "i686" | "win32" => Some("x86"),
+        "arm64" | "aarch64" => Some("arm64"),
+        "arm" => Some("arm"),
+        "neutral" | "any" | "universal" => Some("neutral"),
+        _ => None,
+    }
+}
+
+/// Determine the winget installer type of a Release from the extension of its download URL
+///
+/// # Arguments
+///
+/// * `release` - The Release
+///
+/// # Returns
+///
+/// An Option containing the winget installer type, or None if the download is not an installer
+/// winget can describe without further details
+pub fn installer_type(release: &Release) -> Option<&'static str> {
+    let path = release
+        .download_url
+        .split(['?', '#'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    let extension = path.rsplit_once('.').map(|(_, e)| e)?;
+
+    match extension {
+        "msi" => Some("msi"),
+        "msix" | "msixbundle" => Some("msix"),
+        "appx" | "appxbundle" => Some("appx"),
+        "exe" if release.portable == Some(true) => Some("portable"),
+        "exe" => Some("exe"),
+        _ => None,
+    }
+}
+
+/// Derive the default package identifier of an Application
+///
+/// # Arguments
+///
+/// * `publisher` - The publisher of the Application
+/// * `application` - The Application
+///
+/// # Returns
+///
+/// The identifier in the `Publisher.Name` form, with the words of the name joined
+pub fn default_package_identifier(publisher: &str, application: &Application) -> String {
+    let name: String = application
+        .name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| {
+            let mut chars = w.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect();
+    let publisher: String = publisher
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect();
+
+    format!("{}.{}", publisher, name)
+}
+
+impl<'a> WingetPackage<'a> {
+    /// Build a winget package from the Windows Releases of an Application with a version
+    ///
+    /// Architectures that winget does not know and downloads that are not installers are left out.
+    ///
+    /// # Arguments
+    ///
+    /// * `application` - The Application
+    /// * `version` - The semantic version of the Releases to include
+    /// * `package_identifier` - The identifier of the winget package
+    /// * `publisher` - The publisher of the winget package
+    /// * `license` - The license of the winget package
+    ///
+    /// # Returns
+    ///
+    /// A new WingetPackage
+    ///
+    /// # Errors
+    ///
+    /// Returns a PackageError if no installer was found or an installer has no SHA-256 checksum
+    pub fn new(
+        application: &'a Application,
+        version: &SemVer,
+        package_identifier: &str,
+        publisher: &str,
+        license: &str,
+    ) -> Result<Self, PackageError> {
+        let mut installers = vec![];
+        for architecture in application
+            .platforms
+            .iter()
+            .flatten()
+            .filter(|p| is_windows(&p.platform_name))
+            .flat_map(|p| p.architectures.iter().flatten())
+        {
+            let Some(winget_architecture) = winget_architecture(&architecture.name) else {
+                continue;
+            };
+            let Some(release) = architecture
+                .releases
+                .iter()
+                .flatten()
+                .find(|r| &r.semver == version)
+            else {
+                continue;
+            };
+            let Some(installer_type) = installer_type(release) else {
+                continue;
+            };
+            let sha256 = release
+                .sha256()
+                .ok_or_else(|| PackageError::MissingChecksum(architecture.name.clone()))?;
+
+            installers.push(WingetInstaller {
+                architecture: winget_architecture,
+                installer_type,
+                release,
+                sha256,
+            });
+        }
+
+        if installers.is_empty() {
+            return Err(PackageError::NoInstallers);
+        }
+
+        Ok(WingetPackage {
+            package_identifier: package_identifier.to_string(),
+            publisher: publisher.to_string(),
+            license: license.to_string(),
+            application,
+            version: version.clone(),
+            installers,
+        })
+    }
+
+    /// Get the identifier of the package
+    ///
+    /// # Returns
+    ///
+    /// The identifier of the package
+    pub fn package_identifier(&self) -> &str {
+        &self.package_identifier
+    }
+
+    /// Get the version of the package
+    ///
+    /// # Returns
+    ///
+    /// The version of the package
+    pub fn package_version(&self) -> String {
+        self.version.to_string()
+    }
+
+    /// Get the directory of the manifests in the winget-pkgs repository
+    ///
+    /// # Returns
+    ///
+    /// The path of the directory, such as `manifests/c/CodeDead/DeadHash/1.0.0`
+    pub fn path(&self) -> String {
+        let first = self
+            .package_identifier
+            .chars()
+            .next()
+            .map(|c| c.to_ascii_lowercase())
+            .unwrap_or_default();
+        format!(
+            "manifests/{}/{}/{}",
+            first,
+            self.package_identifier.replace('.', "/"),
+            self.version
+        )
+    }
+
+    /// Render the version, installer and default locale manifests
+    ///
+    /// # Returns
+    ///
+    /// The manifest files, named the way winget-pkgs expects
+    pub fn render(&self) -> Vec<WingetManifest> {
+        vec![
+            WingetManifest {
+                file_name: format!("{}.yaml", self.package_identifier),
+                content: self.render_version(),
+            },
+            WingetManifest {
+                file_name: format!("{}.installer.yaml", self.package_identifier),
+                content: self.render_installer(),
+            },
+            WingetManifest {
+                file_name: format!("{}.locale.{}.yaml", self.package_identifier, DEFAULT_LOCALE),
+                content: self.render_default_locale(),
+            },
+        ]
+    }
+
+    /// Write the schema comment and the keys every manifest starts with
+    ///
+    /// # Arguments
+    ///
+    /// * `yaml` - The manifest to write to
+    /// * `manifest_type` - The type of the manifest
+    fn write_header(&self, yaml: &mut String, manifest_type: &str) {
+        let _ = writeln!(
+            yaml,
+            "# yaml-language-server: $schema=https://aka.ms/winget-manifest.{}.{}.schema.json",
+            manifest_type, MANIFEST_VERSION
+        );
+        yaml.push('\n');
+        let _ = writeln!(
+            yaml,
+            "PackageIdentifier: {}",
+            scalar(&self.package_identifier, 0)
+        );
+        let _ = writeln!(
+            yaml,
+            "PackageVersion: {}",
+            scalar(&self.version.to_string(), 0)
+        );
+    }
+
+    /// Write the keys every manifest ends with
+    ///
+    /// # Arguments
+    ///
+    /// * `yaml` - The manifest to write to
+    /// * `manifest_type` - The type of the manifest
+    fn write_footer(&self, yaml: &mut String, manifest_type: &str) {
+        let _ = writeln!(yaml, "ManifestType: {}", manifest_type);
+        let _ = writeln!(yaml, "ManifestVersion: {}", MANIFEST_VERSION);
+    }
+
+    /// Render the version manifest
+    ///
+    /// # Returns
+    ///
+    /// The YAML document
+    fn render_version(&self) -> String {
+        let mut yaml = String::new();
+        self.write_header(&mut yaml, "version");
+        let _ = writeln!(yaml, "DefaultLocale: {}", DEFAULT_LOCALE);
+        self.write_footer(&mut yaml, "version");
+        yaml
+    }
+
+    /// Render the installer manifest
+    ///
+    /// # Returns
+    ///
+    /// The YAML document
+    fn render_installer(&self) -> String {
+        let mut yaml = String::new();
+        self.write_header(&mut yaml, "installer");
+
+        if let Some(date) = self
+            .installers
+            .iter()
+            .filter_map(|i| i.release.release_date.as_deref().and_then(parse_time))
+            .min()
+        {
+            let _ = writeln!(yaml, "ReleaseDate: {}", date.format("%Y-%m-%d"));
+        }
+
+        yaml.push_str("Installers:\n");
+        for installer in &self.installers {
+            let _ = writeln!(yaml, "- Architecture: {}", installer.architecture);
+            let _ = writeln!(yaml, "  InstallerType: {}", installer.installer_type);
+            let _ = writeln!(
+                yaml,
+                "  InstallerUrl: {}",
+                scalar(&installer.release.download_url, 2)
+            );
+            let _ = writeln!(
+                yaml,
+                "  InstallerSha256: {}",
+                installer.sha256.to_ascii_uppercase()
+            );
+        }
+
+        self.write_footer(&mut yaml, "installer");
+        yaml
+    }
+
+    /// Render the default locale manifest
+    ///
+    /// # Returns
+    ///
+    /// The YAML document
+    fn render_default_locale(&self) -> String {
+        let mut yaml = String::new();
+        self.write_header(&mut yaml, "defaultLocale");
+
+        let description = self
+            .application
+            .description
+            .as_deref()
+            .map(str::trim)
+            .filter(|d| !d.is_empty());
+        let summary: String = short_description(self.application)
+            .chars()
+            .take(SHORT_DESCRIPTION_LENGTH)
+            .collect();
+        let release = self.installers[0].release;
+
+        let _ = writeln!(yaml, "PackageLocale: {}", DEFAULT_LOCALE);
+        let _ = writeln!(yaml, "Publisher: {}", scalar(&self.publisher, 0));
+        let _ = writeln!(yaml, "PackageName: {}", scalar(&self.application.name, 0));
+        let _ = writeln!(yaml, "License: {}", scalar(&self.license, 0));
+        let _ = writeln!(yaml, "ShortDescription: {}", scalar(&summary, 0));
+        if let Some(description) = description
+            && description != summary
+        {
+            let _ = writeln!(yaml, "Description: {}", scalar(description, 0));
+        }
+        if let Some(notes) = release.description.as_deref().filter(|n| !n.is_empty()) {
+            let _ = writeln!(yaml, "ReleaseNotes: {}", scalar(notes, 0));
+        }
+        if let Some(url) = release.info_url.as_deref().filter(|u| !u.is_empty()) {
+            let _ = writeln!(yaml, "ReleaseNotesUrl: {}", scalar(url, 0));
+        }
+
+        self.write_footer(&mut yaml, "defaultLocale");
+        yaml
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn installer_type_follows_the_download_url() {
+        let mut release: Release = serde_json::from_value(serde_json::json!({
+            "semver": "1.0.0",
+            "downloadUrl": "https://codedead.com/setup.MSI?source=api",
+        }))
+        .unwrap();
+        assert_eq!(installer_type(&release), Some("msi"));
+
+        release.download_url = String::from("https://codedead.com/tool.exe");
+        assert_eq!(installer_type(&release), Some("exe"));
+        release.portable = Some(true);
+        assert_eq!(installer_type(&release), Some("portable"));
+
+        release.download_url = String::from("https://codedead.com/tool.zip");
+        assert_eq!(installer_type(&release), None);
+        assert_eq!(winget_architecture("AMD64"), Some("x64"));
+        assert_eq!(winget_architecture("riscv64"), None);
+    }
+}
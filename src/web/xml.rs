@@ -0,0 +1,36 @@
+/// Escape text for use in XML character data and attribute values
+///
+/// # Arguments
+///
+/// * `value` - The text to escape
+///
+/// # Returns
+///
+/// The text with the five predefined XML entities substituted
+pub fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_substitutes_markup_characters() {
+        assert_eq!(
+            escape(r#"<a href="x?a=1&b='2'">"#),
+            "&lt;a href=&quot;x?a=1&amp;b=&apos;2&apos;&quot;&gt;"
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use std::fmt::Write;
+
+/// Format a string as a YAML scalar
+///
+/// Plain scalars are used where they cannot be mistaken for another type or for YAML syntax,
+/// single-quoted scalars otherwise. Multi-line values become literal block scalars.
+///
+/// # Arguments
+///
+/// * `value` - The string to format
+/// * `indent` - The indentation of the key the value belongs to, used for block scalars
+///
+/// # Returns
+///
+/// The YAML representation of the string, without a trailing line break
+pub fn scalar(value: &str, indent: usize) -> String {
+    if value.contains('\n') {
+        let mut block = String::from("|-");
+        for line in value.lines() {
+            let _ = write!(block, "\n{:indent$}{}", "", line, indent = indent + 2);
+        }
+        return block;
+    }
+
+    let plain = value
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphanumeric())
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || " ._/:+-=?&%()".contains(c))
+        && !value.contains(": ")
+        && !value.ends_with(':')
+        && !value.ends_with(' ')
+        && value.parse::<f64>().is_err()
+        && !matches!(
+            value.to_ascii_lowercase().as_str(),
+            "true" | "false" | "yes" | "no" | "on" | "off" | "y" | "n" | "null"
+        );
+
+    if plain {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\'', "''"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scalar_quotes_values_that_would_change_meaning() {
+        assert_eq!(
+            scalar("https://codedead.com/a.exe", 0),
+            "https://codedead.com/a.exe"
+        );
+        assert_eq!(scalar("1.0.0", 0), "1.0.0");
+        assert_eq!(scalar("1.0", 0), "'1.0'");
+        assert_eq!(scalar("yes", 0), "'yes'");
+        assert_eq!(scalar("Fixes: 'bugs' #1", 0), "'Fixes: ''bugs'' #1'");
+        assert_eq!(scalar("", 0), "''");
+        assert_eq!(scalar("a\nb", 2), "|-\n    a\n    b");
+    }
+}